@@ -0,0 +1,98 @@
+//! Rule evaluation benchmarks: `cargo bench --bench rules`
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fluentval::*;
+use regex::Regex;
+
+struct User {
+    email: String,
+}
+
+fn email(c: &mut Criterion) {
+    let validator = ValidatorBuilder::<User>::new()
+        .rule_for("email", |u| &u.email, RuleBuilder::for_property("email").email(None::<String>))
+        .build();
+    let user = User { email: "ada.lovelace@example.com".to_string() };
+
+    let mut group = c.benchmark_group("email");
+    group.bench_function("validator", |b| b.iter(|| validator.validate(black_box(&user))));
+    // What the rule did before its regex was compiled once per process
+    group.bench_function("compile_per_call", |b| b.iter(|| {
+        Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap().is_match(black_box(&user.email))
+    }));
+    group.finish();
+}
+
+fn matches(c: &mut Criterion) {
+    let rule = RuleBuilder::<String>::for_property("sku")
+        .matches(Regex::new(r"^[A-Z]{3}-\d{4}$").unwrap(), None::<String>)
+        .build();
+    let sku = "ABC-1234".to_string();
+    c.bench_function("matches", |b| b.iter(|| rule(black_box(&sku))));
+}
+
+fn length_and_range(c: &mut Criterion) {
+    struct Signup {
+        name: String,
+        age: i32,
+    }
+
+    let validator = ValidatorBuilder::<Signup>::new()
+        .rule_for("name", |s| &s.name, RuleBuilder::for_property("name").length(2, 50, None::<String>, None::<String>))
+        .rule_for("age", |s| &s.age, RuleBuilder::for_property("age").inclusive_between(18, 130, None::<String>))
+        .build();
+    let valid = Signup { name: "Ada".to_string(), age: 36 };
+    let invalid = Signup { name: "A".to_string(), age: 12 };
+
+    let mut group = c.benchmark_group("length_and_range");
+    group.bench_function("valid", |b| b.iter(|| validator.validate(black_box(&valid))));
+    group.bench_function("invalid", |b| b.iter(|| validator.validate(black_box(&invalid))));
+    group.finish();
+}
+
+/// A validator of 20 rules over 10 properties, on input that passes them all
+fn twenty_rules(c: &mut Criterion) {
+    struct Account {
+        username: String,
+        display_name: String,
+        email: String,
+        recovery_email: String,
+        country: String,
+        age: i32,
+        balance: f64,
+        credit_limit: f64,
+        logins: u32,
+        referrals: u32,
+    }
+
+    let text = |name: &str| RuleBuilder::<String>::for_property(name).not_empty(None::<String>).length(2, 64, None::<String>, None::<String>);
+    let validator = ValidatorBuilder::<Account>::new()
+        .rule_for("username", |a| &a.username, text("username").matches(Regex::new(r"^[a-z0-9_]+$").unwrap(), None::<String>))
+        .rule_for("display_name", |a| &a.display_name, text("display_name").not_blank(None::<String>))
+        .rule_for("email", |a| &a.email, text("email").email(None::<String>))
+        .rule_for("recovery_email", |a| &a.recovery_email, text("recovery_email").email(None::<String>))
+        .rule_for("country", |a| &a.country, RuleBuilder::for_property("country").exact_length(2, None::<String>).not_empty(None::<String>))
+        .rule_for("age", |a| &a.age, RuleBuilder::for_property("age").greater_than_or_equal(18, None::<String>).less_than(130, None::<String>))
+        .rule_for("balance", |a| &a.balance, RuleBuilder::for_property("balance").greater_than_or_equal(0.0, None::<String>))
+        .rule_for("credit_limit", |a| &a.credit_limit, RuleBuilder::for_property("credit_limit").inclusive_between(0.0, 10_000.0, None::<String>))
+        .rule_for("logins", |a| &a.logins, RuleBuilder::for_property("logins").less_than_or_equal(1_000_000, None::<String>))
+        .rule_for("referrals", |a| &a.referrals, RuleBuilder::for_property("referrals").less_than(500, None::<String>))
+        .build();
+    let account = Account {
+        username: "ada_l".to_string(),
+        display_name: "Ada Lovelace".to_string(),
+        email: "ada@example.com".to_string(),
+        recovery_email: "ada.backup@example.org".to_string(),
+        country: "GB".to_string(),
+        age: 36,
+        balance: 120.5,
+        credit_limit: 2_500.0,
+        logins: 42,
+        referrals: 3,
+    };
+    assert!(validator.validate(&account).is_valid());
+    c.bench_function("twenty_rules_valid", |b| b.iter(|| validator.validate(black_box(&account))));
+}
+
+criterion_group!(benches, email, matches, length_and_range, twenty_rules);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+//! Lenient rules accepting any of several formats
+
+use std::fmt;
+use std::sync::Arc;
+
+use regex::Regex;
+
+use crate::rule::{str_is_empty, str_size, str_value, RuleBuilder};
+
+/// A named string format, one of the alternatives of [`RuleBuilder::any_format`]
+///
+/// The name is what the default message shows, so it should read well to the caller,
+/// e.g. `"YYYY-MM-DD"` or `"%d/%m/%Y"`.
+#[derive(Clone)]
+pub struct AcceptedFormat {
+    name: String,
+    matches: Arc<dyn Fn(&str) -> bool + Send + Sync>,
+}
+
+impl AcceptedFormat {
+    /// A format recognized by a predicate
+    pub fn new(name: impl Into<String>, matches: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            name: name.into(),
+            matches: Arc::new(matches),
+        }
+    }
+
+    /// A format that matches a regular expression, named after the expression
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        let regex = Regex::new(pattern)?;
+        Ok(Self::new(pattern, move |value| regex.is_match(value)))
+    }
+
+    /// Name shown for the format in messages
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the value is in this format
+    pub fn matches(&self, value: &str) -> bool {
+        (self.matches)(value)
+    }
+}
+
+impl fmt::Debug for AcceptedFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AcceptedFormat").field(&self.name).finish()
+    }
+}
+
+impl<T> RuleBuilder<T> {
+    /// Validate that the value is in at least one of several formats
+    ///
+    /// For lenient ingestion endpoints that accept, say, both `2024-03-01` and `01/03/2024`.
+    /// The default message lists the accepted formats.
+    ///
+    /// # Arguments
+    /// * `formats` - The accepted formats
+    /// * `message` - Optional custom error message. `{formats}` is replaced with the format names.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::for_property("reference").any_format([
+    ///     AcceptedFormat::regex(r"^INV-\d{6}$")?,
+    ///     AcceptedFormat::new("UUID", |s| uuid::Uuid::parse_str(s).is_ok()),
+    /// ], None::<String>)
+    /// ```
+    pub fn any_format(self, formats: impl IntoIterator<Item = AcceptedFormat>, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let formats: Vec<AcceptedFormat> = formats.into_iter().collect();
+        let names = formats.iter().map(AcceptedFormat::name).collect::<Vec<_>>().join(", ");
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).built_in("any_format", vec![("formats", names)], message.map(|m| m.into()),
+            move |value| formats.iter().any(|format| format.matches(value.as_ref())),
+            Some(str_value::<T>))
+    }
+}
@@ -0,0 +1,56 @@
+//! Adapters validating a value with a known prefix or suffix removed
+
+use crate::rule::{str_is_empty, str_value, RuleBuilder};
+
+impl<T> RuleBuilder<T> {
+    /// Validate that the value starts with `prefix`, and validate the rest with `inner`
+    ///
+    /// Handles the prefix of values like `"+4915112345678"` once, so the remainder can be
+    /// checked by length or format rules. When the prefix is missing, only the `starts_with`
+    /// error is reported. Errors of the inner rules are reported on this property, and
+    /// their attempted value is the remainder.
+    ///
+    /// # Arguments
+    /// * `prefix` - The required prefix
+    /// * `inner` - Rules for the value without the prefix
+    /// * `message` - Optional custom error message for a missing prefix. If not provided, uses default message.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::for_property("phone").with_prefix_stripped("+",
+    ///     RuleBuilder::for_property("phone")
+    ///         .length(8, 15, None::<String>, None::<String>)
+    ///         .must(|digits: &String| digits.bytes().all(|b| b.is_ascii_digit()), "must only contain digits after the +"),
+    ///     None::<String>)
+    /// ```
+    pub fn with_prefix_stripped(self, prefix: &str, inner: RuleBuilder<String>, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let (required, strip) = (prefix.to_string(), prefix.to_string());
+        self.empty_when(str_is_empty::<T>).built_in("starts_with", vec![("prefix", prefix.to_string())], message.map(|m| m.into()),
+            move |value| value.as_ref().starts_with(&required),
+            Some(str_value::<T>))
+            .nested(inner, move |value| value.as_ref().strip_prefix(&strip).map(str::to_string))
+    }
+
+    /// Validate that the value ends with `suffix`, and validate the rest with `inner`
+    ///
+    /// The counterpart of [`with_prefix_stripped`](Self::with_prefix_stripped), e.g. for
+    /// amounts like `"250ms"`.
+    ///
+    /// # Arguments
+    /// * `suffix` - The required suffix
+    /// * `inner` - Rules for the value without the suffix
+    /// * `message` - Optional custom error message for a missing suffix. If not provided, uses default message.
+    pub fn with_suffix_stripped(self, suffix: &str, inner: RuleBuilder<String>, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let (required, strip) = (suffix.to_string(), suffix.to_string());
+        self.empty_when(str_is_empty::<T>).built_in("ends_with", vec![("suffix", suffix.to_string())], message.map(|m| m.into()),
+            move |value| value.as_ref().ends_with(&required),
+            Some(str_value::<T>))
+            .nested(inner, move |value| value.as_ref().strip_suffix(&strip).map(str::to_string))
+    }
+}
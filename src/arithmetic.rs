@@ -0,0 +1,124 @@
+//! Rules checking arithmetic relationships between properties
+
+use crate::builder::ValidatorBuilder;
+use crate::error::ValidationError;
+use crate::messages::{render, MessageContext};
+use crate::rule::RuleDescriptor;
+use crate::traits::Numeric;
+
+/// A named numeric property used as an operand
+pub type Operand<T> = (&'static str, fn(&T) -> f64);
+
+impl<T> ValidatorBuilder<T> {
+    /// Validate that a property equals the sum of other properties, e.g. `total == subtotal + tax`
+    ///
+    /// The error is reported on `property` and names every operand, e.g.
+    /// "must equal subtotal + tax (110)". Its state holds `expected` and `properties`,
+    /// the comma-separated list of all involved properties.
+    ///
+    /// # Arguments
+    /// * `tolerance` - Largest accepted absolute difference, e.g. `0.005` for rounding to cents
+    /// * `message` - Optional custom error message. `{expression}` and `{expected}` are replaced.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .sum_equals("total", |i| i.total, &[("subtotal", |i| i.subtotal), ("tax", |i| i.tax)], 0.005, None::<String>)
+    /// ```
+    pub fn sum_equals(self, property_name: impl Into<String>, actual: fn(&T) -> f64, operands: &[Operand<T>], tolerance: f64, message: Option<impl Into<String>>) -> Self
+    where
+        T: 'static,
+    {
+        self.arithmetic("sum_equals", " + ", property_name.into(), actual, operands, |values| values.iter().sum(), tolerance, message.map(|m| m.into()))
+    }
+
+    /// Validate that a property equals the product of other properties, e.g. `line_total == unit_price * qty`
+    ///
+    /// Reported like [`sum_equals`](Self::sum_equals), e.g. "must equal unit_price * qty (25)".
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .product_equals("line_total", |l| l.line_total, &[("unit_price", |l| l.unit_price), ("qty", |l| l.qty as f64)], 0.005, None::<String>)
+    /// ```
+    pub fn product_equals(self, property_name: impl Into<String>, actual: fn(&T) -> f64, operands: &[Operand<T>], tolerance: f64, message: Option<impl Into<String>>) -> Self
+    where
+        T: 'static,
+    {
+        self.arithmetic("product_equals", " * ", property_name.into(), actual, operands, |values| values.iter().product(), tolerance, message.map(|m| m.into()))
+    }
+
+    /// Validate that a property is within `epsilon` of another property
+    ///
+    /// The cross-field counterpart of `RuleBuilder::approx_equal`. The error is reported on
+    /// `property`, with the other property's value as `{expected}` and in the state.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .approx_equal_to("paid", |p| &p.paid, "due", |p| &p.due, 0.005, None::<String>)
+    /// ```
+    pub fn approx_equal_to<V, W, FA, FB>(self, property_name: impl Into<String>, actual: FA, other_name: impl Into<String>, other: FB, epsilon: f64, message: Option<impl Into<String>>) -> Self
+    where
+        FA: Fn(&T) -> &V + Send + Sync + 'static,
+        FB: Fn(&T) -> &W + Send + Sync + 'static,
+        V: Numeric + 'static,
+        W: Numeric + 'static,
+    {
+        let property_name = property_name.into();
+        let other_name = other_name.into();
+        let message = message.map(|m| m.into());
+        let descriptor = RuleDescriptor::new(property_name.clone(), "approx_equal", vec![("other", other_name.clone()), ("epsilon", epsilon.to_string())]).with_message(message.clone());
+        self.add_rule(descriptor, move |instance: &T, messages: &MessageContext| {
+            let actual = actual(instance).to_f64();
+            let expected = other(instance).to_f64();
+            if (actual - expected).abs() <= epsilon {
+                return Vec::new();
+            }
+            let params = vec![
+                ("expected".to_string(), expected.to_string()),
+                ("epsilon".to_string(), epsilon.to_string()),
+                ("other".to_string(), other_name.clone()),
+            ];
+            let message = match &message {
+                Some(message) => render(message, &params),
+                None => messages.message(None, "approx_equal", &params),
+            };
+            vec![ValidationError::new(property_name.clone(), message)
+                .with_code("APPROX_EQUAL")
+                .with_attempted_value(actual)
+                .with_state("expected", expected)
+                .with_state("other", &other_name)]
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn arithmetic(self, kind: &'static str, operator: &str, property_name: String, actual: fn(&T) -> f64, operands: &[Operand<T>], combine: fn(&[f64]) -> f64, tolerance: f64, message: Option<String>) -> Self
+    where
+        T: 'static,
+    {
+        let operands = operands.to_vec();
+        let names: Vec<&str> = operands.iter().map(|(name, _)| *name).collect();
+        let expression = names.join(operator);
+        let properties = std::iter::once(property_name.as_str()).chain(names).collect::<Vec<_>>().join(",");
+        let descriptor = RuleDescriptor::new(property_name.clone(), kind, vec![("expression", expression.clone()), ("tolerance", tolerance.to_string())]).with_message(message.clone());
+        self.add_rule(descriptor, move |instance: &T, messages: &MessageContext| {
+            let values: Vec<f64> = operands.iter().map(|(_, value)| value(instance)).collect();
+            let expected = combine(&values);
+            let actual = actual(instance);
+            if (actual - expected).abs() <= tolerance {
+                return Vec::new();
+            }
+            let params = vec![
+                ("expression".to_string(), expression.clone()),
+                ("expected".to_string(), expected.to_string()),
+            ];
+            let message = match &message {
+                Some(message) => render(message, &params),
+                None => messages.message(None, kind, &params),
+            };
+            vec![ValidationError::new(property_name.clone(), message)
+                .with_code(kind.to_uppercase())
+                .with_attempted_value(actual)
+                .with_state("expected", expected)
+                .with_state("properties", &properties)]
+        })
+    }
+}
@@ -0,0 +1,205 @@
+//! Asynchronous rules for checks that need I/O, such as DNS lookups
+//!
+//! Async rules are declared with an [`AsyncRuleBuilder`] and attached with
+//! `ValidatorBuilder::rule_for_async`. They only run in `Validator::validate_async`, after the
+//! synchronous rules, and only for properties the synchronous rules accepted, so a malformed
+//! host name is reported without a lookup. `validate` and its variants skip them.
+//!
+//! The futures are runtime-agnostic unless a rule says otherwise; `resolvable_host` (feature
+//! `dns`) needs a Tokio runtime with the `net` and `time` drivers.
+//!
+//! # Example
+//! ```rust,ignore
+//! let validator = ValidatorBuilder::<Signup>::new()
+//!     .rule_for("smtp_host", |s| &s.smtp_host,
+//!         RuleBuilder::for_property("smtp_host").not_empty(None::<String>))
+//!     .rule_for_async("smtp_host", |s| &s.smtp_host,
+//!         AsyncRuleBuilder::for_property("smtp_host")
+//!             .resolvable_host(Duration::from_secs(2), None::<String>))
+//!     .build();
+//!
+//! let result = validator.validate_async(&signup).await;
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::builder::ValidatorBuilder;
+use crate::error::ValidationError;
+use crate::messages::{render, MessageContext};
+use crate::rule::{FailureDetails, RuleDescriptor};
+
+/// A boxed future, as returned by async rules and `Validator::validate_async`
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// A user rule resolving to an error message, or `None` when the value passes
+type CustomCheck<T> = Box<dyn for<'a> Fn(&'a T) -> BoxFuture<'a, Option<String>> + Send + Sync>;
+
+/// A built-in check resolving to failure details, or `None` when the value passes
+type DetailedCheck<T> = Box<dyn for<'a> Fn(&'a T) -> BoxFuture<'a, Option<FailureDetails>> + Send + Sync>;
+
+/// An async rule as stored by the validator, reporting the errors for one instance
+pub(crate) type AsyncRuleFn<T> = Box<dyn for<'a> Fn(&'a T, &'a MessageContext<'a>) -> BoxFuture<'a, Vec<ValidationError>> + Send + Sync>;
+
+/// How an async rule decides whether a value is valid
+enum Check<T> {
+    /// A user rule that produces its own message
+    Custom(CustomCheck<T>),
+    /// A built-in check whose message is either custom or looked up by rule kind
+    BuiltIn(DetailedCheck<T>),
+}
+
+/// An async rule together with the message and error code it reports on failure
+struct AsyncEntry<T> {
+    check: Check<T>,
+    kind: String,
+    params: Vec<(String, String)>,
+    message: Option<String>,
+    code: Option<String>,
+}
+
+/// Builder for async validation rules of a property
+pub struct AsyncRuleBuilder<T> {
+    property_name: String,
+    rules: Vec<AsyncEntry<T>>,
+}
+
+impl<T> AsyncRuleBuilder<T> {
+    /// Create a new async rule builder for a property
+    pub fn for_property(property_name: impl Into<String>) -> Self {
+        Self {
+            property_name: property_name.into(),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Add a custom async rule returning an error message on failure
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// AsyncRuleBuilder::<String>::for_property("username")
+    ///     .rule(|name| Box::pin(async move {
+    ///         users.exists(name).await.then(|| "is already taken".to_string())
+    ///     }))
+    /// ```
+    pub fn rule<F>(mut self, rule: F) -> Self
+    where
+        F: for<'a> Fn(&'a T) -> BoxFuture<'a, Option<String>> + Send + Sync + 'static,
+    {
+        self.rules.push(AsyncEntry {
+            check: Check::Custom(Box::new(rule)),
+            kind: "custom".to_string(),
+            params: Vec::new(),
+            message: None,
+            code: None,
+        });
+        self
+    }
+
+    /// Add an async predicate that must hold, reporting `message` when it doesn't
+    pub fn must<F>(self, predicate: F, message: impl Into<String>) -> Self
+    where
+        F: for<'a> Fn(&'a T) -> BoxFuture<'a, bool> + Send + Sync + 'static,
+    {
+        let mut builder = self.built_in("must", Vec::new(), Some(message.into()), move |value| {
+            let passes = predicate(value);
+            Box::pin(async move { (!passes.await).then(Vec::new) })
+        });
+        if let Some(entry) = builder.rules.last_mut() {
+            entry.code = None;
+        }
+        builder
+    }
+
+    /// Add a built-in async rule
+    ///
+    /// As for synchronous built-in rules, the default message is looked up by `kind`, and the
+    /// error code is the upper-cased rule kind.
+    pub(crate) fn built_in<F>(mut self, kind: &str, params: Vec<(&str, String)>, message: Option<String>, check: F) -> Self
+    where
+        F: for<'a> Fn(&'a T) -> BoxFuture<'a, Option<FailureDetails>> + Send + Sync + 'static,
+    {
+        self.rules.push(AsyncEntry {
+            check: Check::BuiltIn(Box::new(check)),
+            kind: kind.to_string(),
+            params: params.into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+            message,
+            code: Some(kind.to_uppercase()),
+        });
+        self
+    }
+
+    /// Describe the configured rules
+    pub(crate) fn descriptors(&self) -> Vec<RuleDescriptor> {
+        self.rules
+            .iter()
+            .map(|entry| RuleDescriptor {
+                params: entry.params.clone(),
+                message: entry.message.clone(),
+                code: entry.code.clone(),
+                ..RuleDescriptor::new(self.property_name.as_str(), &entry.kind, Vec::new())
+            })
+            .collect()
+    }
+
+    /// Run the rules against a value, in declaration order
+    async fn check(&self, value: &T, messages: &MessageContext<'_>) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        for entry in &self.rules {
+            let (message, details) = match &entry.check {
+                Check::Custom(rule) => match rule(value).await {
+                    Some(message) => (message, Vec::new()),
+                    None => continue,
+                },
+                Check::BuiltIn(check) => {
+                    let Some(details) = check(value).await else {
+                        continue;
+                    };
+                    let details: Vec<(String, String)> = details.into_iter().map(|(k, v)| (k.to_string(), v)).collect();
+                    let message = match &entry.message {
+                        Some(message) => render(message, &details),
+                        None => messages.message(None, &entry.kind, &[entry.params.as_slice(), details.as_slice()].concat()),
+                    };
+                    (message, details)
+                }
+            };
+            let mut error = ValidationError::new(self.property_name.clone(), message);
+            if let Some(code) = &entry.code {
+                error = error.with_code(code.clone());
+            }
+            error.state.extend(details);
+            errors.push(error);
+        }
+        errors
+    }
+}
+
+impl<T> ValidatorBuilder<T> {
+    /// Add async rules for a property, run by `validate_async`
+    ///
+    /// The rules are skipped when a synchronous rule already reported an error for the
+    /// property, and are never run by `validate`.
+    pub fn rule_for_async<F, V>(self, _property_name: impl Into<String>, accessor: F, builder: AsyncRuleBuilder<V>) -> Self
+    where
+        F: Fn(&T) -> &V + Send + Sync + 'static,
+        V: 'static,
+    {
+        let property_name = builder.property_name.clone();
+        let descriptors = builder.descriptors();
+        let builder = Arc::new(builder);
+        self.add_async_rule(property_name, descriptors, async_rule(move |instance, messages| {
+            let value = accessor(instance);
+            let builder = Arc::clone(&builder);
+            Box::pin(async move { builder.check(value, messages).await })
+        }))
+    }
+}
+
+/// Box an async rule, fixing the closure's signature to borrow the instance for the future
+fn async_rule<T, F>(rule: F) -> AsyncRuleFn<T>
+where
+    F: for<'a> Fn(&'a T, &'a MessageContext<'a>) -> BoxFuture<'a, Vec<ValidationError>> + Send + Sync + 'static,
+{
+    Box::new(rule)
+}
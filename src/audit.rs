@@ -0,0 +1,140 @@
+//! Audit records of validations, for compliance logs of regulated flows such as financial
+//! onboarding (feature `audit`)
+//!
+//! A validator built with `ValidatorBuilder::audit` hands an [`AuditRecord`] to its
+//! [`AuditSink`] after every validation: who asked (the `actor` value of a
+//! `ValidationContext`), what was validated, with which rule set version, the outcome and
+//! the rules that were skipped. Records hold error properties and codes but no messages or
+//! attempted values, so personal data stays out of the log.
+//!
+//! # Example
+//! ```rust,ignore
+//! let validator = ValidatorBuilder::<Applicant>::new()
+//!     .schema_id("kyc-applicant")
+//!     .schema_version("2024-06")
+//!     .record_skipped_rules()
+//!     .audit(JsonLinesAuditSink::open("/var/log/onboarding/validation.jsonl")?)
+//!     .rule_for("tax_id", |a| &a.tax_id, RuleBuilder::for_property("tax_id").not_empty(None::<String>))
+//!     .build();
+//!
+//! let context = ValidationContext::new().with_value("actor", "agent-17");
+//! let result = validator.validate_with_context(&applicant, &context);
+//! ```
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::{SkippedRule, ValidationResult};
+
+/// The key of the `ValidationContext` value recorded as the actor
+pub const ACTOR_KEY: &str = "actor";
+
+/// What an audit log keeps of one validation
+///
+/// Serializes as one JSON object, e.g.
+/// `{"timestamp_ms": 1718000000000, "actor": "agent-17", "subject": "app::Applicant",
+/// "property": null, "schema_id": "kyc-applicant", "schema_version": "2024-06", "correlation_id": null,
+/// "valid": false, "failures": [{"property": "tax_id", "code": "NOT_EMPTY"}], "truncated": false,
+/// "skipped_rules": []}`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct AuditRecord {
+    /// When the validation ran by the validator's clock, in milliseconds since the Unix epoch
+    pub timestamp_ms: u64,
+    /// Who asked for the validation, from the context's [`ACTOR_KEY`] value
+    pub actor: Option<String>,
+    /// The type that was validated
+    pub subject: String,
+    /// The one property checked by `validate_property`, or `None` when the whole value was
+    /// validated
+    pub property: Option<String>,
+    pub schema_id: Option<Arc<str>>,
+    pub schema_version: Option<Arc<str>>,
+    pub correlation_id: Option<String>,
+    pub valid: bool,
+    pub failures: Vec<AuditedFailure>,
+    /// Whether validation stopped at the validator's `max_errors`
+    pub truncated: bool,
+    /// The rules that did not run, when the validator records them
+    pub skipped_rules: Vec<SkippedRule>,
+}
+
+/// A failed rule as recorded in an audit log
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct AuditedFailure {
+    pub property: Arc<str>,
+    pub code: Option<String>,
+}
+
+impl AuditRecord {
+    /// The record of a validation of `subject`, or of its `property` only, that produced
+    /// `result`
+    pub(crate) fn new(subject: &str, property: Option<&str>, actor: Option<&str>, now: SystemTime, result: &ValidationResult) -> Self {
+        Self {
+            timestamp_ms: now.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_millis() as u64),
+            actor: actor.map(str::to_string),
+            subject: subject.to_string(),
+            property: property.map(str::to_string),
+            schema_id: result.schema_id().map(Arc::from),
+            schema_version: result.schema_version().map(Arc::from),
+            correlation_id: result.correlation_id().map(str::to_string),
+            valid: result.is_valid(),
+            failures: result.errors().iter().map(|error| AuditedFailure { property: Arc::clone(&error.property), code: error.code.clone() }).collect(),
+            truncated: result.is_truncated(),
+            skipped_rules: result.skipped_rules().to_vec(),
+        }
+    }
+}
+
+/// Destination of audit records, set with `ValidatorBuilder::audit`
+///
+/// Called on the validating thread after each validation, so it should be quick or hand the
+/// record off. Implemented for closures, e.g. to forward records to `tracing` or a queue.
+pub trait AuditSink: Send + Sync {
+    /// Store the record of one validation
+    fn record(&self, record: &AuditRecord);
+}
+
+impl<F: Fn(&AuditRecord) + Send + Sync> AuditSink for F {
+    fn record(&self, record: &AuditRecord) {
+        self(record)
+    }
+}
+
+/// An audit sink appending one JSON object per line to a file
+///
+/// Each record is written unbuffered as a whole line to a file opened for appending, so a
+/// crash loses at most the record being written. Validation never fails because of the
+/// log: the first write error is kept for [`take_error`](Self::take_error), which a health
+/// check should poll.
+#[derive(Debug)]
+pub struct JsonLinesAuditSink {
+    file: Mutex<File>,
+    error: Mutex<Option<io::Error>>,
+}
+
+impl JsonLinesAuditSink {
+    /// Open `path` for appending, creating it if needed
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self { file: Mutex::new(file), error: Mutex::new(None) })
+    }
+
+    /// The first error writing a record since the last call, if any
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.error.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take()
+    }
+}
+
+impl AuditSink for JsonLinesAuditSink {
+    fn record(&self, record: &AuditRecord) {
+        let mut line = serde_json::to_vec(record).expect("audit records serialize to JSON");
+        line.push(b'\n');
+        let written = self.file.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).write_all(&line);
+        if let Err(error) = written {
+            self.error.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).get_or_insert(error);
+        }
+    }
+}
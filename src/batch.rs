@@ -0,0 +1,134 @@
+//! Validating large batches of items of one type, such as the rows of an import
+//!
+//! [`validate_all`] runs a validator over every item and returns a
+//! [`BatchValidationReport`] holding the results of the items that failed, keyed by their
+//! position (or a key of your choosing with [`validate_all_by`]), and aggregate counts.
+//!
+//! # Example
+//! ```rust,ignore
+//! let report = validate_all_by(&rows, &row_validator, |row| row.line_number);
+//! println!("{} of {} rows rejected", report.failed(), report.total());
+//! for (line, result) in report.failures() {
+//!     eprintln!("line {}: {:?}", line, result.errors());
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::error::ValidationResult;
+use crate::traits::Validator;
+
+/// Outcome of validating a batch of items
+///
+/// Only the results of failed items are kept, in the order of the items, so reports of
+/// mostly valid batches stay small.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchValidationReport<K = usize> {
+    total: usize,
+    failures: Vec<(K, ValidationResult)>,
+    errors_by_rule: BTreeMap<String, usize>,
+}
+
+impl<K> BatchValidationReport<K> {
+    /// Create a report of an empty batch
+    pub fn new() -> Self {
+        Self {
+            total: 0,
+            failures: Vec::new(),
+            errors_by_rule: BTreeMap::new(),
+        }
+    }
+
+    /// Record the result of the next item
+    pub fn add(&mut self, key: K, result: ValidationResult) {
+        self.total += 1;
+        if result.is_valid() {
+            return;
+        }
+        for error in result.errors() {
+            *self.errors_by_rule.entry(error.code.clone().unwrap_or_default()).or_default() += 1;
+        }
+        self.failures.push((key, result));
+    }
+
+    /// Number of items validated
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Number of items that failed validation
+    pub fn failed(&self) -> usize {
+        self.failures.len()
+    }
+
+    /// Number of items that passed validation
+    pub fn passed(&self) -> usize {
+        self.total - self.failures.len()
+    }
+
+    /// Whether every item passed
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Total number of errors across the batch
+    pub fn error_count(&self) -> usize {
+        self.errors_by_rule.values().sum()
+    }
+
+    /// Number of errors per error code (e.g. `"EMAIL"`), errors without a code counted under `""`
+    pub fn errors_by_rule(&self) -> &BTreeMap<String, usize> {
+        &self.errors_by_rule
+    }
+
+    /// The failed items' keys and results, in the order of the items
+    pub fn failures(&self) -> impl Iterator<Item = (&K, &ValidationResult)> {
+        self.failures.iter().map(|(key, result)| (key, result))
+    }
+
+    /// The result of the item with the given key, `None` if it passed or isn't part of the batch
+    pub fn result_for(&self, key: &K) -> Option<&ValidationResult>
+    where
+        K: PartialEq,
+    {
+        self.failures.iter().find(|(k, _)| k == key).map(|(_, result)| result)
+    }
+
+    /// Consume the report into the failed items' keys and results
+    pub fn into_failures(self) -> Vec<(K, ValidationResult)> {
+        self.failures
+    }
+}
+
+impl<K> Default for BatchValidationReport<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Validate every item of a batch, keying failures by the item's position
+///
+/// Not to be confused with the `validate_all!` macro, which merges the results of several
+/// objects of different types into one result.
+pub fn validate_all<'a, T: 'a>(items: impl IntoIterator<Item = &'a T>, validator: &dyn Validator<T>) -> BatchValidationReport {
+    let mut report = BatchValidationReport::new();
+    for (index, item) in items.into_iter().enumerate() {
+        report.add(index, validator.validate(item));
+    }
+    report
+}
+
+/// Validate every item of a batch, keying failures by `key`, e.g. a row's line number or ID
+pub fn validate_all_by<'a, T: 'a, K>(items: impl IntoIterator<Item = &'a T>, validator: &dyn Validator<T>, key: impl Fn(&T) -> K) -> BatchValidationReport<K> {
+    let mut report = BatchValidationReport::new();
+    for item in items {
+        let result = validator.validate(item);
+        // Keys are only computed for failures
+        if result.is_valid() {
+            report.total += 1;
+        } else {
+            report.add(key(item), result);
+        }
+    }
+    report
+}
@@ -1,31 +1,316 @@
-use crate::error::{ValidationError, ValidationResult};
-use crate::rule::RuleBuilder;
-use crate::traits::Validator;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::sync::Arc;
+#[cfg(feature = "audit")]
+use std::time::SystemTime;
 
-type RuleFn<T> = Box<dyn Fn(&T) -> Vec<ValidationError>>;
+#[cfg(feature = "async")]
+use crate::async_rule::{AsyncRuleFn, BoxFuture};
+#[cfg(feature = "audit")]
+use crate::audit::{AuditRecord, AuditSink, ACTOR_KEY};
+#[cfg(feature = "audit")]
+use crate::context::ValidationContext;
+use crate::clock::Clock;
+use crate::error::{SkipReason, ValidationError, ValidationResult};
+use crate::format::is_within;
+use crate::ids::{IdGenerator, ProcessIds};
+use crate::limits::{self, InputSize, ResourceLimits};
+use crate::messages::{self, MessageContext, MessageProvider};
+use crate::rule::{EmptyValues, RuleBuilder, RuleDescriptor};
+use crate::traits::{self, Validator};
+
+pub(crate) type RuleFn<T> = Box<dyn Fn(&T, &MessageContext) -> Vec<ValidationError> + Send + Sync>;
+/// The properties and kinds of the rules a rule function runs, recorded when it is skipped
+type RuleKinds = Vec<(Arc<str>, String)>;
+/// A rule with the property it reports errors for (`None` if it can report for any property)
+/// and the rules it runs
+type PropertyRule<T> = (Option<String>, RuleKinds, RuleFn<T>);
+type Observer = Box<dyn Fn(&ValidationResult) + Send + Sync>;
+/// An async rule with the property it reports errors for and the kinds of its rules
+#[cfg(feature = "async")]
+type PropertyAsyncRule<T> = (String, Vec<String>, AsyncRuleFn<T>);
 
 /// Helper struct to build validators in a fluent style
 pub struct ValidatorBuilder<T> {
-    rules: Vec<RuleFn<T>>,
+    rules: Vec<PropertyRule<T>>,
+    descriptors: Vec<RuleDescriptor>,
+    message_provider: Option<Arc<dyn MessageProvider>>,
+    clock: Option<Arc<dyn Clock>>,
+    schema_id: Option<Arc<str>>,
+    schema_version: Option<Arc<str>>,
+    correlation_ids: Option<Arc<dyn IdGenerator>>,
+    observers: Vec<Observer>,
+    one_error_per_property: bool,
+    empty_values: EmptyValues,
+    isolate_panics: bool,
+    limits: ResourceLimits,
+    max_errors: Option<usize>,
+    record_skipped_rules: bool,
+    #[cfg(feature = "audit")]
+    audit: Option<Arc<dyn AuditSink>>,
+    #[cfg(feature = "async")]
+    async_rules: Vec<PropertyAsyncRule<T>>,
 }
 
 impl<T> ValidatorBuilder<T> {
     /// Create a new validator builder
     pub fn new() -> Self {
-        Self { rules: Vec::new() }
+        Self {
+            rules: Vec::new(),
+            descriptors: Vec::new(),
+            message_provider: None,
+            clock: None,
+            schema_id: None,
+            schema_version: None,
+            correlation_ids: None,
+            observers: Vec::new(),
+            one_error_per_property: false,
+            empty_values: EmptyValues::Validate,
+            isolate_panics: false,
+            limits: ResourceLimits::default(),
+            max_errors: None,
+            record_skipped_rules: false,
+            #[cfg(feature = "audit")]
+            audit: None,
+            #[cfg(feature = "async")]
+            async_rules: Vec::new(),
+        }
+    }
+
+    /// Look up default messages of built-in rules in a message provider
+    ///
+    /// Rules without a custom message get their wording from the provider, in the locale
+    /// passed to `validate_with_locale`, falling back to the built-in English messages.
+    pub fn with_message_provider(mut self, provider: impl MessageProvider + 'static) -> Self {
+        self.message_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Read the current time for time-dependent rules (`in_past`, `age_at_least`,
+    /// `enforced_after`, ...) from `clock` instead of the system clock
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // Freeze time in tests
+    /// ValidatorBuilder::<User>::new().with_clock(FixedClock::new(UNIX_EPOCH + Duration::from_secs(1_700_000_000)))
+    /// ```
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Tag every result of the validator with an identifier of its rule set
+    ///
+    /// The identifier (and the version, if set) is copied onto each `ValidationResult`, so
+    /// logs and error responses show which rule set rejected a payload.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// ValidatorBuilder::<Order>::new().schema_id("order.create").schema_version("2024-06")
+    /// ```
+    pub fn schema_id(mut self, id: impl Into<Arc<str>>) -> Self {
+        self.schema_id = Some(id.into());
+        self
+    }
+
+    /// Tag every result with the version of the rule set, alongside `schema_id`
+    pub fn schema_version(mut self, version: impl Into<Arc<str>>) -> Self {
+        self.schema_version = Some(version.into());
+        self
+    }
+
+    /// Stamp every result with a unique correlation ID
+    ///
+    /// The ID is serialized with the result, rendered by `ProblemDetails` as `correlationId`
+    /// and passed to observers, so an ID quoted by a user can be matched to the server-side
+    /// log entry. IDs are 16 hex digits, unique within the process (see [`ProcessIds`]).
+    pub fn correlation_ids(self) -> Self {
+        self.correlation_id_generator(ProcessIds)
+    }
+
+    /// Stamp every result with a correlation ID from `generator`, e.g. the current request ID
+    ///
+    /// Pass a [`SeededIds`](crate::SeededIds) for IDs that are the same on every test run.
+    pub fn correlation_id_generator(mut self, generator: impl IdGenerator + 'static) -> Self {
+        self.correlation_ids = Some(Arc::new(generator));
+        self
+    }
+
+    /// Call `observer` with every result the validator produces, e.g. to log failures
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// ValidatorBuilder::<Order>::new()
+    ///     .correlation_ids()
+    ///     .on_result(|result| if !result.is_valid() {
+    ///         log::info!("validation {} failed: {:?}", result.correlation_id().unwrap(), result.errors());
+    ///     })
+    /// ```
+    pub fn on_result(mut self, observer: impl Fn(&ValidationResult) + Send + Sync + 'static) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    /// Report at most one error per property, the first one, for frontends that render a
+    /// single message per field
+    pub fn one_error_per_property(mut self) -> Self {
+        self.one_error_per_property = true;
+        self
+    }
+
+    /// Stop validating once `max` errors were reported, so a badly malformed payload doesn't
+    /// produce thousands of errors
+    ///
+    /// The remaining rules are skipped and the result is marked as truncated, see
+    /// [`ValidationResult::is_truncated`]. Object-level rules reporting several errors at once
+    /// are cut down to `max`.
+    pub fn max_errors(mut self, max: usize) -> Self {
+        self.max_errors = Some(max);
+        self
+    }
+
+    /// Record the rules that did not run on each result, see
+    /// [`ValidationResult::skipped_rules`]
+    ///
+    /// Lists the rules skipped by `skip_when_context` conditions, `enforced_after` dates,
+    /// [`EmptyValues::Skip`], `max_errors` and the resource limits, and async rules of
+    /// properties that already failed, so auditors can verify which checks ran for a request.
+    pub fn record_skipped_rules(mut self) -> Self {
+        self.record_skipped_rules = true;
+        self
+    }
+
+    /// Hand a record of every validation to an audit sink, see [`AuditRecord`]
+    ///
+    /// The actor is read from the [`ACTOR_KEY`] value of the `ValidationContext` passed to
+    /// `validate_with_context`. Combine with [`schema_version`](Self::schema_version) to
+    /// record which rule set judged the input, and with
+    /// [`record_skipped_rules`](Self::record_skipped_rules) to record skipped checks.
+    #[cfg(feature = "audit")]
+    pub fn audit(mut self, sink: impl AuditSink + 'static) -> Self {
+        self.audit = Some(Arc::new(sink));
+        self
+    }
+
+    /// Choose whether rules run on empty values when a property has no presence rule
+    ///
+    /// With [`EmptyValues::Skip`], a property whose value is empty (`""`, whitespace, an empty
+    /// collection or `None`) only runs its presence rules (`not_empty`, `not_null`, ...) and
+    /// rules marked with `even_if_empty`, so `email()` alone makes an optional email field and
+    /// `not_empty().email()` reports a single error for a missing one. Emptiness is known
+    /// from the property's string, length and option rules; chains of only custom rules
+    /// always run.
+    pub fn empty_values(mut self, policy: EmptyValues) -> Self {
+        self.empty_values = policy;
+        self
+    }
+
+    /// Turn panics in rule closures into errors instead of unwinding out of `validate`
+    ///
+    /// A `rule`, `must` or other closure-based rule that panics fails with the code
+    /// `RULE_PANICKED` and the message "could not be validated", and the remaining rules
+    /// still run, so a bug in one predicate doesn't take down the request handler. The panic
+    /// hook still runs, so the panic is logged as usual. Has no effect when built with
+    /// `panic = "abort"`, and async rules are not covered.
+    pub fn isolate_panics(mut self) -> Self {
+        self.isolate_panics = true;
+        self
+    }
+
+    /// Fail strings longer than `bytes` with a `RESOURCE_LIMIT` error instead of running
+    /// their pattern rules
+    ///
+    /// Guards the rules whose cost grows with the input: `matches`, `email`, `any_format`,
+    /// `satisfies`, `date_format`, `time_format`, the HTTP rules, `safe_outbound_url` and
+    /// `not_disposable_email`. A property over the limit reports this single error and none
+    /// of its other rules run.
+    pub fn max_input_bytes(mut self, bytes: usize) -> Self {
+        self.limits.max_input_bytes = Some(bytes);
+        self
+    }
+
+    /// Fail collections with more than `elements` elements with a `RESOURCE_LIMIT` error
+    ///
+    /// `rule_for_each` stops after that many elements, and ordering rules such as
+    /// `sorted_ascending`, `same_length`, `each_pair` and `non_overlapping` don't run on
+    /// larger collections.
+    pub fn max_collection_len(mut self, elements: usize) -> Self {
+        self.limits.max_collection_len = Some(elements);
+        self
+    }
+
+    /// Fail with a `RESOURCE_LIMIT` error instead of running validators nested more than
+    /// `depth` levels deep, e.g. a tree validator calling itself on the children of a node
+    ///
+    /// Counts the validations running on the current thread, this one included; the
+    /// error is reported by the validator over the limit, with an empty property.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.limits.max_depth = Some(depth);
+        self
     }
 
     /// Add a rule for a property
     pub fn rule_for<F, V>(mut self, _property_name: impl Into<String>, accessor: F, builder: RuleBuilder<V>) -> Self
     where
-        F: Fn(&T) -> &V + 'static,
+        F: Fn(&T) -> &V + Send + Sync + 'static,
         V: 'static,
     {
-        let rule_fn = builder.build();
-        self.rules.push(Box::new(move |instance: &T| {
+        let descriptors = builder.descriptors();
+        let property = builder.property_name().to_string();
+        let rule_fn = builder.build_with_messages();
+        self.rules.push((Some(property), kinds_of(&descriptors), Box::new(move |instance: &T, messages: &MessageContext| {
             let value = accessor(instance);
-            rule_fn(value)
-        }));
+            rule_fn(value, messages)
+        })));
+        self.descriptors.extend(descriptors);
+        self
+    }
+
+    /// Add rules applied to every element of a collection property
+    ///
+    /// Errors are reported against the indexed property name, e.g. `lines[2]`, and the
+    /// `{CollectionIndex}` placeholder in any message is replaced with the element index.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .rule_for_each("lines", |o| &o.quantities,
+    ///     RuleBuilder::for_property("lines")
+    ///         .greater_than(0, Some("line {CollectionIndex}: quantity must be positive")))
+    /// ```
+    pub fn rule_for_each<F, C, V>(mut self, _property_name: impl Into<String>, accessor: F, builder: RuleBuilder<V>) -> Self
+    where
+        F: Fn(&T) -> &C + Send + Sync + 'static,
+        C: ?Sized + 'static,
+        for<'a> &'a C: IntoIterator<Item = &'a V>,
+        V: 'static,
+    {
+        let descriptors: Vec<RuleDescriptor> = builder.descriptors().into_iter().map(|mut descriptor| {
+            descriptor.property.push_str("[]");
+            descriptor
+        }).collect();
+        let property = builder.property_name().to_string();
+        let limit_property: Arc<str> = property.as_str().into();
+        let rule_fn = builder.build_with_messages();
+        let kinds = kinds_of(&descriptors);
+        self.descriptors.extend(descriptors);
+        self.rules.push((Some(property), kinds, Box::new(move |instance: &T, messages: &MessageContext| {
+            let mut errors = Vec::new();
+            for (index, element) in accessor(instance).into_iter().enumerate() {
+                if let Some(exceeded) = messages.limits.exceeded(InputSize::Elements(index + 1)) {
+                    errors.push(limits::resource_limit_error(limit_property.clone(), exceeded, messages, None));
+                    break;
+                }
+                let skipped = messages.skipped_len();
+                for mut error in rule_fn(element, messages) {
+                    error.property = format!("{}[{}]", error.property, index).into();
+                    error.message = error.message.replace("{CollectionIndex}", &index.to_string());
+                    errors.push(error);
+                }
+                messages.rename_skipped(skipped, |property| format!("{}[{}]", property, index).into());
+            }
+            errors
+        })));
         self
     }
 
@@ -52,28 +337,165 @@ impl<T> ValidatorBuilder<T> {
     ///     |_, country| Countries::allowed_countries().contains(country),
     ///     "Country is not in the allowed list")
     /// ```
-    pub fn must<F, V, P>(mut self, property_name: impl Into<String>, accessor: F, predicate: P, message: impl Into<String>) -> Self
+    pub fn must<F, V, P>(self, property_name: impl Into<String>, accessor: F, predicate: P, message: impl Into<String>) -> Self
     where
-        F: Fn(&T) -> &V + 'static,
+        F: Fn(&T) -> &V + Send + Sync + 'static,
         V: 'static,
-        P: Fn(&T, &V) -> bool + 'static,
+        P: Fn(&T, &V) -> bool + Send + Sync + 'static,
     {
         let property_name = property_name.into();
         let msg = message.into();
-        self.rules.push(Box::new(move |instance: &T| {
+        let descriptor = RuleDescriptor::new(property_name.clone(), "must", Vec::new()).with_message(Some(msg.clone())).without_code();
+        self.add_rule(descriptor, move |instance: &T, _: &MessageContext| {
             let value = accessor(instance);
             if !predicate(instance, value) {
                 vec![ValidationError::new(property_name.clone(), msg.clone())]
             } else {
                 Vec::new()
             }
-        }));
+        })
+    }
+
+    /// Add the rules of another builder, e.g. shared rules of a base type
+    ///
+    /// Mirrors FluentValidation's `Include()`. The included rules run in place, in the order
+    /// they were declared, with this builder's message provider (falling back to the other
+    /// builder's).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// fn audited() -> ValidatorBuilder<Invoice> {
+    ///     ValidatorBuilder::new()
+    ///         .rule_for("created_by", |i| &i.created_by, RuleBuilder::for_property("created_by").not_empty(None::<String>))
+    /// }
+    ///
+    /// let validator = ValidatorBuilder::<Invoice>::new()
+    ///     .include(audited())
+    ///     .rule_for("total", |i| &i.total, RuleBuilder::for_property("total").greater_than(0.0, None::<String>))
+    ///     .build();
+    /// ```
+    pub fn include(mut self, other: ValidatorBuilder<T>) -> Self {
+        self.rules.extend(other.rules);
+        self.descriptors.extend(other.descriptors);
+        self.message_provider = self.message_provider.or(other.message_provider);
+        self.clock = self.clock.or(other.clock);
+        #[cfg(feature = "async")]
+        self.async_rules.extend(other.async_rules);
+        self
+    }
+
+    /// Replace the rules added so far for a property with new ones
+    ///
+    /// Meant for validators composed with [`include`](Self::include) that need to relax or
+    /// tighten a base rule, e.g. an admin update that allows a shorter name. The earlier rules
+    /// for `property_name` (or its nested and indexed properties, such as `name.first` or
+    /// `name[0]`) are removed with their descriptors, errors included validators report for
+    /// it are dropped, then `builder` is added as with [`rule_for`](Self::rule_for).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let admin_update = ValidatorBuilder::<User>::new()
+    ///     .include(user_rules())
+    ///     .override_rules_for("name", |u| &u.name, RuleBuilder::for_property("name").max_length(200, None::<String>))
+    ///     .build();
+    /// ```
+    pub fn override_rules_for<F, V>(mut self, property_name: impl Into<String>, accessor: F, builder: RuleBuilder<V>) -> Self
+    where
+        F: Fn(&T) -> &V + Send + Sync + 'static,
+        V: 'static,
+        T: 'static,
+    {
+        let property_name: Arc<str> = Arc::from(property_name.into());
+        self.descriptors.retain(|descriptor| !is_within(&descriptor.property, &property_name));
+        #[cfg(feature = "async")]
+        self.async_rules.retain(|(property, _, _)| !is_within(property, &property_name));
+        self.rules = std::mem::take(&mut self.rules)
+            .into_iter()
+            .filter_map(|(property, mut kinds, rule)| -> Option<PropertyRule<T>> {
+                match property {
+                    Some(property) if is_within(&property, &property_name) => None,
+                    Some(property) => Some((Some(property), kinds, rule)),
+                    // Rules of included validators report errors for any property
+                    None => {
+                        kinds.retain(|(property, _)| !is_within(property, &property_name));
+                        let property_name = Arc::clone(&property_name);
+                        Some((None, kinds, Box::new(move |instance: &T, messages: &MessageContext| {
+                            let mut errors = rule(instance, messages);
+                            errors.retain(|error| !is_within(&error.property, &property_name));
+                            errors
+                        })))
+                    }
+                }
+            })
+            .collect();
+        self.rule_for(property_name.to_string(), accessor, builder)
+    }
+
+    /// Add the rules of an already built validator
+    ///
+    /// The validator runs in place with its own configuration, in the locale and context of
+    /// this validator. Only its synchronous rules run; include its builder with
+    /// [`include`](Self::include) to keep async rules.
+    ///
+    /// Each run is a full validation of the included validator: its observers and audit sink
+    /// see its own result, and only its errors and skipped rules are merged, not its
+    /// correlation ID. Include its builder instead to avoid duplicate audit records.
+    pub fn include_validator(mut self, validator: impl Validator<T> + 'static) -> Self {
+        let descriptors = validator.rules();
+        self.rules.push((None, kinds_of(&descriptors), Box::new(move |instance: &T, messages: &MessageContext| {
+            let result = match messages.data {
+                Some(data) => validator.validate_with_locale_and_context(instance, messages.locale, data),
+                None => validator.validate_with_locale(instance, messages.locale),
+            };
+            if let Some(skipped) = messages.skipped {
+                skipped.borrow_mut().extend_from_slice(result.skipped_rules());
+            }
+            result.errors().to_vec()
+        })));
+        self.descriptors.extend(descriptors);
+        self
+    }
+
+    /// Add an object-level rule together with its descriptor
+    pub(crate) fn add_rule(mut self, descriptor: RuleDescriptor, rule: impl Fn(&T, &MessageContext) -> Vec<ValidationError> + Send + Sync + 'static) -> Self {
+        self.rules.push((Some(descriptor.property.clone()), kinds_of(std::slice::from_ref(&descriptor)), Box::new(rule)));
+        self.descriptors.push(descriptor);
+        self
+    }
+
+    /// Add an async rule reporting errors for `property_name`, together with its descriptors
+    #[cfg(feature = "async")]
+    pub(crate) fn add_async_rule(mut self, property_name: String, descriptors: Vec<RuleDescriptor>, rule: AsyncRuleFn<T>) -> Self {
+        let kinds = descriptors.iter().map(|descriptor| descriptor.kind.clone()).collect();
+        self.descriptors.extend(descriptors);
+        self.async_rules.push((property_name, kinds, rule));
         self
     }
 
     /// Build the validator
-    pub fn build(self) -> impl Validator<T> {
-        ValidatorImpl { rules: self.rules }
+    ///
+    /// The validator shares its rules between clones, so cloning it is cheap.
+    pub fn build(self) -> impl Validator<T> + Clone {
+        ValidatorImpl {
+            rules: self.rules.into(),
+            descriptors: self.descriptors.into(),
+            message_provider: self.message_provider,
+            clock: self.clock,
+            schema_id: self.schema_id,
+            schema_version: self.schema_version,
+            correlation_ids: self.correlation_ids,
+            observers: self.observers.into(),
+            one_error_per_property: self.one_error_per_property,
+            empty_values: self.empty_values,
+            isolate_panics: self.isolate_panics,
+            limits: self.limits,
+            max_errors: self.max_errors,
+            record_skipped_rules: self.record_skipped_rules,
+            #[cfg(feature = "audit")]
+            audit: self.audit,
+            #[cfg(feature = "async")]
+            async_rules: self.async_rules.into(),
+        }
     }
 }
 
@@ -84,20 +506,245 @@ impl<T> Default for ValidatorBuilder<T> {
 }
 
 struct ValidatorImpl<T> {
-    rules: Vec<RuleFn<T>>,
+    rules: Arc<[PropertyRule<T>]>,
+    descriptors: Arc<[RuleDescriptor]>,
+    message_provider: Option<Arc<dyn MessageProvider>>,
+    clock: Option<Arc<dyn Clock>>,
+    /// Schema identifier and version stamped on results
+    schema_id: Option<Arc<str>>,
+    schema_version: Option<Arc<str>>,
+    correlation_ids: Option<Arc<dyn IdGenerator>>,
+    observers: Arc<[Observer]>,
+    one_error_per_property: bool,
+    empty_values: EmptyValues,
+    isolate_panics: bool,
+    limits: ResourceLimits,
+    max_errors: Option<usize>,
+    record_skipped_rules: bool,
+    #[cfg(feature = "audit")]
+    audit: Option<Arc<dyn AuditSink>>,
+    #[cfg(feature = "async")]
+    async_rules: Arc<[PropertyAsyncRule<T>]>,
+}
+
+impl<T> Clone for ValidatorImpl<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rules: Arc::clone(&self.rules),
+            descriptors: Arc::clone(&self.descriptors),
+            message_provider: self.message_provider.clone(),
+            clock: self.clock.clone(),
+            schema_id: self.schema_id.clone(),
+            schema_version: self.schema_version.clone(),
+            correlation_ids: self.correlation_ids.clone(),
+            observers: Arc::clone(&self.observers),
+            one_error_per_property: self.one_error_per_property,
+            empty_values: self.empty_values,
+            isolate_panics: self.isolate_panics,
+            limits: self.limits,
+            max_errors: self.max_errors,
+            record_skipped_rules: self.record_skipped_rules,
+            #[cfg(feature = "audit")]
+            audit: self.audit.clone(),
+            #[cfg(feature = "async")]
+            async_rules: Arc::clone(&self.async_rules),
+        }
+    }
 }
 
 impl<T> Validator<T> for ValidatorImpl<T> {
     fn validate(&self, instance: &T) -> ValidationResult {
-        let mut result = ValidationResult::new();
-        for rule in &self.rules {
-            let errors = rule(instance);
-            result.add_errors(errors);
+        messages::with_locale(|locale| self.validate_with_locale(instance, locale))
+    }
+
+    fn validate_with_locale(&self, instance: &T, locale: &str) -> ValidationResult {
+        self.finish(self.run(instance, locale, None, None), None, None)
+    }
+
+    fn validate_with_context(&self, instance: &T, context: &dyn Any) -> ValidationResult {
+        messages::with_locale(|locale| self.validate_with_locale_and_context(instance, locale, context))
+    }
+
+    fn validate_with_locale_and_context(&self, instance: &T, locale: &str, context: &dyn Any) -> ValidationResult {
+        self.finish(self.run(instance, locale, Some(context), None), Some(context), None)
+    }
+
+    fn validate_property(&self, instance: &T, property: &str) -> ValidationResult {
+        messages::with_locale(|locale| self.finish(self.run(instance, locale, None, Some(property)), None, Some(property)))
+    }
+
+    fn validate_property_with_context(&self, instance: &T, property: &str, context: &dyn Any) -> ValidationResult {
+        messages::with_locale(|locale| self.finish(self.run(instance, locale, Some(context), Some(property)), Some(context), Some(property)))
+    }
+
+    #[cfg(feature = "async")]
+    fn validate_async<'a>(&'a self, instance: &'a T) -> BoxFuture<'a, ValidationResult> {
+        self.run_async(instance, None)
+    }
+
+    #[cfg(feature = "async")]
+    fn validate_async_with_context<'a>(&'a self, instance: &'a T, context: &'a dyn Any) -> BoxFuture<'a, ValidationResult> {
+        self.run_async(instance, Some(context))
+    }
+
+    fn locales(&self) -> Vec<String> {
+        messages::available_locales(self.message_provider.as_deref())
+    }
+
+    fn rules(&self) -> Vec<RuleDescriptor> {
+        self.descriptors.to_vec()
+    }
+
+    fn fingerprint(&self) -> String {
+        traits::fingerprint(&self.descriptors, &[("empty_values", format!("{:?}", self.empty_values))])
+    }
+}
+
+impl<T> ValidatorImpl<T> {
+    /// Run the synchronous rules, then the async rules of properties without errors
+    #[cfg(feature = "async")]
+    fn run_async<'a>(&'a self, instance: &'a T, data: Option<&'a dyn Any>) -> BoxFuture<'a, ValidationResult> {
+        Box::pin(async move {
+            let locale = messages::locale();
+            let mut result = self.run(instance, &locale, data, None);
+            let skipped = RefCell::new(Vec::new());
+            let messages = MessageContext {
+                locale: &locale,
+                provider: self.message_provider.as_deref(),
+                data,
+                clock: self.clock.as_deref(),
+                empty_values: self.empty_values,
+                isolate_panics: self.isolate_panics,
+                limits: self.limits,
+                skipped: self.record_skipped_rules.then_some(&skipped),
+            };
+            for (index, (property_name, kinds, rule)) in self.async_rules.iter().enumerate() {
+                if result.is_truncated() || self.reached_max_errors(&mut result) {
+                    for (property_name, kinds, _) in &self.async_rules[index..] {
+                        messages.skip(&Arc::from(property_name.as_str()), kinds.iter().map(String::as_str), SkipReason::MaxErrors);
+                    }
+                    break;
+                }
+                if result.errors().iter().any(|error| *error.property == *property_name) {
+                    messages.skip(&Arc::from(property_name.as_str()), kinds.iter().map(String::as_str), SkipReason::PropertyFailed);
+                    continue;
+                }
+                let errors = rule(instance, &messages).await;
+                result.add_errors(errors);
+            }
+            result.add_skipped_rules(skipped.take());
+            self.cap_errors(&mut result);
+            self.finish(result, data, None)
+        })
+    }
+
+    /// Run the synchronous rules, or with `only` set, the rules that can report for that property
+    fn run(&self, instance: &T, locale: &str, data: Option<&dyn Any>, only: Option<&str>) -> ValidationResult {
+        let skipped = RefCell::new(Vec::new());
+        let messages = MessageContext {
+            locale,
+            provider: self.message_provider.as_deref(),
+            data,
+            clock: self.clock.as_deref(),
+            empty_values: self.empty_values,
+            isolate_panics: self.isolate_panics,
+            limits: self.limits,
+            skipped: self.record_skipped_rules.then_some(&skipped),
+        };
+        let mut result = match &self.schema_id {
+            Some(id) => ValidationResult::new().with_schema(Arc::clone(id), self.schema_version.clone()),
+            None => ValidationResult::new(),
+        };
+        if let Some(generator) = &self.correlation_ids {
+            result = result.with_correlation_id(generator.next_id());
+        }
+        let depth = limits::enter(self.limits.max_depth);
+        let rules: &[PropertyRule<T>] = if depth.is_some() { &self.rules } else { &[] };
+        if depth.is_none() {
+            let exceeded = ("depth", self.limits.max_depth.unwrap_or_default());
+            result.add_error(limits::resource_limit_error("".into(), exceeded, &messages, None));
+            skip_rules(&messages, &self.rules, SkipReason::ResourceLimit);
+        }
+        for (index, (property, _, rule)) in rules.iter().enumerate() {
+            if self.reached_max_errors(&mut result) {
+                skip_rules(&messages, &rules[index..], SkipReason::MaxErrors);
+                break;
+            }
+            let Some(only) = only else {
+                result.add_errors(rule(instance, &messages));
+                continue;
+            };
+            // A rule for `address` can report `address.city`, and one for `lines[0]` is part of `lines`
+            if property.as_deref().is_none_or(|property| is_within(property, only) || is_within(only, property)) {
+                let mut errors = rule(instance, &messages);
+                errors.retain(|error| is_within(&error.property, only));
+                result.add_errors(errors);
+            }
+        }
+        let mut skipped = skipped.take();
+        if let Some(only) = only {
+            skipped.retain(|skipped| is_within(&skipped.property, only));
+        }
+        result.add_skipped_rules(skipped);
+        self.cap_errors(&mut result);
+        result
+    }
+
+    /// Whether the result holds `max_errors` errors with rules left to run, marking it truncated
+    fn reached_max_errors(&self, result: &mut ValidationResult) -> bool {
+        match self.max_errors {
+            Some(max) if result.errors().len() >= max => {
+                result.truncate_errors(max);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Cut the errors down to `max_errors` after the last rule ran
+    fn cap_errors(&self, result: &mut ValidationResult) {
+        if let Some(max) = self.max_errors.filter(|&max| result.errors().len() > max) {
+            result.truncate_errors(max);
+        }
+    }
+
+    /// Apply the result-wide options and hand the final result to the audit sink and the
+    /// observers; `property` is the one property checked by `validate_property`, if any
+    #[cfg_attr(not(feature = "audit"), allow(unused_variables))]
+    fn finish(&self, mut result: ValidationResult, data: Option<&dyn Any>, property: Option<&str>) -> ValidationResult {
+        if self.one_error_per_property {
+            let mut seen = HashSet::new();
+            result.retain_errors(|error| seen.insert(error.property.clone()));
+        }
+        #[cfg(feature = "tracing-error")]
+        result.capture_span_trace();
+        #[cfg(feature = "audit")]
+        if let Some(sink) = &self.audit {
+            let actor = data.and_then(|data| data.downcast_ref::<ValidationContext>()).and_then(|context| context.value(ACTOR_KEY));
+            let now = self.clock.as_ref().map_or_else(SystemTime::now, |clock| clock.now());
+            sink.record(&AuditRecord::new(std::any::type_name::<T>(), property, actor, now, &result));
+        }
+        for observer in self.observers.iter() {
+            observer(&result);
         }
         result
     }
 }
 
+/// The properties and kinds of the rules described by `descriptors`
+fn kinds_of(descriptors: &[RuleDescriptor]) -> RuleKinds {
+    descriptors.iter().map(|descriptor| (Arc::from(descriptor.property.as_str()), descriptor.kind.clone())).collect()
+}
+
+/// Record the rules run by `rules` as skipped for `reason`
+fn skip_rules<T>(messages: &MessageContext, rules: &[PropertyRule<T>], reason: SkipReason) {
+    for (_, kinds, _) in rules {
+        for (property, kind) in kinds {
+            messages.skip(property, [kind.as_str()], reason);
+        }
+    }
+}
+
 /// Helper function to validate an instance with a validator
 pub fn validate<T>(instance: &T, validator: &dyn Validator<T>) -> ValidationResult {
     validator.validate(instance)
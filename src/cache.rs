@@ -0,0 +1,300 @@
+//! Reusing results for identical payloads
+
+use std::any::Any;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "async")]
+use crate::async_rule::BoxFuture;
+use crate::error::ValidationResult;
+use crate::messages;
+use crate::rule::RuleDescriptor;
+use crate::traits::Validator;
+
+/// Storage for validation results, keyed by a content hash of the validated payload
+///
+/// Implement it over a shared store (Redis, memcached, ...) to reuse results across
+/// instances; [`MemoryCache`] keeps them in process.
+pub trait ResultCache: Send + Sync {
+    /// The stored result for `key`, if present and not expired
+    fn get(&self, key: &str) -> Option<ValidationResult>;
+
+    /// Store the result for `key`
+    fn put(&self, key: &str, result: &ValidationResult);
+
+    /// Drop the result for `key`
+    fn invalidate(&self, key: &str);
+
+    /// Drop every result, e.g. after the rules or their configuration changed
+    fn invalidate_all(&self);
+}
+
+/// In-process [`ResultCache`] holding at most `capacity` results, each for a fixed time to
+/// live
+///
+/// Payload keys usually come from request bodies, so the capacity bounds the memory a client
+/// can make the cache hold. When full, the oldest result is evicted.
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<Entries>,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    /// Results with their insertion number and time
+    results: HashMap<String, (u64, Instant, ValidationResult)>,
+    /// Keys in insertion order; since every result has the same time to live, the oldest
+    /// insertions expire first. Holds stale keys of replaced or invalidated results too.
+    order: VecDeque<(u64, Instant, String)>,
+    inserted: u64,
+}
+
+impl MemoryCache {
+    /// Create a cache keeping at most `capacity` results, each for `ttl`
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Number of stored results, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).results.len()
+    }
+
+    /// Whether no results are stored
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl ResultCache for MemoryCache {
+    fn get(&self, key: &str) -> Option<ValidationResult> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.results.get(key) {
+            Some((_, stored, result)) if stored.elapsed() < self.ttl => Some(result.clone()),
+            Some(_) => {
+                entries.results.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Evicts from the front of the insertion order only, so a put takes amortized constant
+    /// time
+    fn put(&self, key: &str, result: &ValidationResult) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let Entries { results, order, inserted } = &mut *entries;
+        while let Some((_, stored, _)) = order.front() {
+            if order.len() < self.capacity && stored.elapsed() < self.ttl {
+                break;
+            }
+            if let Some((number, _, oldest)) = order.pop_front() {
+                if results.get(&oldest).is_some_and(|(current, _, _)| *current == number) {
+                    results.remove(&oldest);
+                }
+            }
+        }
+        *inserted += 1;
+        let now = Instant::now();
+        results.insert(key.to_string(), (*inserted, now, result.clone()));
+        order.push_back((*inserted, now, key.to_string()));
+    }
+
+    fn invalidate(&self, key: &str) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).results.remove(key);
+    }
+
+    fn invalidate_all(&self) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.results.clear();
+        entries.order.clear();
+    }
+}
+
+/// Outcomes of a rule memoized by the hash of the checked value, see `RuleBuilder::cached`
+pub(crate) struct RuleMemo<V> {
+    capacity: usize,
+    ttl: Duration,
+    hasher: RandomState,
+    entries: Mutex<HashMap<u64, (Instant, V)>>,
+}
+
+impl<V: Clone> RuleMemo<V> {
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            hasher: RandomState::new(),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The memoized outcome for `value`, computing and storing it when missing or expired
+    ///
+    /// When full, expired entries are dropped first, then the oldest one.
+    pub(crate) fn get_or_insert_with<T: Hash + ?Sized>(&self, value: &T, compute: impl FnOnce() -> V) -> V {
+        let key = self.hasher.hash_one(value);
+        if let Some((stored, outcome)) = self.entries.lock().unwrap_or_else(|e| e.into_inner()).get(&key) {
+            if stored.elapsed() < self.ttl {
+                return outcome.clone();
+            }
+        }
+        // Computed without the lock, so other threads aren't held up by a slow rule
+        let outcome = compute();
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            entries.retain(|_, (stored, _)| stored.elapsed() < self.ttl);
+            if entries.len() >= self.capacity {
+                let oldest = entries.iter().min_by_key(|(_, (stored, _))| *stored).map(|(key, _)| *key);
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        if self.capacity > 0 {
+            entries.insert(key, (Instant::now(), outcome.clone()));
+        }
+        outcome
+    }
+}
+
+/// A validator that reuses the results of identical payloads, for idempotent endpoints
+///
+/// Results are looked up by a content hash the caller derives from the payload, such as a
+/// SHA-256 digest of its canonical serialization, so a retried request skips expensive
+/// rules. Don't key by an `Idempotency-Key` header alone: a key reused with a different body
+/// would get the old body's result. Only `validate` and `validate_async` use the cache, and
+/// they keep separate entries, since results of `validate` never ran the async rules. Entries
+/// are also kept per global locale, as the messages of a result are rendered in it.
+/// Validating with a locale, a context or a single property always runs the rules, as their
+/// results depend on more than the payload. Cached results keep the correlation ID of the run
+/// that produced them, and observers of the wrapped validator only see that run.
+///
+/// # Example
+/// ```rust,ignore
+/// let validator = CachedValidator::new(order_validator(), MemoryCache::new(10_000, Duration::from_secs(300)),
+///     |order: &Order| hex::encode(Sha256::digest(serde_json::to_vec(order).unwrap())));
+///
+/// let result = validator.validate(&order);
+/// ```
+pub struct CachedValidator<T, V, C> {
+    validator: V,
+    cache: C,
+    key: Box<dyn Fn(&T) -> String + Send + Sync>,
+}
+
+impl<T, V: Validator<T>, C: ResultCache> CachedValidator<T, V, C> {
+    /// Cache the results of `validator` in `cache`, keyed by `key`
+    pub fn new(validator: V, cache: C, key: impl Fn(&T) -> String + Send + Sync + 'static) -> Self {
+        Self {
+            validator,
+            cache,
+            key: Box::new(key),
+        }
+    }
+
+    /// Drop the cached results for a payload, in the current locale and every locale the
+    /// validator serves
+    pub fn invalidate(&self, instance: &T) {
+        let key = (self.key)(instance);
+        let mut locales = self.validator.locales();
+        locales.push(messages::locale());
+        for locale in &locales {
+            self.cache.invalidate(&sync_key(&key, locale));
+            self.cache.invalidate(&async_key(&key, locale));
+        }
+    }
+
+    /// Drop every cached result
+    pub fn invalidate_all(&self) {
+        self.cache.invalidate_all();
+    }
+
+    /// The underlying cache
+    pub fn cache(&self) -> &C {
+        &self.cache
+    }
+}
+
+impl<T, V: Validator<T>, C: ResultCache> Validator<T> for CachedValidator<T, V, C> {
+    fn validate(&self, instance: &T) -> ValidationResult {
+        messages::with_locale(|locale| {
+            let key = sync_key(&(self.key)(instance), locale);
+            if let Some(result) = self.cache.get(&key) {
+                return result;
+            }
+            let result = self.validator.validate_with_locale(instance, locale);
+            self.cache.put(&key, &result);
+            result
+        })
+    }
+
+    fn validate_with_locale(&self, instance: &T, locale: &str) -> ValidationResult {
+        self.validator.validate_with_locale(instance, locale)
+    }
+
+    fn validate_with_context(&self, instance: &T, context: &dyn Any) -> ValidationResult {
+        self.validator.validate_with_context(instance, context)
+    }
+
+    fn validate_with_locale_and_context(&self, instance: &T, locale: &str, context: &dyn Any) -> ValidationResult {
+        self.validator.validate_with_locale_and_context(instance, locale, context)
+    }
+
+    fn validate_property(&self, instance: &T, property: &str) -> ValidationResult {
+        self.validator.validate_property(instance, property)
+    }
+
+    fn validate_property_with_context(&self, instance: &T, property: &str, context: &dyn Any) -> ValidationResult {
+        self.validator.validate_property_with_context(instance, property, context)
+    }
+
+    #[cfg(feature = "async")]
+    fn validate_async<'a>(&'a self, instance: &'a T) -> BoxFuture<'a, ValidationResult> {
+        Box::pin(async move {
+            let key = async_key(&(self.key)(instance), &messages::locale());
+            if let Some(result) = self.cache.get(&key) {
+                return result;
+            }
+            let result = self.validator.validate_async(instance).await;
+            self.cache.put(&key, &result);
+            result
+        })
+    }
+
+    #[cfg(feature = "async")]
+    fn validate_async_with_context<'a>(&'a self, instance: &'a T, context: &'a dyn Any) -> BoxFuture<'a, ValidationResult> {
+        self.validator.validate_async_with_context(instance, context)
+    }
+
+    fn locales(&self) -> Vec<String> {
+        self.validator.locales()
+    }
+
+    fn rules(&self) -> Vec<RuleDescriptor> {
+        self.validator.rules()
+    }
+}
+
+/// The cache key of a result of `validate` for the caller's payload key, rendered in `locale`
+fn sync_key(key: &str, locale: &str) -> String {
+    format!("sync:{}:{}", locale, key)
+}
+
+/// The cache key of a result of `validate_async`, kept apart from `validate`'s since those
+/// never ran the async rules
+fn async_key(key: &str, locale: &str) -> String {
+    format!("async:{}:{}", locale, key)
+}
@@ -0,0 +1,91 @@
+//! Editorial capitalization rules with locale-aware Unicode case mapping (feature `casemap`)
+
+use icu_casemap::titlecase::{TitlecaseOptions, TrailingCase};
+use icu_casemap::{CaseMapper, TitlecaseMapper};
+use icu_locid::LanguageIdentifier;
+
+use crate::rule::{str_is_empty, str_value, RuleBuilder};
+
+const CASE_MAPPER: CaseMapper = CaseMapper::new();
+const TITLECASE_MAPPER: TitlecaseMapper<CaseMapper> = TitlecaseMapper::new();
+
+impl<T> RuleBuilder<T> {
+    /// Validate that every word starts with a capital letter, e.g. `"The Lord Of The Rings"`
+    ///
+    /// Casing follows the rules of the content's language: in Turkish (`"tr"`), `"istanbul"`
+    /// capitalizes to `"İstanbul"`. The rest of each word is not checked, so acronyms and
+    /// names like `"McDonald"` pass. The first offending word is reported in the error state
+    /// as `word`.
+    ///
+    /// # Arguments
+    /// * `locale` - Language of the content, e.g. `Some("tr")`; `None` uses the validation locale
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn title_case(self, locale: Option<&str>, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let content_locale = locale.map(str::to_string);
+        let params = content_locale.iter().map(|l| ("locale", l.clone())).collect();
+        self.empty_when(str_is_empty::<T>).built_in_detailed("title_case", params, message.map(|m| m.into()),
+            move |value, messages| {
+                let langid = language(content_locale.as_deref().unwrap_or(messages.locale));
+                let word = value.as_ref().split_whitespace().find(|word| !is_capitalized(word, &langid))?;
+                Some(vec![("word", word.to_string())])
+            },
+            Some(str_value::<T>))
+    }
+
+    /// Validate that only the first word of each sentence is capitalized, e.g.
+    /// `"The quick brown fox. It jumps."`
+    ///
+    /// Other words must be lowercase, except all-uppercase words such as acronyms (`"NASA"`)
+    /// or the English `"I"`. Sentences start after `.`, `!` or `?`. Casing is locale-aware
+    /// as for [`title_case`](Self::title_case), and the first offending word is reported in
+    /// the error state as `word`.
+    ///
+    /// # Arguments
+    /// * `locale` - Language of the content, e.g. `Some("tr")`; `None` uses the validation locale
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn sentence_case(self, locale: Option<&str>, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let content_locale = locale.map(str::to_string);
+        let params = content_locale.iter().map(|l| ("locale", l.clone())).collect();
+        self.empty_when(str_is_empty::<T>).built_in_detailed("sentence_case", params, message.map(|m| m.into()),
+            move |value, messages| {
+                let langid = language(content_locale.as_deref().unwrap_or(messages.locale));
+                let mut sentence_start = true;
+                for word in value.as_ref().split_whitespace() {
+                    let uppercase = CASE_MAPPER.uppercase_to_string(word, &langid) == word;
+                    let valid = if sentence_start {
+                        uppercase || titlecase(word, &langid, TrailingCase::Lower) == word
+                    } else {
+                        uppercase || CASE_MAPPER.lowercase_to_string(word, &langid) == word
+                    };
+                    if !valid {
+                        return Some(vec![("word", word.to_string())]);
+                    }
+                    sentence_start = word.ends_with(['.', '!', '?']);
+                }
+                None
+            },
+            Some(str_value::<T>))
+    }
+}
+
+/// The language of a locale tag, or the root language when it can't be parsed
+fn language(locale: &str) -> LanguageIdentifier {
+    locale.parse().unwrap_or_default()
+}
+
+/// Whether the word starts with a capital letter, ignoring the rest of the word
+fn is_capitalized(word: &str, langid: &LanguageIdentifier) -> bool {
+    titlecase(word, langid, TrailingCase::Unchanged) == word
+}
+
+fn titlecase(word: &str, langid: &LanguageIdentifier, trailing_case: TrailingCase) -> String {
+    let mut options = TitlecaseOptions::default();
+    options.trailing_case = trailing_case;
+    TITLECASE_MAPPER.titlecase_segment_to_string(word, langid, options)
+}
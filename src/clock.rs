@@ -0,0 +1,160 @@
+//! The current time as seen by time-dependent rules, and rules comparing values against it
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::rule::RuleBuilder;
+
+/// Source of the current time for time-dependent rules such as `in_past` and `age_at_least`
+///
+/// Validators read the system clock unless one is set with `ValidatorBuilder::with_clock`;
+/// tests pass a [`FixedClock`] so results don't depend on when they run.
+pub trait Clock: Send + Sync {
+    /// The current time
+    fn now(&self) -> SystemTime;
+}
+
+/// The system's wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock frozen at a chosen time, which only moves when told to
+///
+/// Share it with an `Arc` to move it between validations.
+///
+/// # Example
+/// ```rust,ignore
+/// let clock = Arc::new(FixedClock::new(UNIX_EPOCH + Duration::from_secs(1_700_000_000)));
+/// let validator = ValidatorBuilder::<User>::new()
+///     .with_clock(Arc::clone(&clock))
+///     .rule_for("birth_date", |u| &u.birth_date, RuleBuilder::for_property("birth_date").age_at_least(18, None::<String>))
+///     .build();
+///
+/// clock.advance(Duration::from_secs(86_400));
+/// ```
+#[derive(Debug)]
+pub struct FixedClock {
+    now: Mutex<SystemTime>,
+}
+
+impl FixedClock {
+    /// Create a clock showing `now`
+    pub fn new(now: SystemTime) -> Self {
+        Self { now: Mutex::new(now) }
+    }
+
+    /// Move the clock to `now`
+    pub fn set(&self, now: SystemTime) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) = now;
+    }
+
+    /// Move the clock forward by `by`
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) += by;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> SystemTime {
+        (**self).now()
+    }
+}
+
+/// A value denoting a point in time, checked by `in_past`, `in_future` and `age_at_least`
+///
+/// Implemented for `SystemTime`, and with the `chrono` feature for chrono's `DateTime`,
+/// `NaiveDateTime` and `NaiveDate` (naive values are taken as UTC, dates as midnight).
+pub trait Timestamp {
+    /// The point in time as a `SystemTime`
+    fn to_system_time(&self) -> SystemTime;
+}
+
+impl Timestamp for SystemTime {
+    fn to_system_time(&self) -> SystemTime {
+        *self
+    }
+}
+
+impl<T: Timestamp> RuleBuilder<T> {
+    /// Validate that the value lies before the current time
+    ///
+    /// The current time comes from the validator's clock, see `ValidatorBuilder::with_clock`.
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn in_past(self, message: Option<impl Into<String>>) -> Self {
+        self.built_in_detailed("in_past", Vec::new(), message.map(|m| m.into()),
+            |value, messages| (value.to_system_time() >= messages.now()).then(Vec::new),
+            None)
+    }
+
+    /// Validate that the value lies after the current time
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn in_future(self, message: Option<impl Into<String>>) -> Self {
+        self.built_in_detailed("in_future", Vec::new(), message.map(|m| m.into()),
+            |value, messages| (value.to_system_time() <= messages.now()).then(Vec::new),
+            None)
+    }
+
+    /// Validate that a birth date is at least `years` whole years before the current date
+    ///
+    /// Ages are counted in calendar years in UTC; someone born on 29 February turns a year
+    /// older on 1 March in common years.
+    ///
+    /// # Arguments
+    /// * `years` - The minimum age
+    /// * `message` - Optional custom error message. `{years}` is replaced with the minimum age.
+    pub fn age_at_least(self, years: u32, message: Option<impl Into<String>>) -> Self {
+        self.built_in_detailed("age_at_least", vec![("years", years.to_string())], message.map(|m| m.into()),
+            move |value, messages| (age(value.to_system_time(), messages.now()) < i64::from(years)).then(Vec::new),
+            None)
+    }
+}
+
+/// Whole calendar years from `birth` to `now`, negative if `birth` is in the future
+fn age(birth: SystemTime, now: SystemTime) -> i64 {
+    let (birth_year, birth_month, birth_day) = civil_date(birth);
+    let (year, month, day) = civil_date(now);
+    let age = year - birth_year;
+    if (month, day) < (birth_month, birth_day) {
+        age - 1
+    } else {
+        age
+    }
+}
+
+/// The UTC calendar date of a point in time, as (year, month, day)
+fn civil_date(time: SystemTime) -> (i64, u32, u32) {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => after.as_secs() as i64,
+        Err(before) => {
+            let before = before.duration();
+            -(before.as_secs() as i64) - i64::from(before.subsec_nanos() > 0)
+        }
+    };
+    // Howard Hinnant's days-to-civil algorithm, on 400-year eras starting 1 March
+    let days = seconds.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,235 @@
+//! Rules relating the elements of collection properties
+
+use std::cmp::Ordering;
+
+use crate::builder::ValidatorBuilder;
+use crate::error::ValidationError;
+use crate::limits::{self, InputSize};
+use crate::messages::{render, MessageContext};
+use crate::rule::{element_count, FailureDetails};
+use crate::rule::{RuleBuilder, RuleDescriptor};
+
+impl<T> RuleBuilder<T> {
+    /// Validate that the elements are in ascending (non-decreasing) order
+    ///
+    /// The first out-of-order index is reported in the message and as `index` in the error state.
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message. `{index}` is replaced with the out-of-order index.
+    pub fn sorted_ascending<E>(self, message: Option<impl Into<String>>) -> Self
+    where
+        for<'a> &'a T: IntoIterator<Item = &'a E>,
+        E: PartialOrd + 'static,
+    {
+        self.sized_by(element_count::<T, E>).built_in_detailed("sorted_ascending", Vec::new(), message.map(|m| m.into()),
+            |value, _| first_out_of_order(value.into_iter(), |previous: &&E, current: &&E| previous <= current),
+            None)
+    }
+
+    /// Validate that a key extracted from each element strictly increases
+    ///
+    /// Useful for time series (`|point| point.timestamp`) or tiered tables (`|tier| tier.threshold`).
+    /// The first index whose key is not greater than its predecessor's is reported in the
+    /// message and as `index` in the error state.
+    ///
+    /// # Arguments
+    /// * `key` - Function extracting the ordered key from an element
+    /// * `message` - Optional custom error message. `{index}` is replaced with the out-of-order index.
+    pub fn strictly_increasing<E, K>(self, key: impl Fn(&E) -> K + Send + Sync + 'static, message: Option<impl Into<String>>) -> Self
+    where
+        for<'a> &'a T: IntoIterator<Item = &'a E>,
+        E: 'static,
+        K: PartialOrd,
+    {
+        self.sized_by(element_count::<T, E>).built_in_detailed("strictly_increasing", Vec::new(), message.map(|m| m.into()),
+            move |value, _| first_out_of_order(value.into_iter().map(&key), |previous: &K, current: &K| previous < current),
+            None)
+    }
+}
+
+/// Failure details naming the first element that breaks the ordering, if any
+fn first_out_of_order<I: Iterator>(items: I, in_order: impl Fn(&I::Item, &I::Item) -> bool) -> Option<FailureDetails> {
+    let mut previous = None;
+    for (index, item) in items.enumerate() {
+        if let Some(previous) = &previous {
+            if !in_order(previous, &item) {
+                return Some(vec![("index", index.to_string())]);
+            }
+        }
+        previous = Some(item);
+    }
+    None
+}
+
+/// The collection limit `items` exceeds, counting no further than one element past it
+fn exceeded_len<I: IntoIterator>(items: I, messages: &MessageContext) -> Option<(&'static str, usize)> {
+    let max = messages.limits.max_collection_len?;
+    messages.limits.exceeded(InputSize::Elements(items.into_iter().take(max.saturating_add(1)).count()))
+}
+
+/// The index pairs `(first, second)`, `first < second`, of the half-open intervals that
+/// overlap, ordered by `second` then `first`
+///
+/// Sweeps the intervals by start, keeping those still open, so intervals that don't overlap
+/// cost `O(n log n)`. Starts that can't be compared, such as NaN, overlap nothing.
+fn overlapping_pairs<K: PartialOrd>(intervals: &[(K, K)]) -> Vec<(usize, usize)> {
+    let mut by_start: Vec<usize> = (0..intervals.len()).filter(|&i| intervals[i].0.partial_cmp(&intervals[i].0).is_some()).collect();
+    by_start.sort_by(|&a, &b| intervals[a].0.partial_cmp(&intervals[b].0).unwrap_or(Ordering::Equal));
+    let mut open: Vec<usize> = Vec::new();
+    let mut pairs = Vec::new();
+    for index in by_start {
+        let (start, end) = &intervals[index];
+        // Later intervals start at or after `start`, so one ending by then overlaps none of them
+        open.retain(|&other| intervals[other].1 > *start);
+        for &other in &open {
+            let (other_start, other_end) = &intervals[other];
+            if start < other_end && other_start < end {
+                pairs.push((other.min(index), other.max(index)));
+            }
+        }
+        open.push(index);
+    }
+    pairs.sort_unstable_by_key(|&(first, second)| (second, first));
+    pairs
+}
+
+impl<T> ValidatorBuilder<T> {
+    /// Validate that two parallel collections have the same number of elements
+    ///
+    /// The error is reported on `property`, with the two lengths in its state and as message
+    /// placeholders (`left_len`, `right_len`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .same_length("values", |c| &c.labels, |c| &c.values, None::<String>)
+    /// ```
+    pub fn same_length<L, R, A, B, FL, FR>(self, property_name: impl Into<String>, left: FL, right: FR, message: Option<impl Into<String>>) -> Self
+    where
+        FL: Fn(&T) -> &L + Send + Sync + 'static,
+        FR: Fn(&T) -> &R + Send + Sync + 'static,
+        L: ?Sized + 'static,
+        R: ?Sized + 'static,
+        for<'a> &'a L: IntoIterator<Item = &'a A>,
+        for<'a> &'a R: IntoIterator<Item = &'a B>,
+        A: 'static,
+        B: 'static,
+    {
+        let property_name = property_name.into();
+        let msg = message.map(|m| m.into());
+        let descriptor = RuleDescriptor::new(property_name.clone(), "same_length", Vec::new()).with_message(msg.clone());
+        self.add_rule(descriptor, move |instance: &T, messages: &MessageContext| {
+            if let Some(exceeded) = exceeded_len(left(instance), messages).or_else(|| exceeded_len(right(instance), messages)) {
+                return vec![limits::resource_limit_error(property_name.as_str().into(), exceeded, messages, None)];
+            }
+            let left_len = left(instance).into_iter().count();
+            let right_len = right(instance).into_iter().count();
+            if left_len == right_len {
+                return Vec::new();
+            }
+            let params = vec![
+                ("left_len".to_string(), left_len.to_string()),
+                ("right_len".to_string(), right_len.to_string()),
+            ];
+            let message = match &msg {
+                Some(msg) => render(msg, &params),
+                None => messages.message(None, "same_length", &params),
+            };
+            vec![ValidationError::new(property_name.clone(), message)
+                .with_code("SAME_LENGTH")
+                .with_state("left_len", left_len)
+                .with_state("right_len", right_len)]
+        })
+    }
+
+    /// Validate a predicate over the element pairs of two parallel collections
+    ///
+    /// Elements are paired by index (extra elements of the longer collection are ignored;
+    /// combine with `same_length` to reject them). Every failing pair is reported on
+    /// `property[index]`, and `{CollectionIndex}` in the message is replaced with the index.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .each_pair("values", |c| &c.labels, |c| &c.values,
+    ///     |label, value| !label.is_empty() || *value == 0,
+    ///     "value {CollectionIndex} needs a label")
+    /// ```
+    pub fn each_pair<L, R, A, B, FL, FR, P>(self, property_name: impl Into<String>, left: FL, right: FR, predicate: P, message: impl Into<String>) -> Self
+    where
+        FL: Fn(&T) -> &L + Send + Sync + 'static,
+        FR: Fn(&T) -> &R + Send + Sync + 'static,
+        L: ?Sized + 'static,
+        R: ?Sized + 'static,
+        for<'a> &'a L: IntoIterator<Item = &'a A>,
+        for<'a> &'a R: IntoIterator<Item = &'a B>,
+        A: 'static,
+        B: 'static,
+        P: Fn(&A, &B) -> bool + Send + Sync + 'static,
+    {
+        let property_name = property_name.into();
+        let msg = message.into();
+        let descriptor = RuleDescriptor::new(property_name.clone(), "each_pair", Vec::new()).with_message(Some(msg.clone())).without_code();
+        self.add_rule(descriptor, move |instance: &T, messages: &MessageContext| {
+            if let Some(exceeded) = exceeded_len(left(instance), messages).or_else(|| exceeded_len(right(instance), messages)) {
+                return vec![limits::resource_limit_error(property_name.as_str().into(), exceeded, messages, None)];
+            }
+            left(instance)
+                .into_iter()
+                .zip(right(instance))
+                .enumerate()
+                .filter(|(_, (a, b))| !predicate(a, b))
+                .map(|(index, _)| {
+                    ValidationError::new(
+                        format!("{}[{}]", property_name, index),
+                        msg.replace("{CollectionIndex}", &index.to_string()),
+                    )
+                })
+                .collect()
+        })
+    }
+
+    /// Validate that the `(start, end)` intervals of a collection don't overlap
+    ///
+    /// Intervals are half-open, so a slot ending at 10:00 may be followed by one starting
+    /// at 10:00. Every conflicting pair is reported on the later element, `property[second]`,
+    /// with both indices in its state (`first`, `second`) and as message placeholders.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .non_overlapping("slots", |b| &b.slots, |slot| (slot.start, slot.end), None::<String>)
+    /// ```
+    pub fn non_overlapping<C, E, K, F, I>(self, property_name: impl Into<String>, accessor: F, interval: I, message: Option<impl Into<String>>) -> Self
+    where
+        F: Fn(&T) -> &C + Send + Sync + 'static,
+        C: ?Sized + 'static,
+        for<'a> &'a C: IntoIterator<Item = &'a E>,
+        E: 'static,
+        I: Fn(&E) -> (K, K) + Send + Sync + 'static,
+        K: PartialOrd,
+    {
+        let property_name = property_name.into();
+        let msg = message.map(|m| m.into());
+        let descriptor = RuleDescriptor::new(property_name.clone(), "non_overlapping", Vec::new()).with_message(msg.clone());
+        self.add_rule(descriptor, move |instance: &T, messages: &MessageContext| {
+            if let Some(exceeded) = exceeded_len(accessor(instance), messages) {
+                return vec![limits::resource_limit_error(property_name.as_str().into(), exceeded, messages, None)];
+            }
+            let intervals: Vec<(K, K)> = accessor(instance).into_iter().map(&interval).collect();
+            let mut errors = Vec::new();
+            for (first, second) in overlapping_pairs(&intervals) {
+                let params = vec![
+                    ("first".to_string(), first.to_string()),
+                    ("second".to_string(), second.to_string()),
+                ];
+                let message = match &msg {
+                    Some(msg) => render(msg, &params),
+                    None => messages.message(None, "non_overlapping", &params),
+                };
+                errors.push(ValidationError::new(format!("{}[{}]", property_name, second), message)
+                    .with_code("NON_OVERLAPPING")
+                    .with_state("first", first)
+                    .with_state("second", second));
+            }
+            errors
+        })
+    }
+}
@@ -0,0 +1,500 @@
+//! Validators defined in YAML or TOML instead of code (feature `config`)
+//!
+//! A definition lists rules by property, rule name, parameters and an optional message, and
+//! compiles into a [`DynamicValidator`] that checks JSON values. Limits can then be tuned
+//! in configuration without recompiling the service:
+//!
+//! ```yaml
+//! rules:
+//!   - property: name
+//!     rule: length
+//!     params: { min: 2, max: 50 }
+//!   - property: address.country
+//!     rule: not_empty
+//!     message: Country is required
+//!   - property: age
+//!     rule: inclusive_between
+//!     params: { min: 18, max: 120 }
+//! ```
+//!
+//! Dotted properties address nested objects. A missing property is `null`, which only
+//! `not_empty` and `not_null` reject; other rules also reject values of the wrong JSON type.
+//!
+//! # Example
+//! ```rust,ignore
+//! use fluentval::config::DynamicValidator;
+//!
+//! let validator = DynamicValidator::from_yaml(&std::fs::read_to_string("rules.yaml")?)?;
+//! let result = validator.validate(&serde_json::json!({"name": "A", "age": 15}));
+//! ```
+//!
+//! # Fuzzing
+//! [`validate_json_bytes`] takes raw fuzzer input, and [`corpus_seeds`] derives starting
+//! inputs from a validator's rules, e.g. for a `cargo fuzz` target:
+//!
+//! ```rust,ignore
+//! fuzz_target!(|data: &[u8]| {
+//!     let _ = fluentval::config::validate_json_bytes(data, &*VALIDATOR);
+//! });
+//!
+//! // Seeding the corpus once
+//! for (i, seed) in corpus_seeds(&VALIDATOR.rules()).iter().enumerate() {
+//!     std::fs::write(format!("fuzz/corpus/validate/seed-{}", i), seed)?;
+//! }
+//! ```
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::builder::ValidatorBuilder;
+use crate::error::ValidationResult;
+use crate::expr::Expression;
+use crate::http::{is_header_value, is_safe_outbound_url, is_token, HTTP_METHODS};
+use crate::rule::{is_email, RuleBuilder, RuleDescriptor};
+use crate::traits::Validator;
+
+/// Version of the definition format written by this crate; newer definitions are rejected
+pub const FORMAT_VERSION: u32 = 1;
+
+/// A validator definition, as deserialized from YAML, TOML or JSON
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ValidatorDefinition {
+    /// Format version the definition was written in, see [`FORMAT_VERSION`]
+    #[serde(default = "format_version")]
+    pub version: u32,
+    /// The rules, in evaluation order
+    #[serde(default)]
+    pub rules: Vec<RuleDefinition>,
+}
+
+fn format_version() -> u32 {
+    FORMAT_VERSION
+}
+
+impl Default for ValidatorDefinition {
+    fn default() -> Self {
+        Self { version: FORMAT_VERSION, rules: Vec::new() }
+    }
+}
+
+/// One rule of a [`ValidatorDefinition`]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RuleDefinition {
+    /// Property the rule applies to; dots address nested objects, e.g. `address.city`
+    pub property: String,
+    /// Rule name, e.g. `min_length`
+    pub rule: String,
+    /// Rule parameters, e.g. `{ min: 2 }`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, Value>,
+    /// Custom error message, replacing the rule's default message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Error code, replacing the rule's default code (its name in upper case)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+}
+
+/// Why a validator definition couldn't be loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The document isn't valid YAML or TOML, or doesn't have the definition's shape
+    Parse(String),
+    /// The rule name isn't supported
+    UnknownRule { property: String, rule: String },
+    /// A parameter the rule needs is missing or has the wrong type
+    InvalidParam { property: String, rule: String, param: String },
+    /// The definition was written in a newer format than this version of the crate reads
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Parse(message) => write!(f, "invalid validator definition: {}", message),
+            ConfigError::UnknownRule { property, rule } => write!(f, "unknown rule `{}` on `{}`", rule, property),
+            ConfigError::InvalidParam { property, rule, param } => {
+                write!(f, "`{}` on `{}` needs a valid `{}` parameter", rule, property, param)
+            }
+            ConfigError::UnsupportedVersion(version) => {
+                write!(f, "definition format version {} is newer than the supported version {}", version, FORMAT_VERSION)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl ValidatorDefinition {
+    /// Parse a definition from YAML
+    pub fn from_yaml(source: &str) -> Result<Self, ConfigError> {
+        serde_yaml::from_str(source).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+
+    /// Parse a definition from TOML, with the rules as a `[[rules]]` array of tables
+    pub fn from_toml(source: &str) -> Result<Self, ConfigError> {
+        toml::from_str(source).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+
+    /// Parse a definition from JSON, e.g. one written with [`to_json`](Self::to_json)
+    pub fn from_json(source: &str) -> Result<Self, ConfigError> {
+        serde_json::from_str(source).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+
+    /// Write the definition as JSON, to be loaded with [`from_json`](Self::from_json)
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("definitions serialize to JSON")
+    }
+
+    /// Write the definition as YAML
+    pub fn to_yaml(&self) -> String {
+        serde_yaml::to_string(self).expect("definitions serialize to YAML")
+    }
+
+    /// Describe the rules of a validator built in code as a definition, e.g. to distribute
+    /// them to services that load them with [`DynamicValidator`]
+    ///
+    /// Only rules a definition can express are described, with the same parameters and
+    /// custom messages and codes; other rules, such as `must` or rules on collection elements, fail
+    /// with [`ConfigError::UnknownRule`] or [`ConfigError::InvalidParam`]. Filter them out
+    /// first to export the declarative subset of a validator. Properties are looked up in
+    /// JSON as named, so they should match the serialized field names.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let definition = ValidatorDefinition::from_rules(&signup_validator().rules())?;
+    /// std::fs::write("signup.rules.json", definition.to_json())?;
+    /// ```
+    pub fn from_rules(rules: &[RuleDescriptor]) -> Result<Self, ConfigError> {
+        let rules = rules.iter().map(describe).collect::<Result<_, _>>()?;
+        Ok(Self { version: FORMAT_VERSION, rules })
+    }
+
+    /// Compile the definition into a validator
+    pub fn build(&self) -> Result<DynamicValidator, ConfigError> {
+        if self.version > FORMAT_VERSION {
+            return Err(ConfigError::UnsupportedVersion(self.version));
+        }
+        let mut builder = ValidatorBuilder::<Value>::new();
+        for definition in &self.rules {
+            let rule = compile(definition)?;
+            let path: Vec<String> = definition.property.split('.').map(str::to_string).collect();
+            builder = builder.rule_for(definition.property.clone(), move |root: &Value| lookup(root, &path), rule);
+        }
+        Ok(DynamicValidator {
+            inner: Arc::new(builder.build()),
+            definition: Arc::new(self.clone()),
+        })
+    }
+}
+
+/// A validator compiled from a [`ValidatorDefinition`], checking JSON values
+#[derive(Clone)]
+pub struct DynamicValidator {
+    inner: Arc<dyn Validator<Value>>,
+    definition: Arc<ValidatorDefinition>,
+}
+
+impl DynamicValidator {
+    /// Load a validator from a YAML definition
+    pub fn from_yaml(source: &str) -> Result<Self, ConfigError> {
+        ValidatorDefinition::from_yaml(source)?.build()
+    }
+
+    /// Load a validator from a TOML definition
+    pub fn from_toml(source: &str) -> Result<Self, ConfigError> {
+        ValidatorDefinition::from_toml(source)?.build()
+    }
+
+    /// Load a validator from a JSON definition
+    pub fn from_json(source: &str) -> Result<Self, ConfigError> {
+        ValidatorDefinition::from_json(source)?.build()
+    }
+
+    /// The definition the validator was compiled from, e.g. to pass it on
+    pub fn definition(&self) -> &ValidatorDefinition {
+        &self.definition
+    }
+}
+
+impl Validator<Value> for DynamicValidator {
+    fn validate(&self, instance: &Value) -> ValidationResult {
+        self.inner.validate(instance)
+    }
+
+    fn validate_with_locale(&self, instance: &Value, locale: &str) -> ValidationResult {
+        self.inner.validate_with_locale(instance, locale)
+    }
+
+    fn validate_with_context(&self, instance: &Value, context: &dyn Any) -> ValidationResult {
+        self.inner.validate_with_context(instance, context)
+    }
+
+    fn validate_with_locale_and_context(&self, instance: &Value, locale: &str, context: &dyn Any) -> ValidationResult {
+        self.inner.validate_with_locale_and_context(instance, locale, context)
+    }
+
+    fn validate_property(&self, instance: &Value, property: &str) -> ValidationResult {
+        self.inner.validate_property(instance, property)
+    }
+
+    fn validate_property_with_context(&self, instance: &Value, property: &str, context: &dyn Any) -> ValidationResult {
+        self.inner.validate_property_with_context(instance, property, context)
+    }
+
+    fn locales(&self) -> Vec<String> {
+        self.inner.locales()
+    }
+
+    fn rules(&self) -> Vec<RuleDescriptor> {
+        self.inner.rules()
+    }
+}
+
+/// Validate raw bytes as a JSON document, `None` when they aren't valid JSON
+///
+/// An entry point for fuzzers: any input either fails to parse or is validated, and
+/// neither may panic. Documents nested deeper than 128 levels don't parse.
+pub fn validate_json_bytes(data: &[u8], validator: &dyn Validator<Value>) -> Option<ValidationResult> {
+    let value: Value = serde_json::from_slice(data).ok()?;
+    Some(validator.validate(&value))
+}
+
+/// Longest string [`corpus_seeds`] generates; longer boundaries are left to the fuzzer
+const MAX_SEED_LENGTH: usize = 4096;
+
+/// JSON documents exercising each rule around its boundaries, as seeds for a fuzzing corpus
+///
+/// For every rule the value of its property is set to values just inside and outside the
+/// rule's parameters (lengths, numeric bounds), to `null` and to values of other JSON types.
+/// Each document sets one property; an empty object comes first. Duplicates are removed.
+pub fn corpus_seeds(rules: &[RuleDescriptor]) -> Vec<Vec<u8>> {
+    let mut seeds = vec![b"{}".to_vec()];
+    for rule in rules {
+        let mut values = vec![Value::Null, Value::Bool(true), Value::Array(Vec::new()), Value::Object(Default::default())];
+        values.extend(boundary_values(rule));
+        for value in values {
+            let document = rule.property.rsplit('.').fold(value, |value, key| {
+                Value::Object([(key.to_string(), value)].into_iter().collect())
+            });
+            let seed = document.to_string().into_bytes();
+            if !seeds.contains(&seed) {
+                seeds.push(seed);
+            }
+        }
+    }
+    seeds
+}
+
+/// Values on either side of a rule's parameters
+fn boundary_values(rule: &RuleDescriptor) -> Vec<Value> {
+    let param = |name: &str| rule.params.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str());
+    let strings = |lengths: &[usize]| -> Vec<Value> {
+        lengths.iter().filter(|&&n| n <= MAX_SEED_LENGTH).map(|&n| Value::String("a".repeat(n))).collect()
+    };
+    let numbers = |bounds: &[&str]| -> Vec<Value> {
+        bounds
+            .iter()
+            .filter_map(|bound| bound.parse::<f64>().ok())
+            .flat_map(|bound| [bound - 1.0, bound, bound + 1.0])
+            .filter_map(|n| serde_json::Number::from_f64(n).map(Value::Number))
+            .collect()
+    };
+    let length = |name: &str| param(name).and_then(|value| value.parse::<usize>().ok());
+    match rule.kind.as_str() {
+        "min_length" | "max_length" | "length" => {
+            let bounds = [length("min"), length("max")].into_iter().flatten();
+            strings(&bounds.flat_map(|n| [n.saturating_sub(1), n, n + 1]).collect::<Vec<_>>())
+        }
+        "greater_than" | "greater_than_or_equal" | "less_than" | "less_than_or_equal" | "inclusive_between" => {
+            numbers(&[param("min"), param("max")].into_iter().flatten().collect::<Vec<_>>())
+        }
+        "email" => ["ada@example.com", "ada@", "@example.com", "ada"].into_iter().map(Value::from).collect(),
+        "http_method" => ["GET", "get", "BREW"].into_iter().map(Value::from).collect(),
+        "safe_outbound_url" => ["https://example.com/", "http://127.0.0.1/", "file:///etc/passwd"].into_iter().map(Value::from).collect(),
+        _ => ["", " ", "a", "\u{0}"].into_iter().map(Value::from).chain([Value::from(0)]).collect(),
+    }
+}
+
+/// The value at a dotted path, or `null` when any segment is missing
+fn lookup<'a>(root: &'a Value, path: &[String]) -> &'a Value {
+    static NULL: Value = Value::Null;
+    path.iter().try_fold(root, |value, key| value.get(key)).unwrap_or(&NULL)
+}
+
+/// Report a JSON value, with strings unquoted
+fn json_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Build the rule a definition describes, with its code when it has one
+fn compile(definition: &RuleDefinition) -> Result<RuleBuilder<Value>, ConfigError> {
+    let rule = compile_rule(definition)?;
+    Ok(match &definition.code {
+        Some(code) => rule.with_code(code.clone()),
+        None => rule,
+    })
+}
+
+/// Build the rule a definition describes
+fn compile_rule(definition: &RuleDefinition) -> Result<RuleBuilder<Value>, ConfigError> {
+    let rule = RuleBuilder::<Value>::for_property(definition.property.clone());
+    let message = definition.message.clone();
+    let kind = definition.rule.as_str();
+    let rule = match kind {
+        "not_empty" => rule.built_in(kind, Vec::new(), message, |v| match v {
+            Value::Null => false,
+            Value::String(s) => !s.trim().is_empty(),
+            Value::Array(items) => !items.is_empty(),
+            Value::Object(fields) => !fields.is_empty(),
+            _ => true,
+        }, Some(json_value)).even_if_empty(),
+        "not_null" => rule.built_in(kind, Vec::new(), message, |v| !v.is_null(), None).even_if_empty(),
+        "min_length" => {
+            let min = length_param(definition, "min")?;
+            string_rule(rule, kind, vec![("min", min.to_string())], message, move |s| s.len() >= min)
+        }
+        "max_length" => {
+            let max = length_param(definition, "max")?;
+            string_rule(rule, kind, vec![("max", max.to_string())], message, move |s| s.len() <= max)
+        }
+        "length" => {
+            let (min, max) = (length_param(definition, "min")?, length_param(definition, "max")?);
+            let rule = string_rule(rule, "min_length", vec![("min", min.to_string())], message.clone(), move |s| s.len() >= min);
+            let rule = match &definition.code {
+                Some(code) => rule.with_code(code.clone()),
+                None => rule,
+            };
+            string_rule(rule, "max_length", vec![("max", max.to_string())], message, move |s| s.len() <= max)
+        }
+        "email" => string_rule(rule, kind, Vec::new(), message, is_email),
+        "http_header_name" => string_rule(rule, kind, Vec::new(), message, is_token),
+        "http_header_value" => string_rule(rule, kind, Vec::new(), message, is_header_value),
+        "http_method" => string_rule(rule, kind, Vec::new(), message, |s| HTTP_METHODS.contains(&s)),
+        "safe_outbound_url" => {
+            let allowed_hosts: Vec<String> = match definition.params.get("allowed_hosts") {
+                None => Vec::new(),
+                Some(Value::Array(hosts)) => hosts
+                    .iter()
+                    .map(|host| host.as_str().map(str::to_ascii_lowercase).ok_or_else(|| invalid_param(definition, "allowed_hosts")))
+                    .collect::<Result<_, _>>()?,
+                Some(_) => return Err(invalid_param(definition, "allowed_hosts")),
+            };
+            let params = if allowed_hosts.is_empty() { Vec::new() } else { vec![("allowed_hosts", allowed_hosts.join(", "))] };
+            string_rule(rule, kind, params, message, move |s| is_safe_outbound_url(s, &allowed_hosts))
+        }
+        "expression" => {
+            let source = definition.params.get("expression").and_then(Value::as_str).ok_or_else(|| invalid_param(definition, "expression"))?;
+            let expression = Expression::parse(source).map_err(|_| invalid_param(definition, "expression"))?;
+            string_rule(rule, kind, vec![("expression", source.to_string())], message, move |s| expression.evaluate(s))
+        }
+        "greater_than" => {
+            let (min, shown) = number_param(definition, "min")?;
+            number_rule(rule, kind, vec![("min", shown)], message, move |n| n > min)
+        }
+        "greater_than_or_equal" => {
+            let (min, shown) = number_param(definition, "min")?;
+            number_rule(rule, kind, vec![("min", shown)], message, move |n| n >= min)
+        }
+        "less_than" => {
+            let (max, shown) = number_param(definition, "max")?;
+            number_rule(rule, kind, vec![("max", shown)], message, move |n| n < max)
+        }
+        "less_than_or_equal" => {
+            let (max, shown) = number_param(definition, "max")?;
+            number_rule(rule, kind, vec![("max", shown)], message, move |n| n <= max)
+        }
+        "inclusive_between" => {
+            let (min, shown_min) = number_param(definition, "min")?;
+            let (max, shown_max) = number_param(definition, "max")?;
+            number_rule(rule, kind, vec![("min", shown_min), ("max", shown_max)], message, move |n| n >= min && n <= max)
+        }
+        _ => {
+            return Err(ConfigError::UnknownRule {
+                property: definition.property.clone(),
+                rule: definition.rule.clone(),
+            })
+        }
+    };
+    Ok(rule)
+}
+
+/// The definition of a rule built in code, if definitions support its kind and parameters
+fn describe(descriptor: &RuleDescriptor) -> Result<RuleDefinition, ConfigError> {
+    let mut definition = RuleDefinition {
+        property: descriptor.property.clone(),
+        rule: descriptor.kind.clone(),
+        params: BTreeMap::new(),
+        message: descriptor.message.clone(),
+        code: descriptor.code.clone().filter(|code| *code != descriptor.kind.to_uppercase()),
+    };
+    if descriptor.property.contains('[') {
+        return Err(ConfigError::UnknownRule { property: definition.property, rule: definition.rule });
+    }
+    for (name, value) in &descriptor.params {
+        let value = match name.as_str() {
+            "min" | "max" => serde_json::from_str::<Value>(value).ok().filter(Value::is_number),
+            "allowed_hosts" => Some(value.split(", ").map(Value::from).collect()),
+            "expression" => Some(Value::from(value.as_str())),
+            _ => None,
+        };
+        match value {
+            Some(value) => definition.params.insert(name.clone(), value),
+            None => return Err(invalid_param(&definition, name)),
+        };
+    }
+    // Checks the rule exists and takes these parameters
+    compile_rule(&definition)?;
+    Ok(definition)
+}
+
+/// Add a rule for string values; `null` passes and other JSON types fail
+fn string_rule(rule: RuleBuilder<Value>, kind: &str, params: Vec<(&str, String)>, message: Option<String>, passes: impl Fn(&str) -> bool + Send + Sync + 'static) -> RuleBuilder<Value> {
+    rule.built_in(kind, params, message, move |value| match value {
+        Value::Null => true,
+        Value::String(s) => passes(s),
+        _ => false,
+    }, Some(json_value))
+}
+
+/// Add a rule for numeric values; `null` passes and other JSON types fail
+fn number_rule(rule: RuleBuilder<Value>, kind: &str, params: Vec<(&str, String)>, message: Option<String>, passes: impl Fn(f64) -> bool + Send + Sync + 'static) -> RuleBuilder<Value> {
+    rule.built_in(kind, params, message, move |value| match value {
+        Value::Null => true,
+        Value::Number(n) => n.as_f64().is_some_and(&passes),
+        _ => false,
+    }, Some(json_value))
+}
+
+fn invalid_param(definition: &RuleDefinition, param: &str) -> ConfigError {
+    ConfigError::InvalidParam {
+        property: definition.property.clone(),
+        rule: definition.rule.clone(),
+        param: param.to_string(),
+    }
+}
+
+/// A non-negative integer parameter
+fn length_param(definition: &RuleDefinition, param: &str) -> Result<usize, ConfigError> {
+    definition
+        .params
+        .get(param)
+        .and_then(Value::as_u64)
+        .and_then(|n| usize::try_from(n).ok())
+        .ok_or_else(|| invalid_param(definition, param))
+}
+
+/// A numeric parameter, together with how it is shown in messages
+fn number_param(definition: &RuleDefinition, param: &str) -> Result<(f64, String), ConfigError> {
+    let value = definition.params.get(param).ok_or_else(|| invalid_param(definition, param))?;
+    let number = value.as_f64().ok_or_else(|| invalid_param(definition, param))?;
+    Ok((number, value.to_string()))
+}
@@ -0,0 +1,155 @@
+//! Per-request data passed to rules
+
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::builder::ValidatorBuilder;
+use crate::error::ValidationError;
+use crate::messages::MessageContext;
+use crate::rule::RuleDescriptor;
+
+/// Per-request data for rules, such as tenant configuration or feature flags
+///
+/// Pass it to `Validator::validate_with_context`. Rules read string values, flags and typed
+/// extensions from it instead of global state. Context-aware rules that expect a typed context,
+/// like `RuleBuilder::at_most_ctx`, also find it among the extensions.
+///
+/// # Example
+/// ```rust,ignore
+/// let context = ValidationContext::new()
+///     .with_value("tenant", "acme")
+///     .with_flag("import_mode")
+///     .with_extension(TenantConfig { max_items: 50 });
+///
+/// let result = validator.validate_with_context(&order, &context);
+/// ```
+#[derive(Default)]
+pub struct ValidationContext {
+    values: HashMap<String, String>,
+    flags: HashSet<String>,
+    extensions: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl ValidationContext {
+    /// Create an empty context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a string value
+    pub fn with_value(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values.insert(key.into(), value.into());
+        self
+    }
+
+    /// Raise a flag
+    pub fn with_flag(mut self, flag: impl Into<String>) -> Self {
+        self.flags.insert(flag.into());
+        self
+    }
+
+    /// Add a typed extension, replacing any previous extension of the same type
+    pub fn with_extension<V: Any + Send + Sync>(mut self, extension: V) -> Self {
+        self.extensions.insert(TypeId::of::<V>(), Box::new(extension));
+        self
+    }
+
+    /// The string value for `key`, if set
+    pub fn value(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Whether `flag` is raised
+    pub fn flag(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    /// The extension of type `V`, if added
+    pub fn extension<V: Any>(&self) -> Option<&V> {
+        self.extensions.get(&TypeId::of::<V>()).and_then(|extension| extension.downcast_ref::<V>())
+    }
+
+    /// The context of a validation run, or an empty one when none was passed
+    pub(crate) fn current<'a>(messages: &MessageContext<'a>) -> &'a ValidationContext {
+        static EMPTY: OnceLock<ValidationContext> = OnceLock::new();
+        messages
+            .data
+            .and_then(|data| data.downcast_ref::<ValidationContext>())
+            .unwrap_or_else(|| EMPTY.get_or_init(ValidationContext::new))
+    }
+}
+
+impl fmt::Debug for ValidationContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidationContext")
+            .field("values", &self.values)
+            .field("flags", &self.flags)
+            .field("extensions", &self.extensions.len())
+            .finish()
+    }
+}
+
+/// What a context-aware rule sees: the object being validated, the property and the context
+pub struct RuleContext<'a, T> {
+    root: &'a T,
+    property: &'a str,
+    context: &'a ValidationContext,
+}
+
+impl<'a, T> RuleContext<'a, T> {
+    /// The object being validated
+    pub fn root(&self) -> &'a T {
+        self.root
+    }
+
+    /// The property the rule reports on
+    pub fn property(&self) -> &'a str {
+        self.property
+    }
+
+    /// The context passed to `validate_with_context`, empty if validated without one
+    pub fn context(&self) -> &'a ValidationContext {
+        self.context
+    }
+}
+
+impl<T> ValidatorBuilder<T> {
+    /// Add a rule for a property whose predicate reads the validation context
+    ///
+    /// # Arguments
+    /// * `property_name` - Name of the property being validated
+    /// * `accessor` - Function to access the property value from the object
+    /// * `predicate` - Function receiving the value and the [`RuleContext`], returns true if valid
+    /// * `message` - Error message to use if validation fails
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .must_with_context("currency", |o| &o.currency,
+    ///     |currency, ctx| ctx.context().extension::<TenantConfig>().is_none_or(|t| t.currencies.contains(currency)),
+    ///     "currency is not enabled for this tenant")
+    /// ```
+    pub fn must_with_context<F, V, P>(self, property_name: impl Into<String>, accessor: F, predicate: P, message: impl Into<String>) -> Self
+    where
+        F: Fn(&T) -> &V + Send + Sync + 'static,
+        V: 'static,
+        P: Fn(&V, &RuleContext<T>) -> bool + Send + Sync + 'static,
+    {
+        let property_name = property_name.into();
+        let msg = message.into();
+        let descriptor = RuleDescriptor::new(property_name.clone(), "must", Vec::new()).with_message(Some(msg.clone())).without_code();
+        self.add_rule(descriptor, move |instance: &T, messages: &MessageContext| {
+            let context = RuleContext {
+                root: instance,
+                property: &property_name,
+                context: ValidationContext::current(messages),
+            };
+            if predicate(accessor(instance), &context) {
+                Vec::new()
+            } else {
+                vec![ValidationError::new(property_name.clone(), msg.clone())]
+            }
+        })
+    }
+}
@@ -0,0 +1,80 @@
+//! Rules for dates and times written in a strftime-style format (feature `chrono`)
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+use crate::accepted_format::AcceptedFormat;
+use crate::clock::Timestamp;
+use crate::rule::{str_is_empty, str_size, str_value, RuleBuilder};
+
+impl<T> RuleBuilder<T> {
+    /// Validate that the value is a date in the given format, e.g. `"%Y-%m-%d"`
+    ///
+    /// The format uses chrono's strftime-style specifiers and is shown in the default message.
+    /// Impossible dates such as `2023-02-30` are rejected.
+    ///
+    /// # Arguments
+    /// * `format` - The expected format
+    /// * `message` - Optional custom error message. `{format}` is replaced with the format.
+    pub fn date_format(self, format: &str, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let expected = format.to_string();
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).built_in("date_format", vec![("format", format.to_string())], message.map(|m| m.into()),
+            move |value| NaiveDate::parse_from_str(value.as_ref(), &expected).is_ok(),
+            Some(str_value::<T>))
+    }
+
+    /// Validate that the value is a time of day in the given format, e.g. `"%H:%M"`
+    ///
+    /// # Arguments
+    /// * `format` - The expected format
+    /// * `message` - Optional custom error message. `{format}` is replaced with the format.
+    pub fn time_format(self, format: &str, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let expected = format.to_string();
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).built_in("time_format", vec![("format", format.to_string())], message.map(|m| m.into()),
+            move |value| NaiveTime::parse_from_str(value.as_ref(), &expected).is_ok(),
+            Some(str_value::<T>))
+    }
+}
+
+impl AcceptedFormat {
+    /// A date format for [`RuleBuilder::any_format`], named after the format string
+    pub fn date(format: &str) -> Self {
+        let expected = format.to_string();
+        Self::new(format, move |value| NaiveDate::parse_from_str(value, &expected).is_ok())
+    }
+
+    /// A time-of-day format for [`RuleBuilder::any_format`], named after the format string
+    pub fn time(format: &str) -> Self {
+        let expected = format.to_string();
+        Self::new(format, move |value| NaiveTime::parse_from_str(value, &expected).is_ok())
+    }
+}
+
+impl<Tz: TimeZone> Timestamp for DateTime<Tz> {
+    fn to_system_time(&self) -> SystemTime {
+        let nanos = self.timestamp_subsec_nanos();
+        match u64::try_from(self.timestamp()) {
+            Ok(seconds) => UNIX_EPOCH + Duration::new(seconds, nanos),
+            Err(_) => UNIX_EPOCH - Duration::from_secs(self.timestamp().unsigned_abs()) + Duration::from_nanos(nanos.into()),
+        }
+    }
+}
+
+impl Timestamp for NaiveDateTime {
+    fn to_system_time(&self) -> SystemTime {
+        self.and_utc().to_system_time()
+    }
+}
+
+impl Timestamp for NaiveDate {
+    fn to_system_time(&self) -> SystemTime {
+        self.and_time(NaiveTime::MIN).to_system_time()
+    }
+}
@@ -0,0 +1,249 @@
+//! Validating batches of mixed command types
+//!
+//! A [`CommandDispatcher`] routes each variant of a command enum to the validator
+//! registered for it, so a message-bus consumer can validate whatever arrives with one
+//! call and report which kind of command failed.
+//!
+//! # Example
+//! ```rust,ignore
+//! enum Command {
+//!     CreateUser(CreateUser),
+//!     DeleteUser(DeleteUser),
+//! }
+//!
+//! let dispatcher = CommandDispatcher::<Command>::new()
+//!     .route("CreateUser", |c| match c { Command::CreateUser(x) => Some(x), _ => None }, create_user_validator())
+//!     .route("DeleteUser", |c| match c { Command::DeleteUser(x) => Some(x), _ => None }, delete_user_validator());
+//!
+//! for item in dispatcher.validate_batch(&commands) {
+//!     if !item.result.is_valid() {
+//!         eprintln!("command {} ({:?}) rejected", item.index, item.variant);
+//!     }
+//! }
+//! ```
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+#[cfg(feature = "async")]
+use crate::async_rule::BoxFuture;
+use crate::error::{ValidationError, ValidationResult};
+use crate::rule::RuleDescriptor;
+use crate::traits::Validator;
+
+/// Validator for an enum of commands, delegating each variant to its own validator
+///
+/// Commands without a registered route fail with a single error on the empty property,
+/// with the code `UNROUTED`, so nothing passes unvalidated.
+pub struct CommandDispatcher<E> {
+    routes: Vec<(String, Box<dyn Route<E>>)>,
+}
+
+/// Result of one command of a batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchedResult {
+    /// Position of the command in the batch
+    pub index: usize,
+    /// Name of the route that validated the command, `None` if no route matched
+    pub variant: Option<String>,
+    pub result: ValidationResult,
+}
+
+impl<E> CommandDispatcher<E> {
+    /// Create a dispatcher without routes
+    pub fn new() -> Self {
+        Self { routes: Vec::new() }
+    }
+
+    /// Validate commands for which `extract` returns `Some` with `validator`
+    ///
+    /// # Arguments
+    /// * `variant` - Name reported for these commands, e.g. the variant name
+    /// * `extract` - Function returning the command's payload if it is this variant
+    /// * `validator` - Validator for the payload
+    pub fn route<C, X, V>(mut self, variant: impl Into<String>, extract: X, validator: V) -> Self
+    where
+        X: for<'a> Fn(&'a E) -> Option<&'a C> + Send + Sync + 'static,
+        V: Validator<C> + 'static,
+        C: 'static,
+        E: 'static,
+    {
+        self.routes.push((variant.into(), Box::new(Variant { extract, validator, payload: PhantomData })));
+        self
+    }
+
+    /// Name of the route a command is dispatched to, if any
+    pub fn variant_of(&self, command: &E) -> Option<&str> {
+        self.find(command).map(|(variant, _)| variant.as_str())
+    }
+
+    /// Validate every command of a batch, tagging each result with its index and variant
+    pub fn validate_batch<'a>(&self, commands: impl IntoIterator<Item = &'a E>) -> Vec<DispatchedResult>
+    where
+        E: 'a,
+    {
+        commands
+            .into_iter()
+            .enumerate()
+            .map(|(index, command)| DispatchedResult {
+                index,
+                variant: self.variant_of(command).map(str::to_string),
+                result: self.validate(command),
+            })
+            .collect()
+    }
+
+    fn find(&self, command: &E) -> Option<&(String, Box<dyn Route<E>>)> {
+        self.routes.iter().find(|(_, route)| route.accepts(command))
+    }
+
+    fn dispatch(&self, command: &E, validate: impl FnOnce(&dyn Route<E>) -> ValidationResult) -> ValidationResult {
+        match self.find(command) {
+            Some((_, route)) => validate(route.as_ref()),
+            None => unrouted(),
+        }
+    }
+}
+
+impl<E> Default for CommandDispatcher<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Validator<E> for CommandDispatcher<E> {
+    fn validate(&self, instance: &E) -> ValidationResult {
+        self.dispatch(instance, |route| route.validate(instance))
+    }
+
+    fn validate_with_locale(&self, instance: &E, locale: &str) -> ValidationResult {
+        self.dispatch(instance, |route| route.validate_with_locale(instance, locale))
+    }
+
+    fn validate_with_context(&self, instance: &E, context: &dyn Any) -> ValidationResult {
+        self.dispatch(instance, |route| route.validate_with_context(instance, context))
+    }
+
+    fn validate_with_locale_and_context(&self, instance: &E, locale: &str, context: &dyn Any) -> ValidationResult {
+        self.dispatch(instance, |route| route.validate_with_locale_and_context(instance, locale, context))
+    }
+
+    fn validate_property(&self, instance: &E, property: &str) -> ValidationResult {
+        self.dispatch(instance, |route| route.validate_property(instance, property))
+    }
+
+    fn validate_property_with_context(&self, instance: &E, property: &str, context: &dyn Any) -> ValidationResult {
+        self.dispatch(instance, |route| route.validate_property_with_context(instance, property, context))
+    }
+
+    #[cfg(feature = "async")]
+    fn validate_async<'a>(&'a self, instance: &'a E) -> BoxFuture<'a, ValidationResult> {
+        match self.find(instance) {
+            Some((_, route)) => route.validate_async(instance),
+            None => Box::pin(std::future::ready(unrouted())),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    fn validate_async_with_context<'a>(&'a self, instance: &'a E, context: &'a dyn Any) -> BoxFuture<'a, ValidationResult> {
+        match self.find(instance) {
+            Some((_, route)) => route.validate_async_with_context(instance, context),
+            None => Box::pin(std::future::ready(unrouted())),
+        }
+    }
+
+    /// Locales of every route, in registration order
+    fn locales(&self) -> Vec<String> {
+        let mut locales: Vec<String> = self.routes.iter().flat_map(|(_, route)| route.locales()).collect();
+        let mut seen = HashSet::new();
+        locales.retain(|locale| seen.insert(locale.clone()));
+        locales
+    }
+
+    /// Rules of every route, in registration order
+    fn rules(&self) -> Vec<RuleDescriptor> {
+        self.routes.iter().flat_map(|(_, route)| route.rules()).collect()
+    }
+}
+
+fn unrouted() -> ValidationResult {
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("", "no validator is registered for this command").with_code("UNROUTED"));
+    result
+}
+
+/// A validator for one variant of `E`
+trait Route<E>: Validator<E> {
+    fn accepts(&self, command: &E) -> bool;
+}
+
+struct Variant<X, V, C> {
+    extract: X,
+    validator: V,
+    payload: PhantomData<fn(&C)>,
+}
+
+impl<E, C, X, V> Route<E> for Variant<X, V, C>
+where
+    X: for<'a> Fn(&'a E) -> Option<&'a C> + Send + Sync,
+    V: Validator<C>,
+{
+    fn accepts(&self, command: &E) -> bool {
+        (self.extract)(command).is_some()
+    }
+}
+
+impl<E, C, X, V> Validator<E> for Variant<X, V, C>
+where
+    X: for<'a> Fn(&'a E) -> Option<&'a C> + Send + Sync,
+    V: Validator<C>,
+{
+    fn validate(&self, instance: &E) -> ValidationResult {
+        (self.extract)(instance).map_or_else(ValidationResult::new, |payload| self.validator.validate(payload))
+    }
+
+    fn validate_with_locale(&self, instance: &E, locale: &str) -> ValidationResult {
+        (self.extract)(instance).map_or_else(ValidationResult::new, |payload| self.validator.validate_with_locale(payload, locale))
+    }
+
+    fn validate_with_context(&self, instance: &E, context: &dyn Any) -> ValidationResult {
+        (self.extract)(instance).map_or_else(ValidationResult::new, |payload| self.validator.validate_with_context(payload, context))
+    }
+
+    fn validate_with_locale_and_context(&self, instance: &E, locale: &str, context: &dyn Any) -> ValidationResult {
+        (self.extract)(instance).map_or_else(ValidationResult::new, |payload| self.validator.validate_with_locale_and_context(payload, locale, context))
+    }
+
+    fn validate_property(&self, instance: &E, property: &str) -> ValidationResult {
+        (self.extract)(instance).map_or_else(ValidationResult::new, |payload| self.validator.validate_property(payload, property))
+    }
+
+    fn validate_property_with_context(&self, instance: &E, property: &str, context: &dyn Any) -> ValidationResult {
+        (self.extract)(instance).map_or_else(ValidationResult::new, |payload| self.validator.validate_property_with_context(payload, property, context))
+    }
+
+    #[cfg(feature = "async")]
+    fn validate_async<'a>(&'a self, instance: &'a E) -> BoxFuture<'a, ValidationResult> {
+        match (self.extract)(instance) {
+            Some(payload) => self.validator.validate_async(payload),
+            None => Box::pin(std::future::ready(ValidationResult::new())),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    fn validate_async_with_context<'a>(&'a self, instance: &'a E, context: &'a dyn Any) -> BoxFuture<'a, ValidationResult> {
+        match (self.extract)(instance) {
+            Some(payload) => self.validator.validate_async_with_context(payload, context),
+            None => Box::pin(std::future::ready(ValidationResult::new())),
+        }
+    }
+
+    fn locales(&self) -> Vec<String> {
+        self.validator.locales()
+    }
+
+    fn rules(&self) -> Vec<RuleDescriptor> {
+        self.validator.rules()
+    }
+}
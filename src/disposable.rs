@@ -0,0 +1,147 @@
+//! Disposable email domain detection with pluggable block-lists
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use crate::rule::{str_is_empty, str_size, str_value, RuleBuilder};
+
+/// Domains shipped with [`BundledDisposableDomains`]
+const DISPOSABLE_DOMAINS: [&str; 24] = [
+    "10minutemail.com", "20minutemail.com", "discard.email", "dispostable.com", "emailondeck.com",
+    "fakeinbox.com", "getairmail.com", "getnada.com", "guerrillamail.com", "guerrillamail.net",
+    "mailcatch.com", "maildrop.cc", "mailinator.com", "mailnesia.com", "mintemail.com",
+    "moakt.com", "mohmal.com", "sharklasers.com", "spamgourmet.com", "temp-mail.org",
+    "tempmail.com", "throwawaymail.com", "trashmail.com", "yopmail.com",
+];
+
+/// A block-list of email domains
+///
+/// A domain also matches its subdomains: listing `mailinator.com` blocks
+/// `eu.mailinator.com`. Domains are compared in lowercase.
+pub trait DomainList: Send + Sync {
+    /// Whether the (lowercase) domain is listed, ignoring subdomains
+    fn contains(&self, domain: &str) -> bool;
+
+    /// Whether the domain or one of its parent domains is listed
+    fn matches(&self, domain: &str) -> bool {
+        let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+        let mut candidate = domain.as_str();
+        loop {
+            if self.contains(candidate) {
+                return true;
+            }
+            match candidate.split_once('.') {
+                Some((_, parent)) if parent.contains('.') => candidate = parent,
+                _ => return false,
+            }
+        }
+    }
+}
+
+impl<L: DomainList + ?Sized> DomainList for Arc<L> {
+    fn contains(&self, domain: &str) -> bool {
+        (**self).contains(domain)
+    }
+}
+
+/// A small built-in list of well-known disposable email providers
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BundledDisposableDomains;
+
+impl DomainList for BundledDisposableDomains {
+    fn contains(&self, domain: &str) -> bool {
+        DISPOSABLE_DOMAINS.contains(&domain)
+    }
+}
+
+/// A replaceable set of domains, loaded from text, a file or any other source
+///
+/// Share it in an `Arc` and call [`replace`](Self::replace) to refresh the list at runtime,
+/// for example from a periodically downloaded feed.
+///
+/// # Example
+/// ```rust,ignore
+/// let domains = Arc::new(DomainSet::from_file("disposable_domains.txt")?);
+/// let rule = RuleBuilder::<String>::for_property("email")
+///     .not_disposable_email(Arc::clone(&domains), None::<String>);
+///
+/// // later, from a background task
+/// domains.replace(fetch_latest_list().await?);
+/// ```
+#[derive(Debug, Default)]
+pub struct DomainSet {
+    domains: RwLock<HashSet<String>>,
+}
+
+impl DomainSet {
+    /// Create a set from domains
+    pub fn new<I, S>(domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let set = Self::default();
+        set.replace(domains);
+        set
+    }
+
+    /// Parse one domain per line, ignoring blank lines and `#` comments
+    pub fn parse(text: &str) -> Self {
+        Self::new(text.lines().map(|line| line.split('#').next().unwrap_or_default().trim()).filter(|line| !line.is_empty()))
+    }
+
+    /// Load a file with one domain per line, as accepted by [`parse`](Self::parse)
+    pub fn from_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Replace the listed domains
+    pub fn replace<I, S>(&self, domains: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let domains = domains.into_iter().map(|d| d.as_ref().trim_end_matches('.').to_ascii_lowercase()).collect();
+        *self.domains.write().unwrap_or_else(|e| e.into_inner()) = domains;
+    }
+
+    /// Number of listed domains
+    pub fn len(&self) -> usize {
+        self.domains.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Whether no domains are listed
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl DomainList for DomainSet {
+    fn contains(&self, domain: &str) -> bool {
+        self.domains.read().unwrap_or_else(|e| e.into_inner()).contains(domain)
+    }
+}
+
+impl<T> RuleBuilder<T> {
+    /// Validate that an email address doesn't use a disposable (throwaway) domain
+    ///
+    /// The domain after the last `@` is checked against `domains`, including its parent
+    /// domains. Values without an `@` pass; combine with `email()` to check the syntax.
+    /// The failing domain is reported in the error state as `domain`.
+    ///
+    /// # Arguments
+    /// * `domains` - The block-list, e.g. [`BundledDisposableDomains`] or a shared [`DomainSet`]
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn not_disposable_email(self, domains: impl DomainList + 'static, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).built_in_detailed("not_disposable_email", Vec::new(), message.map(|m| m.into()),
+            move |value, _| {
+                let (_, domain) = value.as_ref().rsplit_once('@')?;
+                domains.matches(domain).then(|| vec![("domain", domain.to_ascii_lowercase())])
+            },
+            Some(str_value::<T>))
+    }
+}
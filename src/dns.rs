@@ -0,0 +1,92 @@
+//! Async rules backed by DNS lookups (feature `dns`; `email_has_mx` needs `mx`)
+
+#[cfg(feature = "mx")]
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+#[cfg(feature = "mx")]
+use hickory_resolver::TokioResolver;
+
+use crate::async_rule::AsyncRuleBuilder;
+
+impl<T> AsyncRuleBuilder<T> {
+    /// Validate that the value is a host name that resolves to at least one address
+    ///
+    /// Meant for onboarding flows that accept SMTP or webhook endpoints. A lookup that fails
+    /// or doesn't finish within `timeout` is reported as unresolvable. Resolution uses the
+    /// system resolver through Tokio, so it needs a Tokio runtime with the `net` and `time`
+    /// drivers enabled.
+    ///
+    /// # Arguments
+    /// * `timeout` - How long to wait for the lookup
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// AsyncRuleBuilder::<String>::for_property("smtp_host")
+    ///     .resolvable_host(Duration::from_secs(2), None::<String>)
+    /// ```
+    pub fn resolvable_host(self, timeout: Duration, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.built_in("resolvable_host", vec![("timeout_ms", timeout.as_millis().to_string())], message.map(|m| m.into()),
+            move |value| {
+                let host = value.as_ref().trim_end_matches('.').to_string();
+                Box::pin(async move {
+                    let resolved = match tokio::time::timeout(timeout, tokio::net::lookup_host((host.as_str(), 0))).await {
+                        Ok(Ok(mut addresses)) => addresses.next().is_some(),
+                        Ok(Err(_)) => false,
+                        Err(_) => return Some(vec![("timed_out", "true".to_string())]),
+                    };
+                    (!resolved).then(Vec::new)
+                })
+            })
+    }
+
+    /// Validate that the domain of an email address publishes MX records
+    ///
+    /// Layer it on the syntactic `email()` rule: async rules only run for properties whose
+    /// synchronous rules passed, so malformed addresses are reported without a lookup. A
+    /// domain without MX records, with a null MX (RFC 7505), or whose lookup fails or exceeds
+    /// `timeout` is reported as undeliverable. Needs a Tokio runtime with the `net` and `time`
+    /// drivers enabled.
+    ///
+    /// # Arguments
+    /// * `timeout` - How long to wait for the lookup
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// ValidatorBuilder::<Signup>::new()
+    ///     .rule_for("email", |s| &s.email, RuleBuilder::for_property("email").email(None::<String>))
+    ///     .rule_for_async("email", |s| &s.email,
+    ///         AsyncRuleBuilder::for_property("email").email_has_mx(Duration::from_secs(3), None::<String>))
+    /// ```
+    #[cfg(feature = "mx")]
+    pub fn email_has_mx(self, timeout: Duration, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let resolver: Arc<OnceLock<Option<TokioResolver>>> = Arc::new(OnceLock::new());
+        self.built_in("email_has_mx", vec![("timeout_ms", timeout.as_millis().to_string())], message.map(|m| m.into()),
+            move |value| {
+                let domain = value.as_ref().rsplit_once('@').map(|(_, domain)| format!("{}.", domain.trim_end_matches('.')));
+                let resolver = Arc::clone(&resolver);
+                Box::pin(async move {
+                    let Some(domain) = domain else {
+                        return Some(Vec::new());
+                    };
+                    let Some(resolver) = resolver.get_or_init(|| TokioResolver::builder_tokio().ok().map(|builder| builder.build())) else {
+                        return Some(Vec::new());
+                    };
+                    let has_mx = match tokio::time::timeout(timeout, resolver.mx_lookup(domain)).await {
+                        Ok(Ok(records)) => records.iter().any(|mx| !mx.exchange().is_root()),
+                        Ok(Err(_)) => false,
+                        Err(_) => return Some(vec![("timed_out", "true".to_string())]),
+                    };
+                    (!has_mx).then(Vec::new)
+                })
+            })
+    }
+}
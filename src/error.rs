@@ -1,38 +1,254 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+
+#[cfg(feature = "indexmap")]
+use indexmap::IndexMap;
+/// How serious a validation failure is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+    Info,
+}
 
 /// Represents a validation error with a property name and error message
+///
+/// With the `serde` feature this serializes as:
+///
+/// ```json
+/// {
+///   "property": "age",
+///   "message": "must be greater than or equal to 18",
+///   "code": "GREATER_THAN_OR_EQUAL",
+///   "severity": "error",
+///   "attempted_value": "15",
+///   "state": {"limit": "18"}
+/// }
+/// ```
+///
+/// `property`, `message`, `code` (`null` when absent) and `severity` are always present;
+/// `attempted_value` and `state` are omitted when empty.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidationError {
-    pub property: String,
+    /// Path of the failing property; shared with the rule that reported it, so cloning is cheap
+    pub property: Arc<str>,
     pub message: String,
+    /// Machine-readable identifier of the rule that failed (e.g. `"EMAIL"`)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub code: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub severity: Severity,
+    /// The offending value, as reported by the rule that failed (built-in rules fill this in)
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub attempted_value: Option<String>,
+    /// Structured context attached by the rule (limits, suggestions, ...), keyed by name
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "BTreeMap::is_empty"))]
+    pub state: BTreeMap<String, String>,
 }
 
 impl ValidationError {
-    pub fn new(property: impl Into<String>, message: impl Into<String>) -> Self {
+    pub fn new(property: impl Into<Arc<str>>, message: impl Into<String>) -> Self {
         Self {
             property: property.into(),
             message: message.into(),
+            code: None,
+            severity: Severity::Error,
+            attempted_value: None,
+            state: BTreeMap::new(),
         }
     }
+
+    /// Attach a machine-readable error code
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Set the severity of the error
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Attach the value that failed validation
+    pub fn with_attempted_value(mut self, value: impl Display) -> Self {
+        self.attempted_value = Some(value.to_string());
+        self
+    }
+
+    /// Attach a piece of structured context to the error
+    pub fn with_state(mut self, key: impl Into<String>, value: impl Display) -> Self {
+        self.state.insert(key.into(), value.to_string());
+        self
+    }
+
+    /// Read a piece of structured context as a typed value, e.g. `error.state_as::<usize>("limit")`
+    ///
+    /// Returns `None` if the key is missing or the value doesn't parse as `V`.
+    pub fn state_as<V: FromStr>(&self, key: &str) -> Option<V> {
+        self.state.get(key)?.parse().ok()
+    }
+
+    /// Whether the error has the given code
+    pub fn has_code(&self, code: &str) -> bool {
+        self.code.as_deref() == Some(code)
+    }
 }
 
 impl Display for ValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}: {}", self.property, self.message)
+        write!(f, "{}: {}", self.property, self.message)?;
+        if let Some(value) = &self.attempted_value {
+            write!(f, " (got {})", value)?;
+        }
+        Ok(())
     }
 }
 
+/// Why a rule did not run, see [`ValidationResult::skipped_rules`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SkipReason {
+    /// A `skip_when_context` condition held, e.g. a feature flag raised in the context
+    Condition,
+    /// The validation ran before the date set with `enforced_after`
+    NotYetEnforced,
+    /// The value was empty and the validator skips rules on empty values
+    EmptyValue,
+    /// An async rule whose property already failed a synchronous rule
+    PropertyFailed,
+    /// Validation stopped at the validator's `max_errors`
+    MaxErrors,
+    /// The value, or the nesting of validators, was over a resource limit
+    ResourceLimit,
+}
+
+/// A rule that did not run during a validation, recorded by validators built with
+/// `ValidatorBuilder::record_skipped_rules`
+///
+/// With the `serde` feature this serializes as
+/// `{"property": "vat_id", "rule_kind": "not_empty", "reason": "condition"}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkippedRule {
+    /// Path of the property the rule validates, e.g. `lines[2]` for a collection element
+    pub property: Arc<str>,
+    /// Kind of the rule, as in its descriptor (e.g. `"email"`)
+    pub rule_kind: String,
+    pub reason: SkipReason,
+}
+
 /// Result of validation containing errors if validation failed
+///
+/// With the `serde` feature this serializes as `{"errors": [ ... ]}`, plus `schema_id` and
+/// `schema_version` when the validator is tagged with them, `correlation_id` when the
+/// validator generates correlation IDs and `skipped_rules` when it records skipped rules.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidationResult {
     errors: Vec<ValidationError>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    schema_id: Option<Arc<str>>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    schema_version: Option<Arc<str>>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    correlation_id: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "std::ops::Not::not"))]
+    truncated: bool,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    skipped_rules: Vec<SkippedRule>,
+    #[cfg(feature = "tracing-error")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span_trace: FailureSpan,
 }
 
+/// The span context a failed validation ran in, ignored when comparing results
+#[cfg(feature = "tracing-error")]
+#[derive(Debug, Clone, Default)]
+struct FailureSpan(Option<tracing_error::SpanTrace>);
+
+#[cfg(feature = "tracing-error")]
+impl PartialEq for FailureSpan {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "tracing-error")]
+impl Eq for FailureSpan {}
+
 impl ValidationResult {
     /// Create a new empty validation result
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            errors: Vec::new(),
+            schema_id: None,
+            schema_version: None,
+            correlation_id: None,
+            truncated: false,
+            skipped_rules: Vec::new(),
+            #[cfg(feature = "tracing-error")]
+            span_trace: FailureSpan::default(),
+        }
+    }
+
+    /// Tag the result with the rule set that produced it
+    pub fn with_schema(mut self, id: impl Into<Arc<str>>, version: Option<impl Into<Arc<str>>>) -> Self {
+        self.schema_id = Some(id.into());
+        self.schema_version = version.map(|v| v.into());
+        self
+    }
+
+    /// Identifier of the rule set that produced the result, set with `ValidatorBuilder::schema_id`
+    pub fn schema_id(&self) -> Option<&str> {
+        self.schema_id.as_deref()
+    }
+
+    /// Version of the rule set that produced the result, set with `ValidatorBuilder::schema_version`
+    pub fn schema_version(&self) -> Option<&str> {
+        self.schema_version.as_deref()
+    }
+
+    /// Attach an ID identifying this validation run, e.g. to match a support request to a log entry
+    pub fn with_correlation_id(mut self, id: impl Into<String>) -> Self {
+        self.correlation_id = Some(id.into());
+        self
+    }
+
+    /// ID of this validation run, set when the validator was built with `ValidatorBuilder::correlation_ids`
+    pub fn correlation_id(&self) -> Option<&str> {
+        self.correlation_id.as_deref()
+    }
+
+    /// The tracing spans that were active when validation failed, e.g. the request being handled
+    ///
+    /// Captured once per failed result by validators built with `ValidatorBuilder`, when the
+    /// subscriber has a `tracing_error::ErrorLayer`. `None` for valid results.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// if let Some(trace) = result.span_trace() {
+    ///     tracing::warn!("unexpected validation failure: {:?}\n{}", result.errors(), trace);
+    /// }
+    /// ```
+    #[cfg(feature = "tracing-error")]
+    pub fn span_trace(&self) -> Option<&tracing_error::SpanTrace> {
+        self.span_trace.0.as_ref()
+    }
+
+    /// Record the current span context on a failed result
+    #[cfg(feature = "tracing-error")]
+    pub(crate) fn capture_span_trace(&mut self) {
+        if !self.is_valid() {
+            self.span_trace = FailureSpan(Some(tracing_error::SpanTrace::capture()));
+        }
     }
 
     /// Add a validation error
@@ -45,40 +261,370 @@ impl ValidationResult {
         self.errors.extend(errors);
     }
 
+    /// Mutable access to the errors, for adapters that annotate them
+    pub(crate) fn errors_mut(&mut self) -> &mut [ValidationError] {
+        &mut self.errors
+    }
+
+    /// The rules that did not run, in the order they were skipped
+    ///
+    /// Only validators built with `ValidatorBuilder::record_skipped_rules` record them, so
+    /// an audit log can show which checks actually ran for a request. Async rules are not
+    /// listed when validating with `validate`, which never runs them.
+    pub fn skipped_rules(&self) -> &[SkippedRule] {
+        &self.skipped_rules
+    }
+
+    /// Record rules that did not run
+    pub(crate) fn add_skipped_rules(&mut self, skipped: impl IntoIterator<Item = SkippedRule>) {
+        self.skipped_rules.extend(skipped);
+    }
+
+    /// Record rules skipped before the ones already recorded, e.g. by an earlier pipeline stage
+    pub(crate) fn add_earlier_skipped_rules(&mut self, earlier: &[SkippedRule]) {
+        self.skipped_rules.splice(0..0, earlier.iter().cloned());
+    }
+
+    /// Mutable access to the skipped rules, for adapters that rename properties
+    pub(crate) fn skipped_rules_mut(&mut self) -> &mut [SkippedRule] {
+        &mut self.skipped_rules
+    }
+
+    /// Add the errors of another result, e.g. of a second validator or a manual check
+    ///
+    /// This result keeps its schema and correlation ID, taking the other's when it has none,
+    /// and is truncated if either result was. Skipped rules are combined like errors.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut result = order_validator.validate(&order);
+    /// result.merge(stock_validator.validate(&order.lines));
+    /// ```
+    pub fn merge(&mut self, other: ValidationResult) {
+        if self.schema_id.is_none() {
+            self.schema_id = other.schema_id;
+            self.schema_version = other.schema_version;
+        }
+        self.correlation_id = self.correlation_id.take().or(other.correlation_id);
+        self.truncated |= other.truncated;
+        self.errors.extend(other.errors);
+        self.skipped_rules.extend(other.skipped_rules);
+    }
+
+    /// Add the errors of another result, with their properties prefixed
+    ///
+    /// `email` becomes `customer.email` and `[0]` becomes `customer[0]`; errors on the
+    /// object itself (the empty property) are reported on `customer`.
+    pub fn add_prefixed(&mut self, prefix: &str, other: ValidationResult) {
+        self.truncated |= other.truncated;
+        self.errors.extend(other.errors.into_iter().map(|mut error| {
+            error.property = prefixed(prefix, &error.property);
+            error
+        }));
+        self.skipped_rules.extend(other.skipped_rules.into_iter().map(|mut skipped| {
+            skipped.property = prefixed(prefix, &skipped.property);
+            skipped
+        }));
+    }
+
+    /// Whether validation stopped at the validator's `max_errors`, so there may be more errors
+    /// than reported
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Keep the first `max` errors and record that the result is incomplete
+    pub(crate) fn truncate_errors(&mut self, max: usize) {
+        self.errors.truncate(max);
+        self.truncated = true;
+    }
+
+    /// Keep only the errors matching `keep`
+    pub(crate) fn retain_errors(&mut self, mut keep: impl FnMut(&ValidationError) -> bool) {
+        self.errors.retain(|error| keep(error));
+    }
+
     /// Check if validation passed (no errors)
     pub fn is_valid(&self) -> bool {
         self.errors.is_empty()
     }
 
+    /// `Ok(())` if validation passed, the errors otherwise
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        self.ok_or_errors(())
+    }
+
+    /// `Ok(value)` if validation passed, the errors otherwise, e.g. to pass on the validated value
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let order = validator.validate(&order).ok_or_errors(order)?;
+    /// ```
+    pub fn ok_or_errors<T>(self, value: T) -> Result<T, ValidationErrors> {
+        if self.is_valid() {
+            Ok(value)
+        } else {
+            Err(ValidationErrors(Box::new(self)))
+        }
+    }
+
     /// Get all validation errors
+    ///
+    /// Errors are in the order they were reported: properties in the order their rules
+    /// were added to the validator, and each property's errors in the order of its rules,
+    /// so the same input always gives the same order.
     pub fn errors(&self) -> &[ValidationError] {
         &self.errors
     }
 
-    /// Get errors grouped by property name
-    pub fn errors_by_property(&self) -> HashMap<String, Vec<String>> {
+    /// The errors ordered by property path, each property's errors in reported order
+    ///
+    /// Paths compare as strings, so `lines[10]` comes before `lines[2]`.
+    pub fn sorted(&self) -> Vec<&ValidationError> {
+        let mut errors: Vec<&ValidationError> = self.errors.iter().collect();
+        errors.sort_by(|a, b| a.property.cmp(&b.property));
+        errors
+    }
+
+    /// Remove repeated errors, keeping the first of those with the same property, code and
+    /// message, e.g. when overlapping rule sets or merged results report the same failure
+    pub fn dedup(&mut self) {
+        let mut seen = HashSet::new();
+        let keep: Vec<bool> = self.errors.iter().map(|error| seen.insert((&*error.property, error.code.as_deref(), error.message.as_str()))).collect();
+        let mut keep = keep.into_iter();
+        self.errors.retain(|_| keep.next().unwrap_or(true));
+    }
+
+    /// Consume the result, grouping the error messages by property
+    ///
+    /// Meant for consumers that expect a plain `HashMap<String, Vec<String>>`. The shape is
+    /// stable:
+    ///
+    /// * keys are property paths exactly as reported on the errors: `email`, `address.city`
+    ///   for nested properties, `lines[2]` for collection elements, and `""` for errors on
+    ///   the object itself; they are never renamed or re-cased (use `remap` for that)
+    /// * each property's messages keep the order in which the errors were reported
+    /// * properties without errors have no entry
+    ///
+    /// The map has no order; use [`errors_by_property`](Self::errors_by_property), or
+    /// `into_index_map` with the `indexmap` feature, to keep properties in the order of their
+    /// first error.
+    pub fn into_map(self) -> HashMap<String, Vec<String>> {
         let mut grouped: HashMap<String, Vec<String>> = HashMap::new();
+        for error in self.errors {
+            grouped.entry(error.property.to_string()).or_default().push(error.message);
+        }
+        grouped
+    }
+
+    /// Consume the result, grouping the error messages by property in the order of each
+    /// property's first error
+    ///
+    /// Keys and messages follow the same rules as [`into_map`](Self::into_map). Requires the
+    /// `indexmap` feature.
+    #[cfg(feature = "indexmap")]
+    pub fn into_index_map(self) -> IndexMap<String, Vec<String>> {
+        let mut grouped: IndexMap<String, Vec<String>> = IndexMap::new();
+        for error in self.errors {
+            grouped.entry(error.property.to_string()).or_default().push(error.message);
+        }
+        grouped
+    }
+
+    /// Get errors grouped by property name, like [`into_map`](Self::into_map) without consuming the result
+    ///
+    /// Properties are in the order of their first error, and share the errors' property names
+    /// instead of copying them.
+    pub fn errors_by_property(&self) -> Vec<(Arc<str>, Vec<String>)> {
+        let mut grouped: Vec<(Arc<str>, Vec<String>)> = Vec::new();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
         for error in &self.errors {
-            grouped
-                .entry(error.property.clone())
-                .or_default()
-                .push(error.message.clone());
+            let position = *positions.entry(&error.property).or_insert_with(|| {
+                grouped.push((error.property.clone(), Vec::new()));
+                grouped.len() - 1
+            });
+            grouped[position].1.push(error.message.clone());
         }
         grouped
     }
 
+    /// Get the errors reported for a property
+    pub fn errors_for<'a>(&'a self, property: &'a str) -> impl Iterator<Item = &'a ValidationError> + 'a {
+        self.errors.iter().filter(move |e| &*e.property == property)
+    }
+
+    /// Check whether a property has any error, e.g. to mark a form field as invalid
+    pub fn has_error_for(&self, property: &str) -> bool {
+        self.errors_for(property).next().is_some()
+    }
+
+    /// Check whether a property has an error with the given code (e.g. `"EMAIL"`)
+    pub fn has_error(&self, property: &str, code: &str) -> bool {
+        self.errors_for(property).any(|e| e.has_code(code))
+    }
+
+    /// Get the properties that have errors, in the order of their first error
+    pub fn properties(&self) -> Vec<&str> {
+        let mut properties: Vec<&str> = Vec::new();
+        for error in &self.errors {
+            if !properties.contains(&&*error.property) {
+                properties.push(&error.property);
+            }
+        }
+        properties
+    }
+
+    /// Get the first error message of each property
+    ///
+    /// For frontends that render a single message per field. Keys follow the same rules
+    /// as [`into_map`](Self::into_map).
+    pub fn single_message_map(&self) -> HashMap<String, String> {
+        let mut messages = HashMap::new();
+        for error in &self.errors {
+            messages.entry(error.property.to_string()).or_insert_with(|| error.message.clone());
+        }
+        messages
+    }
+
+    /// Get the first error message of each property, in the order of each property's first
+    /// error
+    ///
+    /// Like [`single_message_map`](Self::single_message_map), for forms that also focus the
+    /// first invalid field. Requires the `indexmap` feature.
+    #[cfg(feature = "indexmap")]
+    pub fn first_errors_map(&self) -> IndexMap<String, String> {
+        let mut messages = IndexMap::new();
+        for error in &self.errors {
+            messages.entry(error.property.to_string()).or_insert_with(|| error.message.clone());
+        }
+        messages
+    }
+
     /// Get the first error message for a property, if any
     pub fn first_error_for(&self, property: &str) -> Option<&str> {
         self.errors
             .iter()
-            .find(|e| e.property == property)
+            .find(|e| &*e.property == property)
             .map(|e| e.message.as_str())
     }
 }
 
+impl FromIterator<ValidationError> for ValidationResult {
+    fn from_iter<I: IntoIterator<Item = ValidationError>>(errors: I) -> Self {
+        let mut result = ValidationResult::new();
+        result.extend(errors);
+        result
+    }
+}
+
+impl Extend<ValidationError> for ValidationResult {
+    fn extend<I: IntoIterator<Item = ValidationError>>(&mut self, errors: I) {
+        self.errors.extend(errors);
+    }
+}
+
+impl IntoIterator for ValidationResult {
+    type Item = ValidationError;
+    type IntoIter = std::vec::IntoIter<ValidationError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationResult {
+    type Item = &'a ValidationError;
+    type IntoIter = std::slice::Iter<'a, ValidationError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+#[cfg(feature = "json")]
+impl ValidationResult {
+    /// Reconstruct a result from its serialized JSON form, e.g. a server's validation response
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let result = ValidationResult::from_json(&response.text().await?)?;
+    /// if result.has_error("email", "EMAIL") {
+    ///     show_hint("email", result.first_error_for("email").unwrap());
+    /// }
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// `property` reported under `prefix`: `email` becomes `customer.email`, `[0]` becomes
+/// `customer[0]` and the empty property becomes `customer`
+fn prefixed(prefix: &str, property: &str) -> Arc<str> {
+    match property {
+        "" => prefix.into(),
+        property if property.starts_with('[') => format!("{}{}", prefix, property).into(),
+        property => format!("{}.{}", prefix, property).into(),
+    }
+}
+
 impl Default for ValidationResult {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// The errors of a failed validation as a `std::error::Error`, for `?`-based error handling
+///
+/// Created by [`ValidationResult::into_result`] and [`ValidationResult::ok_or_errors`], and
+/// boxed so `Result`s carrying it stay small. Its `Display` lists every error, e.g.
+/// `validation failed: email: must be a valid email address; age: must be at least 18`.
+///
+/// # Example
+/// ```rust,ignore
+/// fn register(form: &SignupForm) -> anyhow::Result<UserId> {
+///     signup_validator().validate(form).into_result()?;
+///     Ok(store(form)?)
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationErrors(Box<ValidationResult>);
+
+impl ValidationErrors {
+    /// The validation errors
+    pub fn errors(&self) -> &[ValidationError] {
+        self.0.errors()
+    }
+
+    /// The failed result, with its schema and correlation ID
+    pub fn result(&self) -> &ValidationResult {
+        &self.0
+    }
+
+    /// Consume the error into the failed result
+    pub fn into_inner(self) -> ValidationResult {
+        *self.0
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("validation failed")?;
+        for (index, error) in self.0.errors().iter().enumerate() {
+            f.write_str(if index == 0 { ": " } else { "; " })?;
+            write!(f, "{}", error)?;
+        }
+        if self.0.is_truncated() {
+            f.write_str("; ...")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl From<ValidationErrors> for ValidationResult {
+    fn from(errors: ValidationErrors) -> Self {
+        *errors.0
+    }
+}
+
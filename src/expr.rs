@@ -0,0 +1,413 @@
+//! A small expression language for string rules stored as text, e.g. in a database
+//!
+//! ```text
+//! len >= 8 && contains_upper && !contains(' ')
+//! ```
+//!
+//! Expressions combine conditions with `&&`, `||`, `!` and parentheses:
+//!
+//! * `len` (length in characters) and `words` (whitespace-separated words) compare to
+//!   numbers, `value` to quoted strings, with `==`, `!=`, `<`, `<=`, `>` and `>=`
+//! * `contains_upper`, `contains_lower`, `contains_digit`, `contains_symbol`,
+//!   `contains_whitespace` and `is_empty` test the characters of the value
+//! * `contains('x')`, `starts_with('x')`, `ends_with('x')` and `matches('regex')` take a
+//!   single- or double-quoted string
+//!
+//! Expressions are parsed once, so syntax errors and invalid regexes surface when loading
+//! the rule rather than while validating.
+//!
+//! # Example
+//! ```rust,ignore
+//! let policy = Expression::parse(&row.password_policy)?;
+//! RuleBuilder::<String>::for_property("password").satisfies(&policy, None::<String>)
+//! ```
+
+use std::fmt;
+
+use regex::Regex;
+
+use crate::rule::{str_is_empty, str_size, str_value, Rule, RuleBuilder};
+
+/// A parsed rule expression
+#[derive(Debug, Clone)]
+pub struct Expression {
+    source: String,
+    root: Node,
+}
+
+/// Why an expression couldn't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpressionError {
+    /// Character offset of the problem in the source
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ExpressionError {}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Or(Box<Node>, Box<Node>),
+    And(Box<Node>, Box<Node>),
+    Not(Box<Node>),
+    Compare(Operand, Comparison, Operand),
+    Test(Predicate),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Len,
+    Words,
+    Value,
+    Number(f64),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone)]
+enum Predicate {
+    ContainsUpper,
+    ContainsLower,
+    ContainsDigit,
+    ContainsSymbol,
+    ContainsWhitespace,
+    IsEmpty,
+    Contains(String),
+    StartsWith(String),
+    EndsWith(String),
+    Matches(Regex),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f64),
+    Text(String),
+    Compare(Comparison),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl Expression {
+    /// Parse an expression
+    pub fn parse(source: &str) -> Result<Self, ExpressionError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, next: 0, end: source.chars().count() };
+        let root = parser.or()?;
+        if let Some((position, _)) = parser.tokens.get(parser.next) {
+            return Err(error(*position, "unexpected input"));
+        }
+        Ok(Self { source: source.to_string(), root })
+    }
+
+    /// The expression as written
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether the value satisfies the expression
+    pub fn evaluate(&self, value: &str) -> bool {
+        self.root.evaluate(value)
+    }
+
+    /// Compile into a custom rule reporting `message` when the value doesn't satisfy the expression
+    pub fn to_rule<T: AsRef<str>>(&self, message: impl Into<String>) -> Rule<T> {
+        let expression = self.clone();
+        let message = message.into();
+        Box::new(move |value: &T| (!expression.evaluate(value.as_ref())).then(|| message.clone()))
+    }
+}
+
+impl Node {
+    fn evaluate(&self, value: &str) -> bool {
+        match self {
+            Node::Or(left, right) => left.evaluate(value) || right.evaluate(value),
+            Node::And(left, right) => left.evaluate(value) && right.evaluate(value),
+            Node::Not(inner) => !inner.evaluate(value),
+            Node::Compare(left, comparison, right) => match (left.resolve(value), right.resolve(value)) {
+                (Resolved::Number(a), Resolved::Number(b)) => comparison.holds(a.partial_cmp(&b)),
+                (Resolved::Text(a), Resolved::Text(b)) => comparison.holds(Some(a.cmp(b))),
+                _ => false,
+            },
+            Node::Test(predicate) => match predicate {
+                Predicate::ContainsUpper => value.chars().any(char::is_uppercase),
+                Predicate::ContainsLower => value.chars().any(char::is_lowercase),
+                Predicate::ContainsDigit => value.chars().any(|c| c.is_ascii_digit()),
+                Predicate::ContainsSymbol => value.chars().any(|c| !c.is_alphanumeric() && !c.is_whitespace()),
+                Predicate::ContainsWhitespace => value.chars().any(char::is_whitespace),
+                Predicate::IsEmpty => value.is_empty(),
+                Predicate::Contains(text) => value.contains(text.as_str()),
+                Predicate::StartsWith(text) => value.starts_with(text.as_str()),
+                Predicate::EndsWith(text) => value.ends_with(text.as_str()),
+                Predicate::Matches(regex) => regex.is_match(value),
+            },
+        }
+    }
+}
+
+enum Resolved<'a> {
+    Number(f64),
+    Text(&'a str),
+}
+
+impl Operand {
+    fn resolve<'a>(&'a self, value: &'a str) -> Resolved<'a> {
+        match self {
+            Operand::Len => Resolved::Number(value.chars().count() as f64),
+            Operand::Words => Resolved::Number(value.split_whitespace().count() as f64),
+            Operand::Value => Resolved::Text(value),
+            Operand::Number(n) => Resolved::Number(*n),
+            Operand::Text(text) => Resolved::Text(text),
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self, Operand::Len | Operand::Words | Operand::Number(_))
+    }
+}
+
+impl Comparison {
+    fn holds(self, ordering: Option<std::cmp::Ordering>) -> bool {
+        use std::cmp::Ordering::*;
+        match (self, ordering) {
+            (_, None) => false,
+            (Comparison::Eq, Some(o)) => o == Equal,
+            (Comparison::Ne, Some(o)) => o != Equal,
+            (Comparison::Lt, Some(o)) => o == Less,
+            (Comparison::Le, Some(o)) => o != Greater,
+            (Comparison::Gt, Some(o)) => o == Greater,
+            (Comparison::Ge, Some(o)) => o != Less,
+        }
+    }
+}
+
+fn error(position: usize, message: impl Into<String>) -> ExpressionError {
+    ExpressionError { position, message: message.into() }
+}
+
+/// Split the source into tokens, each with its character offset
+fn tokenize(source: &str) -> Result<Vec<(usize, Token)>, ExpressionError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        let c = chars[i];
+        let pair = |second: char| chars.get(i + 1) == Some(&second);
+        let token = match c {
+            _ if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '&' if pair('&') => Token::And,
+            '|' if pair('|') => Token::Or,
+            '!' if pair('=') => Token::Compare(Comparison::Ne),
+            '!' => Token::Not,
+            '=' if pair('=') => Token::Compare(Comparison::Eq),
+            '<' if pair('=') => Token::Compare(Comparison::Le),
+            '>' if pair('=') => Token::Compare(Comparison::Ge),
+            '<' => Token::Compare(Comparison::Lt),
+            '>' => Token::Compare(Comparison::Gt),
+            '\'' | '"' => {
+                let mut text = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(error(start, "unterminated string")),
+                        Some(&q) if q == c => break,
+                        Some('\\') => {
+                            i += 1;
+                            text.push(*chars.get(i).ok_or_else(|| error(start, "unterminated string"))?);
+                        }
+                        Some(&other) => text.push(other),
+                    }
+                    i += 1;
+                }
+                Token::Text(text)
+            }
+            _ if c.is_ascii_digit() => {
+                while chars.get(i + 1).is_some_and(|c| c.is_ascii_digit() || *c == '.') {
+                    i += 1;
+                }
+                let literal: String = chars[start..=i].iter().collect();
+                Token::Number(literal.parse().map_err(|_| error(start, format!("invalid number `{}`", literal)))?)
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                while chars.get(i + 1).is_some_and(|c| c.is_alphanumeric() || *c == '_') {
+                    i += 1;
+                }
+                Token::Ident(chars[start..=i].iter().collect())
+            }
+            _ => return Err(error(start, format!("unexpected character `{}`", c))),
+        };
+        if matches!(token, Token::And | Token::Or) || matches!(token, Token::Compare(_)) && chars.get(i + 1) == Some(&'=') {
+            i += 1;
+        }
+        tokens.push((start, token));
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+    /// Offset reported for errors at the end of the input
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next).map(|(_, token)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.next).map_or(self.end, |(position, _)| *position)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.next).map(|(_, token)| token.clone());
+        self.next += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token, what: &str) -> Result<(), ExpressionError> {
+        let position = self.position();
+        match self.advance() {
+            Some(token) if token == expected => Ok(()),
+            _ => Err(error(position, format!("expected {}", what))),
+        }
+    }
+
+    fn or(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.next += 1;
+            node = Node::Or(Box::new(node), Box::new(self.and()?));
+        }
+        Ok(node)
+    }
+
+    fn and(&mut self) -> Result<Node, ExpressionError> {
+        let mut node = self.unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.next += 1;
+            node = Node::And(Box::new(node), Box::new(self.unary()?));
+        }
+        Ok(node)
+    }
+
+    fn unary(&mut self) -> Result<Node, ExpressionError> {
+        match self.peek() {
+            Some(Token::Not) => {
+                self.next += 1;
+                Ok(Node::Not(Box::new(self.unary()?)))
+            }
+            Some(Token::Open) => {
+                self.next += 1;
+                let node = self.or()?;
+                self.expect(Token::Close, "`)`")?;
+                Ok(node)
+            }
+            _ => self.condition(),
+        }
+    }
+
+    fn condition(&mut self) -> Result<Node, ExpressionError> {
+        if let Some(Token::Ident(name)) = self.peek() {
+            let predicate = match name.as_str() {
+                "contains_upper" => Some(Predicate::ContainsUpper),
+                "contains_lower" => Some(Predicate::ContainsLower),
+                "contains_digit" => Some(Predicate::ContainsDigit),
+                "contains_symbol" => Some(Predicate::ContainsSymbol),
+                "contains_whitespace" => Some(Predicate::ContainsWhitespace),
+                "is_empty" => Some(Predicate::IsEmpty),
+                _ => None,
+            };
+            if let Some(predicate) = predicate {
+                self.next += 1;
+                return Ok(Node::Test(predicate));
+            }
+            if let Some(function) = ["contains", "starts_with", "ends_with", "matches"].into_iter().find(|f| f == name) {
+                self.next += 1;
+                self.expect(Token::Open, "`(`")?;
+                let argument_position = self.position();
+                let Some(Token::Text(argument)) = self.advance() else {
+                    return Err(error(argument_position, "expected a quoted string"));
+                };
+                self.expect(Token::Close, "`)`")?;
+                let predicate = match function {
+                    "contains" => Predicate::Contains(argument),
+                    "starts_with" => Predicate::StartsWith(argument),
+                    "ends_with" => Predicate::EndsWith(argument),
+                    _ => Predicate::Matches(Regex::new(&argument).map_err(|e| error(argument_position, format!("invalid regex: {}", e)))?),
+                };
+                return Ok(Node::Test(predicate));
+            }
+        }
+
+        let left = self.operand()?;
+        let comparison_position = self.position();
+        let Some(Token::Compare(comparison)) = self.advance() else {
+            return Err(error(comparison_position, "expected a comparison"));
+        };
+        let right_position = self.position();
+        let right = self.operand()?;
+        if left.is_numeric() != right.is_numeric() {
+            return Err(error(right_position, "cannot compare a number with a string"));
+        }
+        Ok(Node::Compare(left, comparison, right))
+    }
+
+    fn operand(&mut self) -> Result<Operand, ExpressionError> {
+        let position = self.position();
+        match self.advance() {
+            Some(Token::Number(n)) => Ok(Operand::Number(n)),
+            Some(Token::Text(text)) => Ok(Operand::Text(text)),
+            Some(Token::Ident(name)) => match name.as_str() {
+                "len" => Ok(Operand::Len),
+                "words" => Ok(Operand::Words),
+                "value" => Ok(Operand::Value),
+                _ => Err(error(position, format!("unknown name `{}`", name))),
+            },
+            _ => Err(error(position, "expected a condition")),
+        }
+    }
+}
+
+impl<T> RuleBuilder<T> {
+    /// Validate that the value satisfies a parsed [`Expression`]
+    ///
+    /// # Arguments
+    /// * `expression` - The parsed expression, e.g. `len >= 8 && contains_upper`
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn satisfies(self, expression: &Expression, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let expression = expression.clone();
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).built_in("expression", vec![("expression", expression.source.clone())], message.map(|m| m.into()),
+            move |value| expression.evaluate(value.as_ref()),
+            Some(str_value::<T>))
+    }
+}
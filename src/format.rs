@@ -0,0 +1,371 @@
+//! Well-known JSON error response shapes
+//!
+//! Teams migrating from other stacks often need byte-compatible error bodies. A
+//! [`ValidationResult`] can be rendered in any of the supported shapes with
+//! [`ValidationResult::to_json`]; web adapters take an [`ErrorFormat`] to choose theirs.
+//!
+//! # Example
+//! ```rust,ignore
+//! use fluentval::ErrorFormat;
+//!
+//! let body = result.to_json(ErrorFormat::Rails);
+//! // {"errors":{"email":["must be a valid email address"]}}
+//! ```
+
+use crate::error::{ValidationError, ValidationResult};
+
+/// Error body shape produced by [`ValidationResult::to_json`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorFormat {
+    /// Rails style: `{"errors": {"field": ["message"]}}`
+    Rails,
+    /// ASP.NET Core `ValidationProblemDetails`:
+    /// `{"type": ..., "title": ..., "status": 400, "errors": {"field": ["message"]}}`
+    #[default]
+    ProblemDetails,
+    /// JSON:API: `{"errors": [{"status": "422", "code": ..., "detail": ..., "source": {"pointer": ...}}]}`
+    JsonApi,
+    /// GraphQL style, with property paths as segment arrays:
+    /// `{"errors": [{"message": ..., "path": ["items", 2, "name"], "extensions": {"code": ...}}]}`
+    Graphql,
+}
+
+/// One segment of a property path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A named property, e.g. `items`
+    Key(String),
+    /// A collection index, e.g. the `2` in `items[2]`
+    Index(usize),
+}
+
+/// Split a property path such as `items[2].name` into its segments
+///
+/// Both `items[2].name` and `items.2.name` yield `["items", 2, "name"]`.
+pub fn path_segments(property: &str) -> Vec<PathSegment> {
+    let mut segments = Vec::new();
+    for part in property.split('.').filter(|p| !p.is_empty()) {
+        let mut rest = part;
+        if let Some(bracket) = rest.find('[') {
+            if bracket > 0 {
+                segments.push(segment(&rest[..bracket]));
+            }
+            rest = &rest[bracket..];
+            while let Some(inner) = rest.strip_prefix('[') {
+                let Some(end) = inner.find(']') else {
+                    segments.push(PathSegment::Key(rest.to_string()));
+                    break;
+                };
+                segments.push(segment(&inner[..end]));
+                rest = &inner[end + 1..];
+            }
+        } else {
+            segments.push(segment(rest));
+        }
+    }
+    segments
+}
+
+/// Whether `property` is `parent` or one of its nested (`parent.x`) or indexed (`parent[0]`) properties
+pub(crate) fn is_within(property: &str, parent: &str) -> bool {
+    property
+        .strip_prefix(parent)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.') || rest.starts_with('['))
+}
+
+fn segment(part: &str) -> PathSegment {
+    match part.parse::<usize>() {
+        Ok(index) => PathSegment::Index(index),
+        Err(_) => PathSegment::Key(part.to_string()),
+    }
+}
+
+impl ValidationResult {
+    /// Render the errors as a JSON body in the given shape
+    pub fn to_json(&self, format: ErrorFormat) -> String {
+        match format {
+            ErrorFormat::Rails => format!("{{\"errors\":{}}}", messages_object(self.errors())),
+            ErrorFormat::ProblemDetails => ProblemDetails::new().render(self),
+            ErrorFormat::JsonApi => {
+                let objects: Vec<String> = self.json_api_errors().iter().map(JsonApiError::to_json).collect();
+                format!("{{\"errors\":[{}]}}", objects.join(","))
+            }
+            ErrorFormat::Graphql => {
+                let objects: Vec<String> = self.errors().iter().map(graphql_error).collect();
+                format!("{{\"errors\":[{}]}}", objects.join(","))
+            }
+        }
+    }
+}
+
+/// Formatter for ASP.NET Core's `ValidationProblemDetails` body
+///
+/// Produces the exact shape ASP.NET emits for model validation failures, so clients
+/// written against .NET FluentValidation services keep working:
+///
+/// ```json
+/// {"type":"https://tools.ietf.org/html/rfc9110#section-15.5.1","title":"One or more validation errors occurred.","status":400,"errors":{"Email":["..."]},"traceId":"00-abc-01"}
+/// ```
+///
+/// `instance` and `traceId` are only emitted when set, `schemaId` and `schemaVersion`
+/// when the validator is tagged with them, and `correlationId` when the result carries one.
+///
+/// # Example
+/// ```rust,ignore
+/// let body = ProblemDetails::new()
+///     .pascal_case_properties(true)
+///     .trace_id(request_id)
+///     .render(&result);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProblemDetails {
+    type_uri: String,
+    title: String,
+    status: u16,
+    instance: Option<String>,
+    trace_id: Option<String>,
+    pascal_case: bool,
+}
+
+impl ProblemDetails {
+    /// Create a formatter with ASP.NET's default type, title and a 400 status
+    pub fn new() -> Self {
+        Self {
+            type_uri: "https://tools.ietf.org/html/rfc9110#section-15.5.1".to_string(),
+            title: "One or more validation errors occurred.".to_string(),
+            status: 400,
+            instance: None,
+            trace_id: None,
+            pascal_case: false,
+        }
+    }
+
+    /// Set the `type` URI
+    pub fn type_uri(mut self, type_uri: impl Into<String>) -> Self {
+        self.type_uri = type_uri.into();
+        self
+    }
+
+    /// Set the `title`
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the `status` (ASP.NET uses 400; some APIs prefer 422)
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set the `instance` URI identifying this occurrence
+    pub fn instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    /// Set the `traceId` extension member
+    pub fn trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Report property names in PascalCase (`tax_number` → `TaxNumber`, `lines[0].qty` → `Lines[0].Qty`),
+    /// matching .NET property names
+    pub fn pascal_case_properties(mut self, enabled: bool) -> Self {
+        self.pascal_case = enabled;
+        self
+    }
+
+    /// Render the result as a JSON body
+    pub fn render(&self, result: &ValidationResult) -> String {
+        let errors = if self.pascal_case {
+            let renamed: Vec<ValidationError> = result
+                .errors()
+                .iter()
+                .map(|e| ValidationError { property: pascal_case_path(&e.property).into(), ..e.clone() })
+                .collect();
+            messages_object(&renamed)
+        } else {
+            messages_object(result.errors())
+        };
+        let mut fields = vec![
+            format!("\"type\":{}", json_string(&self.type_uri)),
+            format!("\"title\":{}", json_string(&self.title)),
+            format!("\"status\":{}", self.status),
+        ];
+        if let Some(instance) = &self.instance {
+            fields.push(format!("\"instance\":{}", json_string(instance)));
+        }
+        fields.push(format!("\"errors\":{}", errors));
+        if let Some(trace_id) = &self.trace_id {
+            fields.push(format!("\"traceId\":{}", json_string(trace_id)));
+        }
+        if let Some(schema_id) = result.schema_id() {
+            fields.push(format!("\"schemaId\":{}", json_string(schema_id)));
+        }
+        if let Some(schema_version) = result.schema_version() {
+            fields.push(format!("\"schemaVersion\":{}", json_string(schema_version)));
+        }
+        if let Some(correlation_id) = result.correlation_id() {
+            fields.push(format!("\"correlationId\":{}", json_string(correlation_id)));
+        }
+        format!("{{{}}}", fields.join(","))
+    }
+}
+
+impl Default for ProblemDetails {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// PascalCase every named segment of a property path, keeping indices and separators
+fn pascal_case_path(property: &str) -> String {
+    let mut out = String::with_capacity(property.len());
+    let mut capitalize = true;
+    let mut in_index = false;
+    for c in property.chars() {
+        match c {
+            '[' => in_index = true,
+            ']' => in_index = false,
+            _ => {}
+        }
+        if c == '_' && !in_index {
+            capitalize = true;
+            continue;
+        }
+        if c == '.' || c == ']' {
+            out.push(c);
+            capitalize = true;
+            continue;
+        }
+        if capitalize && !in_index {
+            out.extend(c.to_uppercase());
+        } else {
+            out.push(c);
+        }
+        capitalize = false;
+    }
+    out
+}
+
+/// `{"property": ["message", ...], ...}` with properties in first-error order
+fn messages_object(errors: &[ValidationError]) -> String {
+    let mut grouped: Vec<(&str, Vec<&str>)> = Vec::new();
+    for error in errors {
+        match grouped.iter_mut().find(|(property, _)| *property == &*error.property) {
+            Some((_, messages)) => messages.push(&error.message),
+            None => grouped.push((&error.property, vec![&error.message])),
+        }
+    }
+    let fields: Vec<String> = grouped
+        .iter()
+        .map(|(property, messages)| {
+            let messages: Vec<String> = messages.iter().map(|m| json_string(m)).collect();
+            format!("{}:[{}]", json_string(property), messages.join(","))
+        })
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+/// A JSON:API error object for one validation error
+///
+/// See <https://jsonapi.org/format/#error-objects>.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonApiError {
+    /// HTTP status as a string, always `"422"`
+    pub status: String,
+    /// Application-specific error code, from `ValidationError::code`
+    pub code: Option<String>,
+    pub title: String,
+    /// The validation message
+    pub detail: String,
+    /// JSON Pointer to the offending attribute, e.g. `/data/attributes/items/2/name`
+    pub pointer: String,
+}
+
+impl JsonApiError {
+    /// Convert a validation error into a JSON:API error object
+    pub fn from_error(error: &ValidationError) -> Self {
+        Self {
+            status: "422".to_string(),
+            code: error.code.clone(),
+            title: "Invalid Attribute".to_string(),
+            detail: error.message.clone(),
+            pointer: json_pointer(&error.property),
+        }
+    }
+
+    /// Render as a JSON object
+    pub fn to_json(&self) -> String {
+        let mut fields = vec![format!("\"status\":{}", json_string(&self.status))];
+        if let Some(code) = &self.code {
+            fields.push(format!("\"code\":{}", json_string(code)));
+        }
+        fields.push(format!("\"title\":{}", json_string(&self.title)));
+        fields.push(format!("\"detail\":{}", json_string(&self.detail)));
+        fields.push(format!("\"source\":{{\"pointer\":{}}}", json_string(&self.pointer)));
+        format!("{{{}}}", fields.join(","))
+    }
+}
+
+impl ValidationResult {
+    /// Convert the errors into JSON:API error objects
+    pub fn json_api_errors(&self) -> Vec<JsonApiError> {
+        self.errors().iter().map(JsonApiError::from_error).collect()
+    }
+}
+
+/// JSON Pointer (RFC 6901) to a property under `/data/attributes`
+///
+/// Nested and collection paths become pointer segments, so `items[2].name` points at
+/// `/data/attributes/items/2/name`; `~` and `/` in names are escaped as `~0` and `~1`.
+pub fn json_pointer(property: &str) -> String {
+    let mut pointer = String::from("/data/attributes");
+    for segment in path_segments(property) {
+        pointer.push('/');
+        match segment {
+            PathSegment::Key(key) => pointer.push_str(&key.replace('~', "~0").replace('/', "~1")),
+            PathSegment::Index(index) => pointer.push_str(&index.to_string()),
+        }
+    }
+    pointer
+}
+
+fn graphql_error(error: &ValidationError) -> String {
+    let path: Vec<String> = path_segments(&error.property)
+        .iter()
+        .map(|segment| match segment {
+            PathSegment::Key(key) => json_string(key),
+            PathSegment::Index(index) => index.to_string(),
+        })
+        .collect();
+    let mut fields = vec![
+        format!("\"message\":{}", json_string(&error.message)),
+        format!("\"path\":[{}]", path.join(",")),
+    ];
+    if let Some(code) = &error.code {
+        fields.push(format!("\"extensions\":{{\"code\":{}}}", json_string(code)));
+    }
+    format!("{{{}}}", fields.join(","))
+}
+
+/// Quote and escape a string as a JSON string literal
+pub(crate) fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,172 @@
+//! Rules for HTTP protocol elements in configuration payloads
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::rule::{str_is_empty, str_size, str_value, RuleBuilder};
+
+/// Methods accepted by [`RuleBuilder::http_method`]
+pub(crate) const HTTP_METHODS: [&str; 9] = ["GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH"];
+
+impl<T> RuleBuilder<T> {
+    /// Validate that the value is a valid HTTP header field name (an RFC 9110 token)
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn http_header_name(self, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).built_in("http_header_name", Vec::new(), message.map(|m| m.into()),
+            |value| is_token(value.as_ref()),
+            Some(str_value::<T>))
+    }
+
+    /// Validate that the value is a safe HTTP header field value
+    ///
+    /// Only visible ASCII, spaces and tabs are allowed, with no leading or trailing
+    /// whitespace. CR and LF are rejected, which prevents header injection when the value
+    /// is copied into an outgoing request.
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn http_header_value(self, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).built_in("http_header_value", Vec::new(), message.map(|m| m.into()),
+            |value| is_header_value(value.as_ref()),
+            Some(str_value::<T>))
+    }
+
+    /// Validate that the value is a standard HTTP method (`GET`, `POST`, ...), case-sensitively
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn http_method(self, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).built_in("http_method", Vec::new(), message.map(|m| m.into()),
+            |value| HTTP_METHODS.contains(&value.as_ref()),
+            Some(str_value::<T>))
+    }
+
+    /// Validate that the value is an `http` or `https` URL that is safe for the server to call,
+    /// such as a user-supplied webhook or callback URL
+    ///
+    /// Guards against server-side request forgery: URLs pointing at `localhost` or at
+    /// loopback, private, link-local, site-local, carrier-grade NAT, multicast or unspecified
+    /// IP literals are rejected, including IPv6 addresses embedding such an IPv4 address
+    /// (IPv4-mapped, IPv4-compatible, NAT64 and 6to4) and numeric forms like `http://2130706433`.
+    /// Hostnames are not resolved, so pair this with network-level egress controls.
+    ///
+    /// # Arguments
+    /// * `allowed_hosts` - Hosts accepted even if they would be rejected, e.g. an internal relay
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::<String>::for_property("callback_url")
+    ///     .safe_outbound_url(&["hooks.internal"], None::<String>)
+    /// ```
+    pub fn safe_outbound_url(self, allowed_hosts: &[&str], message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let allowed_hosts: Vec<String> = allowed_hosts.iter().map(|h| h.to_ascii_lowercase()).collect();
+        let params = if allowed_hosts.is_empty() { Vec::new() } else { vec![("allowed_hosts", allowed_hosts.join(", "))] };
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).built_in("safe_outbound_url", params, message.map(|m| m.into()),
+            move |value| is_safe_outbound_url(value.as_ref(), &allowed_hosts),
+            Some(str_value::<T>))
+    }
+}
+
+/// Whether the value is a non-empty RFC 9110 token
+pub(crate) fn is_token(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
+/// Whether the value is visible ASCII, spaces and tabs, without surrounding whitespace
+pub(crate) fn is_header_value(value: &str) -> bool {
+    value.bytes().all(|b| b.is_ascii_graphic() || b == b' ' || b == b'\t') && value.trim_matches([' ', '\t']) == value
+}
+
+/// Whether the URL uses `http(s)` and its host is allow-listed or not a local or private address
+pub(crate) fn is_safe_outbound_url(url: &str, allowed_hosts: &[String]) -> bool {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return false;
+    };
+    if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+        return false;
+    }
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    // Backslashes and whitespace are read as separators by some clients, hiding the real host
+    if authority.chars().any(|c| c == '\\' || c.is_whitespace() || c.is_control()) {
+        return false;
+    }
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, host_port)| host_port);
+    let host = match host_port.strip_prefix('[') {
+        Some(bracketed) => match bracketed.split_once(']') {
+            Some((host, _)) => host,
+            None => return false,
+        },
+        None => host_port.split(':').next().unwrap_or_default(),
+    };
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    if host.is_empty() {
+        return false;
+    }
+    if allowed_hosts.contains(&host) {
+        return true;
+    }
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return is_public(ip);
+    }
+    if !host.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'.' || b == b'_') {
+        return false;
+    }
+    if host == "localhost" || host.ends_with(".localhost") {
+        return false;
+    }
+    // Numeric hosts such as `2130706433` or `0x7f.1` are IPv4 addresses to most resolvers
+    !host.split('.').all(|part| part.bytes().all(|b| b.is_ascii_digit()) || part.starts_with("0x"))
+}
+
+/// Whether the address is publicly routable, judging IPv6 addresses that embed an IPv4
+/// address by the embedded one
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match embedded_v4(ip) {
+            Some(embedded) => is_public_v4(embedded),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+/// The IPv4 address embedded in an IPv4-mapped (`::ffff:0:0/96`), IPv4-compatible
+/// (`::/96`), NAT64 (`64:ff9b::/96`) or 6to4 (`2002::/16`) address, which reach that IPv4
+/// address through the gateways that translate them
+fn embedded_v4(ip: Ipv6Addr) -> Option<Ipv4Addr> {
+    let octets = ip.octets();
+    match ip.segments() {
+        [0, 0, 0, 0, 0, 0 | 0xffff, _, _] | [0x64, 0xff9b, 0, 0, 0, 0, _, _] => Some(Ipv4Addr::new(octets[12], octets[13], octets[14], octets[15])),
+        [0x2002, ..] => Some(Ipv4Addr::new(octets[2], octets[3], octets[4], octets[5])),
+        _ => None,
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    let shared = first == 100 && (second & 0xc0) == 64;
+    !(ip.is_private() || ip.is_loopback() || ip.is_link_local() || ip.is_unspecified() || ip.is_broadcast() || ip.is_multicast() || first == 0 || shared)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    let unique_local = (first & 0xfe00) == 0xfc00;
+    let link_local = (first & 0xffc0) == 0xfe80;
+    // Deprecated site-local addresses are still routed internally by some networks
+    let site_local = (first & 0xffc0) == 0xfec0;
+    !(ip.is_loopback() || ip.is_unspecified() || ip.is_multicast() || unique_local || link_local || site_local)
+}
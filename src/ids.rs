@@ -0,0 +1,66 @@
+//! Generators for the identifiers the crate stamps on results, such as correlation IDs
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Source of identifiers, e.g. the correlation IDs set with `ValidatorBuilder::correlation_ids`
+///
+/// Implemented for closures returning a `String`, so a request ID can be passed as-is.
+/// Tests use [`SeededIds`] for IDs that are the same on every run.
+pub trait IdGenerator: Send + Sync {
+    /// The next identifier
+    fn next_id(&self) -> String;
+}
+
+impl<F: Fn() -> String + Send + Sync> IdGenerator for F {
+    fn next_id(&self) -> String {
+        self()
+    }
+}
+
+/// IDs of 16 hex digits, unique within the process: a shared counter offset by the process
+/// start time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcessIds;
+
+impl IdGenerator for ProcessIds {
+    fn next_id(&self) -> String {
+        static SEED: OnceLock<u64> = OnceLock::new();
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let seed = *SEED.get_or_init(|| SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64));
+        hex_id(seed, COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// IDs in the same format as [`ProcessIds`], in a sequence fixed by a seed
+///
+/// Two generators with the same seed produce the same IDs, so tests and replayed reports
+/// are reproducible.
+#[derive(Debug)]
+pub struct SeededIds {
+    seed: u64,
+    counter: AtomicU64,
+}
+
+impl SeededIds {
+    /// Create a generator whose sequence is determined by `seed`
+    pub fn new(seed: u64) -> Self {
+        Self { seed, counter: AtomicU64::new(0) }
+    }
+}
+
+impl IdGenerator for SeededIds {
+    fn next_id(&self) -> String {
+        hex_id(self.seed, self.counter.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// The `counter`th ID of the sequence starting at `seed`, as 16 hex digits
+fn hex_id(seed: u64, counter: u64) -> String {
+    // splitmix64 is a bijection, so distinct counter values never collide
+    let mut x = seed.wrapping_add(counter.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    format!("{:016x}", x ^ (x >> 31))
+}
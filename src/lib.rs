@@ -36,13 +36,82 @@
 //! }
 //! ```
 
+mod accepted_format;
+mod affix;
+mod arithmetic;
+#[cfg(feature = "async")]
+mod async_rule;
+#[cfg(feature = "audit")]
+mod audit;
+mod batch;
 mod builder;
+mod cache;
+mod clock;
+#[cfg(feature = "casemap")]
+mod casing;
+mod collection;
+mod context;
+#[cfg(feature = "chrono")]
+mod datetime;
+mod disposable;
+mod dispatch;
+#[cfg(feature = "dns")]
+mod dns;
 mod error;
+mod expr;
+mod format;
+mod http;
+mod ids;
+mod limits;
+mod macros;
+mod password;
+mod pipeline;
+mod quota;
+mod range;
+mod remap;
 mod rule;
+mod rule_set;
+mod schema;
+#[cfg(feature = "stream")]
+mod stream;
 mod traits;
 
+#[cfg(feature = "config")]
+pub mod config;
+pub mod messages;
+#[cfg(feature = "utoipa")]
+pub mod openapi;
+pub mod testkit;
+#[cfg(any(feature = "actix", feature = "async-graphql", feature = "axum", feature = "rocket", feature = "tonic"))]
+pub mod web;
+
 // Re-export all public types
+pub use accepted_format::AcceptedFormat;
+pub use arithmetic::Operand;
+#[cfg(feature = "async")]
+pub use async_rule::{AsyncRuleBuilder, BoxFuture};
+#[cfg(feature = "audit")]
+pub use audit::{AuditRecord, AuditSink, AuditedFailure, JsonLinesAuditSink, ACTOR_KEY};
+pub use batch::{validate_all, validate_all_by, BatchValidationReport};
+#[cfg(feature = "breach")]
+pub use password::BreachRangeProvider;
 pub use builder::{validate, ValidatorBuilder};
-pub use error::{ValidationError, ValidationResult};
-pub use rule::{Rule, RuleBuilder};
-pub use traits::{Numeric, OptionLike, Validator};
+pub use cache::{CachedValidator, MemoryCache, ResultCache};
+pub use clock::{Clock, FixedClock, SystemClock, Timestamp};
+pub use context::{RuleContext, ValidationContext};
+pub use disposable::{BundledDisposableDomains, DomainList, DomainSet};
+pub use dispatch::{CommandDispatcher, DispatchedResult};
+pub use error::{Severity, SkipReason, SkippedRule, ValidationError, ValidationErrors, ValidationResult};
+pub use expr::{Expression, ExpressionError};
+pub use format::{json_pointer, path_segments, ErrorFormat, JsonApiError, PathSegment, ProblemDetails};
+pub use ids::{IdGenerator, ProcessIds, SeededIds};
+pub use messages::MessageProvider;
+pub use pipeline::ValidationPipeline;
+pub use quota::Quota;
+pub use range::DateRange;
+pub use remap::{Case, ErrorMap};
+pub use rule::{EmptyValues, Rule, RuleBuilder, RuleDescriptor, TrimMode};
+pub use rule_set::RuleSet;
+#[cfg(feature = "stream")]
+pub use stream::validate_stream;
+pub use traits::{Flags, HasLength, HasValidator, Numeric, OptionLike, Validate, Validator, WithValidator};
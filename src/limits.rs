@@ -0,0 +1,71 @@
+//! Caps on the work a validator does for one input, so untrusted payloads can't make
+//! regex, URL or collection rules run for unbounded time
+
+use std::cell::Cell;
+use std::sync::Arc;
+
+use crate::error::ValidationError;
+use crate::messages::{MessageContext, MessageProvider};
+
+/// The limits configured with `ValidatorBuilder::max_input_bytes`, `max_collection_len`
+/// and `max_depth`; `None` means unlimited
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct ResourceLimits {
+    pub(crate) max_input_bytes: Option<usize>,
+    pub(crate) max_collection_len: Option<usize>,
+    pub(crate) max_depth: Option<usize>,
+}
+
+/// The size of a value as seen by the limits
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum InputSize {
+    /// Length of a string in bytes
+    Bytes(usize),
+    /// Number of elements of a collection
+    Elements(usize),
+}
+
+impl ResourceLimits {
+    /// The exceeded limit's name and value, if `size` is over one
+    pub(crate) fn exceeded(&self, size: InputSize) -> Option<(&'static str, usize)> {
+        match size {
+            InputSize::Bytes(bytes) => self.max_input_bytes.filter(|&max| bytes > max).map(|max| ("input_bytes", max)),
+            InputSize::Elements(elements) => self.max_collection_len.filter(|&max| elements > max).map(|max| ("collection_len", max)),
+        }
+    }
+}
+
+/// The error reported for a value over a limit, with the limit's name and value in its state
+#[cold]
+pub(crate) fn resource_limit_error(property: Arc<str>, (resource, limit): (&'static str, usize), messages: &MessageContext, provider: Option<&dyn MessageProvider>) -> ValidationError {
+    let params = [("limit".to_string(), limit.to_string())];
+    let mut error = ValidationError::new(property, messages.message(provider, "resource_limit", &params)).with_code("RESOURCE_LIMIT");
+    error.state.extend([("resource".to_string(), resource.to_string()), ("limit".to_string(), limit.to_string())]);
+    error
+}
+
+thread_local! {
+    /// Number of validations running on this thread, counting validators run from rules
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Marks a validation as running until dropped
+pub(crate) struct DepthGuard;
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Enter a validation, or `None` when that would nest validations deeper than `max_depth`
+pub(crate) fn enter(max_depth: Option<usize>) -> Option<DepthGuard> {
+    DEPTH.with(|depth| {
+        let entered = depth.get() + 1;
+        if max_depth.is_some_and(|max| entered > max) {
+            return None;
+        }
+        depth.set(entered);
+        Some(DepthGuard)
+    })
+}
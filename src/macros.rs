@@ -0,0 +1,88 @@
+//! Declarative shorthand for building validators
+
+/// Build a validator from a terse list of rules per field
+///
+/// Each field lists [`RuleBuilder`](crate::RuleBuilder) methods by name, with their arguments
+/// except the trailing optional message, which is always `None` (the default message). The
+/// field name is used as the property name. Use the builder directly for custom messages,
+/// cross-property rules or nested validators.
+///
+/// # Example
+/// ```rust,ignore
+/// let validator = validator!(User {
+///     name: [not_empty, min_length(2)],
+///     email: [email],
+///     age: [inclusive_between(18, 130)],
+/// });
+/// ```
+///
+/// expands to
+///
+/// ```rust,ignore
+/// ValidatorBuilder::<User>::new()
+///     .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").not_empty(None::<String>).min_length(2, None::<String>))
+///     .rule_for("email", |u| &u.email, RuleBuilder::for_property("email").email(None::<String>))
+///     .rule_for("age", |u| &u.age, RuleBuilder::for_property("age").inclusive_between(18, 130, None::<String>))
+///     .build()
+/// ```
+#[macro_export]
+macro_rules! validator {
+    ($type:ty { $($field:ident : [$($rule:ident $(($($arg:expr),* $(,)?))?),* $(,)?]),* $(,)? }) => {
+        $crate::ValidatorBuilder::<$type>::new()
+            $(.rule_for(stringify!($field), |instance| &instance.$field,
+                $crate::RuleBuilder::for_property(stringify!($field))
+                    $(.$rule($($($arg,)*)? None::<String>))*))*
+            .build()
+    };
+}
+
+/// The property name of a struct field, checked by the compiler
+///
+/// Expands to the field path as a `&'static str`, with dots between nested fields, and fails
+/// to compile when the field doesn't exist, so renaming a field can't leave a stale property
+/// name behind.
+///
+/// # Example
+/// ```rust,ignore
+/// .rule_for(prop!(User::email), |u| &u.email,
+///     RuleBuilder::for_property(prop!(User::email)).email(None::<String>))
+/// // prop!(User::address.city) == "address.city"
+/// ```
+#[macro_export]
+macro_rules! prop {
+    ($type:ident :: $field:ident $(. $nested:ident)*) => {{
+        let _ = |instance: &$type| {
+            let _ = &instance.$field $(.$nested)*;
+        };
+        concat!(stringify!($field) $(, ".", stringify!($nested))*)
+    }};
+}
+
+/// Validate several independent objects and merge their results
+///
+/// Each entry pairs an object with its validator. Errors are prefixed with the object's
+/// variable name, or with an explicit prefix given as `"prefix" => value`, so a request made
+/// of several resources reports `customer.email` and `shipping.zip` in one result.
+///
+/// # Example
+/// ```rust,ignore
+/// let result = validate_all!(
+///     (customer, &customer_validator),
+///     ("shipping" => request.shipping_address, &address_validator),
+/// );
+/// // &*result.errors()[0].property == "customer.email"
+/// ```
+#[macro_export]
+macro_rules! validate_all {
+    (@add $result:ident, ($prefix:literal => $value:expr, $validator:expr)) => {
+        $result.add_prefixed($prefix, $crate::validate(&$value, $validator));
+    };
+    (@add $result:ident, ($value:ident, $validator:expr)) => {
+        $result.add_prefixed(stringify!($value), $crate::validate(&$value, $validator));
+    };
+    ($($entry:tt),+ $(,)?) => {{
+        let mut result = $crate::ValidationResult::new();
+        $($crate::validate_all!(@add result, $entry);)+
+        result
+    }};
+}
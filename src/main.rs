@@ -0,0 +1,223 @@
+//! `fluentval` command-line tool (feature `cli`)
+//!
+//! Checks JSON records against a YAML or TOML rule definition before they are ingested:
+//!
+//! ```text
+//! fluentval check --rules rules.yaml data.ndjson [more files...]
+//! ```
+//!
+//! `.json` files hold one record or an array of records; anything else, including `-` for
+//! stdin, is read as NDJSON with one record per line. Each error is printed as
+//! `file:record: property: message`. The exit code is 0 when every record is valid, 1 when
+//! some are invalid and 2 when the rules or data can't be read.
+//!
+//! `fluentval repl --rules rules.yaml` loads a definition and explains how it judges JSON
+//! payloads pasted one at a time (payloads may span lines): every rule is listed as `ok` or
+//! `FAIL` with its message. `:rules` lists the rules, `:reload` reloads the definition after
+//! editing it and `:quit` (or end of input) exits.
+
+use std::io::{BufRead, IsTerminal, Read, Write};
+use std::process::ExitCode;
+
+use fluentval::config::DynamicValidator;
+use fluentval::{RuleDescriptor, ValidationResult, Validator};
+use serde_json::Value;
+
+const USAGE: &str = "usage: fluentval check --rules <rules.yaml|rules.toml> <data.json|data.ndjson|->...
+       fluentval repl --rules <rules.yaml|rules.toml>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(message) => {
+            eprintln!("fluentval: {}", message);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Run the command, returning whether every record was valid
+fn run(args: &[String]) -> Result<bool, String> {
+    match args.split_first().map(|(command, rest)| (command.as_str(), rest)) {
+        Some(("check", args)) => check(args),
+        Some(("repl", args)) => repl(args),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// Check data files, returning whether every record was valid
+fn check(args: &[String]) -> Result<bool, String> {
+    let mut rules = None;
+    let mut files = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rules" => rules = Some(args.next().ok_or(USAGE)?),
+            _ => files.push(arg),
+        }
+    }
+    let (Some(rules), false) = (rules, files.is_empty()) else {
+        return Err(USAGE.to_string());
+    };
+
+    let validator = load_validator(rules)?;
+    let (mut checked, mut invalid) = (0, 0);
+    for file in files {
+        for (record, value) in read_records(file)? {
+            checked += 1;
+            let errors = match value {
+                Ok(value) => validator
+                    .validate(&value)
+                    .errors()
+                    .iter()
+                    .map(|error| format!("{}: {}", error.property, error.message))
+                    .collect(),
+                Err(message) => vec![format!("invalid JSON: {}", message)],
+            };
+            if !errors.is_empty() {
+                invalid += 1;
+            }
+            for error in errors {
+                println!("{}:{}: {}", file, record, error);
+            }
+        }
+    }
+    eprintln!("checked {} records, {} invalid", checked, invalid);
+    Ok(invalid == 0)
+}
+
+/// Explain how the rules judge payloads read from stdin, until `:quit` or end of input
+fn repl(args: &[String]) -> Result<bool, String> {
+    let [flag, rules] = args else {
+        return Err(USAGE.to_string());
+    };
+    if flag != "--rules" {
+        return Err(USAGE.to_string());
+    }
+    let mut validator = load_validator(rules)?;
+    let interactive = std::io::stdin().is_terminal();
+    if interactive {
+        println!("loaded {} rules from {}; paste JSON payloads, :rules, :reload or :quit", validator.rules().len(), rules);
+    }
+
+    let mut payload = String::new();
+    let mut lines = std::io::stdin().lock().lines();
+    loop {
+        if interactive {
+            print!("{}", if payload.is_empty() { "> " } else { ". " });
+            std::io::stdout().flush().map_err(|e| e.to_string())?;
+        }
+        let Some(line) = lines.next() else { break };
+        let line = line.map_err(|e| format!("stdin: {}", e))?;
+        if payload.is_empty() {
+            match line.trim() {
+                "" => continue,
+                ":quit" => break,
+                ":rules" => {
+                    for rule in validator.rules() {
+                        println!("  {}", describe(&rule));
+                    }
+                    continue;
+                }
+                ":reload" => {
+                    match load_validator(rules) {
+                        Ok(reloaded) => {
+                            validator = reloaded;
+                            println!("reloaded {} rules", validator.rules().len());
+                        }
+                        Err(message) => println!("error: {}", message),
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        payload.push_str(&line);
+        payload.push('\n');
+        match serde_json::from_str::<Value>(&payload) {
+            Ok(value) => print!("{}", explain(&validator.rules(), &validator.validate(&value))),
+            // The payload continues on the next line
+            Err(e) if e.is_eof() => continue,
+            Err(e) => println!("invalid JSON: {}", e),
+        }
+        payload.clear();
+    }
+    Ok(true)
+}
+
+/// Every rule with whether it passed, and the errors no rule accounts for
+fn explain(rules: &[RuleDescriptor], result: &ValidationResult) -> String {
+    let mut explained = vec![false; result.errors().len()];
+    let mut output = String::new();
+    for rule in rules {
+        let failure = result.errors().iter().enumerate().find(|(index, error)| {
+            !explained[*index] && *error.property == rule.property && error.code == rule.code
+        });
+        match failure {
+            Some((index, error)) => {
+                explained[index] = true;
+                output.push_str(&format!("  FAIL  {}: {}\n", describe(rule), error.message));
+            }
+            None => output.push_str(&format!("  ok    {}\n", describe(rule))),
+        }
+    }
+    for (error, _) in result.errors().iter().zip(&explained).filter(|(_, explained)| !**explained) {
+        output.push_str(&format!("  FAIL  {}\n", error));
+    }
+    output.push_str(&match result.errors().len() {
+        0 => "valid\n".to_string(),
+        1 => "invalid: 1 error\n".to_string(),
+        count => format!("invalid: {} errors\n", count),
+    });
+    output
+}
+
+/// A rule as `property: kind(param = value, ...)`
+fn describe(rule: &RuleDescriptor) -> String {
+    let params: Vec<String> = rule.params.iter().map(|(name, value)| format!("{} = {}", name, value)).collect();
+    if params.is_empty() {
+        format!("{}: {}", rule.property, rule.kind)
+    } else {
+        format!("{}: {}({})", rule.property, rule.kind, params.join(", "))
+    }
+}
+
+fn load_validator(path: &str) -> Result<DynamicValidator, String> {
+    let source = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    let validator = if path.ends_with(".toml") {
+        DynamicValidator::from_toml(&source)
+    } else {
+        DynamicValidator::from_yaml(&source)
+    };
+    validator.map_err(|e| format!("{}: {}", path, e))
+}
+
+/// A record number and the parsed record, or why it couldn't be parsed
+type Record = (usize, Result<Value, String>);
+
+/// The records of a data file, numbered from 1 (by line for NDJSON)
+fn read_records(path: &str) -> Result<Vec<Record>, String> {
+    let source = if path == "-" {
+        let mut source = String::new();
+        std::io::stdin().read_to_string(&mut source).map_err(|e| format!("stdin: {}", e))?;
+        source
+    } else {
+        std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?
+    };
+
+    if path.ends_with(".json") {
+        return match serde_json::from_str(&source) {
+            Ok(Value::Array(records)) => Ok(records.into_iter().enumerate().map(|(index, record)| (index + 1, Ok(record))).collect()),
+            Ok(record) => Ok(vec![(1, Ok(record))]),
+            Err(e) => Err(format!("{}: invalid JSON: {}", path, e)),
+        };
+    }
+    Ok(source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| (index + 1, serde_json::from_str(line).map_err(|e| e.to_string())))
+        .collect())
+}
@@ -0,0 +1,757 @@
+//! Default error messages and pluggable localization
+//!
+//! Built-in rules don't hard-code their default wording. When a rule fails without a
+//! custom message, its message template is looked up by rule kind (e.g. `"min_length"`)
+//! and locale in a [`MessageProvider`], and placeholders such as `{min}` are filled in
+//! from the rule's parameters. Lookups fall back from a regional locale to its language
+//! (`"de-DE"` → `"de"`) and finally to the built-in English messages.
+//!
+//! With the `i18n` feature enabled, bundled catalogs for Spanish, French, German,
+//! Portuguese and Chinese ([`BundledMessages`]) are consulted before falling back to
+//! English. The locale can be chosen per validation with `validate_with_locale`, or
+//! globally with [`set_locale`], which `validate` uses.
+//!
+//! The English wording itself can be replaced application-wide with [`set_default`].
+//!
+//! Rules added with `ValidatorBuilder::rule_for_each` additionally support the
+//! `{CollectionIndex}` placeholder, in both default and custom messages.
+//!
+//! # Example
+//! ```rust,ignore
+//! use fluentval::messages::MessageCatalog;
+//!
+//! let german = MessageCatalog::new()
+//!     .with("de", "not_empty", "darf nicht leer sein")
+//!     .with("de", "min_length", "muss mindestens {min} Zeichen lang sein");
+//!
+//! let validator = ValidatorBuilder::<User>::new()
+//!     .with_message_provider(german)
+//!     .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").not_empty(None::<String>))
+//!     .build();
+//!
+//! let result = validator.validate_with_locale(&user, "de-DE");
+//!
+//! // Web adapters negotiate the locale from the request's Accept-Language header
+//! let locale = negotiate_locale("fr-CH, de;q=0.9, en;q=0.8", &["en", "de"]);
+//! assert_eq!(locale.as_deref(), Some("de"));
+//! ```
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::SystemTime;
+
+use crate::clock::Clock;
+use crate::context::ValidationContext;
+use crate::error::{SkipReason, SkippedRule};
+use crate::limits::ResourceLimits;
+use crate::rule::{EmptyValues, RuleDescriptor};
+
+/// Locale used when validating without an explicit locale
+pub const DEFAULT_LOCALE: &str = "en";
+
+static GLOBAL_LOCALE: RwLock<Option<Arc<str>>> = RwLock::new(None);
+
+/// Set the locale `validate` uses for default messages across the application
+pub fn set_locale(locale: impl Into<String>) {
+    *GLOBAL_LOCALE.write().unwrap_or_else(|e| e.into_inner()) = Some(locale.into().into());
+}
+
+/// Reset the global locale to `DEFAULT_LOCALE`
+pub fn reset_locale() {
+    *GLOBAL_LOCALE.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// The locale `validate` currently uses for default messages
+pub fn locale() -> String {
+    with_locale(str::to_string)
+}
+
+/// Call `f` with the global locale, without copying it
+///
+/// The lock is released before `f` runs, so `f` may validate recursively or set the locale.
+pub(crate) fn with_locale<R>(f: impl FnOnce(&str) -> R) -> R {
+    let locale = GLOBAL_LOCALE.read().unwrap_or_else(|e| e.into_inner()).clone();
+    f(locale.as_deref().unwrap_or(DEFAULT_LOCALE))
+}
+
+/// Source of default message templates, keyed by rule kind and locale
+pub trait MessageProvider: Send + Sync {
+    /// Return the message template for a rule kind in a locale, or `None` to fall back
+    fn message(&self, kind: &str, locale: &str) -> Option<String>;
+
+    /// The locales this provider has templates for, offered when negotiating a request's
+    /// `Accept-Language`
+    ///
+    /// Providers that can't list their locales return none, and only the built-in ones are
+    /// negotiated.
+    fn locales(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// The built-in English messages
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultMessages;
+
+impl MessageProvider for DefaultMessages {
+    fn message(&self, kind: &str, _locale: &str) -> Option<String> {
+        default_message(kind)
+    }
+}
+
+/// An in-memory table of message templates per locale and rule kind
+#[derive(Debug, Clone, Default)]
+pub struct MessageCatalog {
+    messages: HashMap<(String, String), String>,
+}
+
+impl MessageCatalog {
+    /// Create an empty catalog
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a template for a rule kind in a locale
+    pub fn with(mut self, locale: impl Into<String>, kind: impl Into<String>, template: impl Into<String>) -> Self {
+        self.insert(locale, kind, template);
+        self
+    }
+
+    /// Add or replace a template for a rule kind in a locale
+    pub fn insert(&mut self, locale: impl Into<String>, kind: impl Into<String>, template: impl Into<String>) {
+        self.messages.insert((locale.into(), kind.into()), template.into());
+    }
+}
+
+impl MessageProvider for MessageCatalog {
+    fn message(&self, kind: &str, locale: &str) -> Option<String> {
+        self.messages
+            .get(&(locale.to_string(), kind.to_string()))
+            .cloned()
+    }
+
+    fn locales(&self) -> Vec<String> {
+        let mut locales: Vec<String> = self.messages.keys().map(|(locale, _)| locale.clone()).collect();
+        locales.sort();
+        locales.dedup();
+        locales
+    }
+}
+
+fn default_overrides() -> &'static RwLock<HashMap<String, String>> {
+    static OVERRIDES: OnceLock<RwLock<HashMap<String, String>>> = OnceLock::new();
+    OVERRIDES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Replace the default message template of a built-in rule kind application-wide
+///
+/// The template supports the same placeholders as the built-in one (e.g. `{min}`).
+/// Custom messages and message providers still take precedence.
+///
+/// # Example
+/// ```rust,ignore
+/// fluentval::messages::set_default("not_empty", "This field is required");
+/// ```
+pub fn set_default(kind: impl Into<String>, template: impl Into<String>) {
+    default_overrides()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(kind.into(), template.into());
+}
+
+/// Remove all overrides registered with [`set_default`]
+pub fn clear_defaults() {
+    default_overrides().write().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// The default template for a rule kind, honouring [`set_default`] overrides
+pub fn default_message(kind: &str) -> Option<String> {
+    let overridden = default_overrides()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(kind)
+        .cloned();
+    overridden.or_else(|| default_template(kind).map(str::to_string))
+}
+
+/// Message catalogs bundled with the crate for `es`, `fr`, `de`, `pt` and `zh`
+///
+/// Always consulted before the English defaults when the `i18n` feature is enabled;
+/// it can also be passed to `with_message_provider` explicitly.
+#[cfg(feature = "i18n")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BundledMessages;
+
+#[cfg(feature = "i18n")]
+impl MessageProvider for BundledMessages {
+    fn message(&self, kind: &str, locale: &str) -> Option<String> {
+        bundled_template(kind, locale).map(str::to_string)
+    }
+
+    fn locales(&self) -> Vec<String> {
+        BUNDLED_LOCALES.iter().map(|locale| locale.to_string()).collect()
+    }
+}
+
+/// The locales of the bundled catalogs
+#[cfg(feature = "i18n")]
+const BUNDLED_LOCALES: [&str; 5] = ["es", "fr", "de", "pt", "zh"];
+
+#[cfg(feature = "i18n")]
+fn bundled_template(kind: &str, locale: &str) -> Option<&'static str> {
+    let template = match (locale, kind) {
+        ("es", "not_empty") => "no debe estar vacío",
+        ("es", "not_null") => "no debe ser nulo",
+        ("es", "min_length") => "debe tener al menos {min} caracteres",
+        ("es", "max_length") => "debe tener como máximo {max} caracteres",
+        ("es", "email") => "debe ser una dirección de correo electrónico válida",
+        ("es", "greater_than") => "debe ser mayor que {min}",
+        ("es", "greater_than_or_equal") => "debe ser mayor o igual que {min}",
+        ("es", "less_than") => "debe ser menor que {max}",
+        ("es", "less_than_or_equal") => "debe ser menor o igual que {max}",
+        ("es", "inclusive_between") => "debe estar entre {min} y {max}",
+        ("es", "same_length") => "debe tener el mismo número de elementos ({left_len} frente a {right_len})",
+        ("es", "sorted_ascending") => "debe estar ordenado de forma ascendente (el elemento {index} está fuera de orden)",
+        ("es", "strictly_increasing") => "debe ser estrictamente creciente (el elemento {index} está fuera de orden)",
+        ("es", "non_overlapping") => "se solapa con el elemento {first}",
+        ("es", "date_range") => "debe ser posterior a {start}",
+        ("es", "date_range_inclusive") => "no debe ser anterior a {start}",
+        ("es", "date_range_span") => "debe estar dentro de {max_span} desde {start}",
+        ("es", "at_most") => "debe ser como máximo {max}",
+        ("es", "at_least") => "debe ser como mínimo {min}",
+        ("es", "quota") => "debe contener como máximo {limit} elementos en el plan {plan}",
+        ("es", "sum_equals") => "debe ser igual a {expression} ({expected})",
+        ("es", "product_equals") => "debe ser igual a {expression} ({expected})",
+        ("es", "approx_equal") => "debe estar a menos de {epsilon} de {expected}",
+        ("es", "fits_in") => "debe caber en {type}",
+        ("es", "only_flags") => "solo debe activar indicadores dentro de {mask} (bits desconocidos {unknown})",
+        ("es", "has_flag") => "debe tener activado el indicador {flag}",
+        ("es", "known_code") => "debe ser uno de {codes}",
+        ("es", "http_header_name") => "debe ser un nombre de cabecera HTTP válido",
+        ("es", "http_header_value") => "debe ser un valor de cabecera HTTP válido",
+        ("es", "http_method") => "debe ser un método HTTP válido",
+        ("es", "safe_outbound_url") => "debe ser una URL http o https pública",
+        ("es", "not_disposable_email") => "no debe usar un dominio de correo desechable",
+        ("es", "title_case") => "debe tener cada palabra en mayúscula inicial",
+        ("es", "sentence_case") => "debe tener mayúscula solo al inicio de cada oración",
+        ("es", "expression") => "debe cumplir {expression}",
+        ("es", "not_similar_to") => "no debe ser demasiado parecido a {other}",
+        ("es", "no_repeated_chars") => "no debe repetir un carácter más de {max_run} veces seguidas",
+        ("es", "no_keyboard_sequences") => "no debe contener secuencias de teclado",
+        ("es", "not_in_breach_list") => "ha aparecido en una filtración de datos y no debe usarse",
+        ("es", "not_blank") => "no debe estar en blanco",
+        ("es", "blank") => "debe estar en blanco",
+        ("es", "exact_length") => "la longitud debe ser exactamente {length}",
+        ("es", "length_one_of") => "la longitud debe ser una de {lengths}",
+        ("es", "starts_with") => "debe empezar por {prefix}",
+        ("es", "ends_with") => "debe terminar en {suffix}",
+        ("es", "any_format") => "debe coincidir con uno de los formatos aceptados: {formats}",
+        ("es", "date_format") => "debe ser una fecha con el formato {format}",
+        ("es", "time_format") => "debe ser una hora con el formato {format}",
+        ("es", "matches") => "debe coincidir con el patrón {pattern}",
+        ("es", "resolvable_host") => "debe ser un nombre de host resoluble",
+        ("es", "email_has_mx") => "debe usar un dominio que acepte correo electrónico",
+        ("es", "in_past") => "debe estar en el pasado",
+        ("es", "in_future") => "debe estar en el futuro",
+        ("es", "age_at_least") => "debe tener al menos {years} años",
+        ("es", "rule_panicked") => "no se pudo validar",
+        ("es", "resource_limit") => "es demasiado grande para validarse (límite {limit})",
+        ("es", "context_missing") => "no se pudo validar sin su contexto",
+
+        ("fr", "not_empty") => "ne doit pas être vide",
+        ("fr", "not_null") => "ne doit pas être nul",
+        ("fr", "min_length") => "doit contenir au moins {min} caractères",
+        ("fr", "max_length") => "doit contenir au plus {max} caractères",
+        ("fr", "email") => "doit être une adresse e-mail valide",
+        ("fr", "greater_than") => "doit être supérieur à {min}",
+        ("fr", "greater_than_or_equal") => "doit être supérieur ou égal à {min}",
+        ("fr", "less_than") => "doit être inférieur à {max}",
+        ("fr", "less_than_or_equal") => "doit être inférieur ou égal à {max}",
+        ("fr", "inclusive_between") => "doit être compris entre {min} et {max}",
+        ("fr", "same_length") => "doit avoir le même nombre d'éléments ({left_len} contre {right_len})",
+        ("fr", "sorted_ascending") => "doit être trié par ordre croissant (l'élément {index} n'est pas à sa place)",
+        ("fr", "strictly_increasing") => "doit être strictement croissant (l'élément {index} n'est pas à sa place)",
+        ("fr", "non_overlapping") => "chevauche l'élément {first}",
+        ("fr", "date_range") => "doit être postérieur à {start}",
+        ("fr", "date_range_inclusive") => "ne doit pas être antérieur à {start}",
+        ("fr", "date_range_span") => "doit être à moins de {max_span} de {start}",
+        ("fr", "at_most") => "doit être au plus {max}",
+        ("fr", "at_least") => "doit être au moins {min}",
+        ("fr", "quota") => "doit contenir au plus {limit} éléments avec le forfait {plan}",
+        ("fr", "sum_equals") => "doit être égal à {expression} ({expected})",
+        ("fr", "product_equals") => "doit être égal à {expression} ({expected})",
+        ("fr", "approx_equal") => "doit être à {epsilon} près de {expected}",
+        ("fr", "fits_in") => "doit tenir dans {type}",
+        ("fr", "only_flags") => "ne doit activer que des indicateurs de {mask} (bits inconnus {unknown})",
+        ("fr", "has_flag") => "doit avoir l'indicateur {flag} activé",
+        ("fr", "known_code") => "doit être l'un de {codes}",
+        ("fr", "http_header_name") => "doit être un nom d'en-tête HTTP valide",
+        ("fr", "http_header_value") => "doit être une valeur d'en-tête HTTP valide",
+        ("fr", "http_method") => "doit être une méthode HTTP valide",
+        ("fr", "safe_outbound_url") => "doit être une URL http ou https publique",
+        ("fr", "not_disposable_email") => "ne doit pas utiliser un domaine d'e-mail jetable",
+        ("fr", "title_case") => "doit avoir une majuscule à chaque mot",
+        ("fr", "sentence_case") => "ne doit avoir une majuscule qu'en début de phrase",
+        ("fr", "expression") => "doit satisfaire {expression}",
+        ("fr", "not_similar_to") => "ne doit pas être trop similaire à {other}",
+        ("fr", "no_repeated_chars") => "ne doit pas répéter un caractère plus de {max_run} fois de suite",
+        ("fr", "no_keyboard_sequences") => "ne doit pas contenir de suites de touches du clavier",
+        ("fr", "not_in_breach_list") => "est apparu dans une fuite de données et ne doit pas être utilisé",
+        ("fr", "not_blank") => "ne doit pas être vide",
+        ("fr", "blank") => "doit être vide",
+        ("fr", "exact_length") => "la longueur doit être exactement {length}",
+        ("fr", "length_one_of") => "la longueur doit être l'une des valeurs {lengths}",
+        ("fr", "starts_with") => "doit commencer par {prefix}",
+        ("fr", "ends_with") => "doit se terminer par {suffix}",
+        ("fr", "any_format") => "doit correspondre à l'un des formats acceptés : {formats}",
+        ("fr", "date_format") => "doit être une date au format {format}",
+        ("fr", "time_format") => "doit être une heure au format {format}",
+        ("fr", "matches") => "doit correspondre au motif {pattern}",
+        ("fr", "resolvable_host") => "doit être un nom d'hôte résoluble",
+        ("fr", "email_has_mx") => "doit utiliser un domaine qui accepte les e-mails",
+        ("fr", "in_past") => "doit être dans le passé",
+        ("fr", "in_future") => "doit être dans le futur",
+        ("fr", "age_at_least") => "doit avoir au moins {years} ans",
+        ("fr", "rule_panicked") => "n'a pas pu être validé",
+        ("fr", "resource_limit") => "est trop volumineux pour être validé (limite {limit})",
+        ("fr", "context_missing") => "n'a pas pu être validé sans son contexte",
+
+        ("de", "not_empty") => "darf nicht leer sein",
+        ("de", "not_null") => "darf nicht null sein",
+        ("de", "min_length") => "muss mindestens {min} Zeichen lang sein",
+        ("de", "max_length") => "darf höchstens {max} Zeichen lang sein",
+        ("de", "email") => "muss eine gültige E-Mail-Adresse sein",
+        ("de", "greater_than") => "muss größer als {min} sein",
+        ("de", "greater_than_or_equal") => "muss größer oder gleich {min} sein",
+        ("de", "less_than") => "muss kleiner als {max} sein",
+        ("de", "less_than_or_equal") => "muss kleiner oder gleich {max} sein",
+        ("de", "inclusive_between") => "muss zwischen {min} und {max} liegen",
+        ("de", "same_length") => "muss gleich viele Elemente haben ({left_len} gegenüber {right_len})",
+        ("de", "sorted_ascending") => "muss aufsteigend sortiert sein (Element {index} ist nicht in der richtigen Reihenfolge)",
+        ("de", "strictly_increasing") => "muss streng monoton steigend sein (Element {index} ist nicht in der richtigen Reihenfolge)",
+        ("de", "non_overlapping") => "überschneidet sich mit Element {first}",
+        ("de", "date_range") => "muss nach {start} liegen",
+        ("de", "date_range_inclusive") => "darf nicht vor {start} liegen",
+        ("de", "date_range_span") => "muss innerhalb von {max_span} nach {start} liegen",
+        ("de", "at_most") => "darf höchstens {max} sein",
+        ("de", "at_least") => "muss mindestens {min} sein",
+        ("de", "quota") => "darf im Tarif {plan} höchstens {limit} Elemente enthalten",
+        ("de", "sum_equals") => "muss {expression} entsprechen ({expected})",
+        ("de", "product_equals") => "muss {expression} entsprechen ({expected})",
+        ("de", "approx_equal") => "muss höchstens {epsilon} von {expected} abweichen",
+        ("de", "fits_in") => "muss in {type} passen",
+        ("de", "only_flags") => "darf nur Flags aus {mask} setzen (unbekannte Bits {unknown})",
+        ("de", "has_flag") => "muss das Flag {flag} gesetzt haben",
+        ("de", "known_code") => "muss einer von {codes} sein",
+        ("de", "http_header_name") => "muss ein gültiger HTTP-Headername sein",
+        ("de", "http_header_value") => "muss ein gültiger HTTP-Headerwert sein",
+        ("de", "http_method") => "muss eine gültige HTTP-Methode sein",
+        ("de", "safe_outbound_url") => "muss eine öffentliche http- oder https-URL sein",
+        ("de", "not_disposable_email") => "darf keine Wegwerf-E-Mail-Domain verwenden",
+        ("de", "title_case") => "muss jedes Wort großschreiben",
+        ("de", "sentence_case") => "darf nur am Satzanfang großgeschrieben sein",
+        ("de", "expression") => "muss {expression} erfüllen",
+        ("de", "not_similar_to") => "darf {other} nicht zu ähnlich sein",
+        ("de", "no_repeated_chars") => "darf ein Zeichen nicht mehr als {max_run} Mal hintereinander wiederholen",
+        ("de", "no_keyboard_sequences") => "darf keine Tastaturfolgen enthalten",
+        ("de", "not_in_breach_list") => "ist in einem Datenleck aufgetaucht und darf nicht verwendet werden",
+        ("de", "not_blank") => "darf nicht leer sein",
+        ("de", "blank") => "muss leer sein",
+        ("de", "exact_length") => "die Länge muss genau {length} betragen",
+        ("de", "length_one_of") => "die Länge muss einer der Werte {lengths} sein",
+        ("de", "starts_with") => "muss mit {prefix} beginnen",
+        ("de", "ends_with") => "muss mit {suffix} enden",
+        ("de", "any_format") => "muss einem der akzeptierten Formate entsprechen: {formats}",
+        ("de", "date_format") => "muss ein Datum im Format {format} sein",
+        ("de", "time_format") => "muss eine Uhrzeit im Format {format} sein",
+        ("de", "matches") => "muss dem Muster {pattern} entsprechen",
+        ("de", "resolvable_host") => "muss ein auflösbarer Hostname sein",
+        ("de", "email_has_mx") => "muss eine Domain verwenden, die E-Mails annimmt",
+        ("de", "in_past") => "muss in der Vergangenheit liegen",
+        ("de", "in_future") => "muss in der Zukunft liegen",
+        ("de", "age_at_least") => "muss mindestens {years} Jahre alt sein",
+        ("de", "rule_panicked") => "konnte nicht validiert werden",
+        ("de", "resource_limit") => "ist zu groß für die Validierung (Grenze {limit})",
+        ("de", "context_missing") => "konnte ohne Kontext nicht validiert werden",
+
+        ("pt", "not_empty") => "não deve estar vazio",
+        ("pt", "not_null") => "não deve ser nulo",
+        ("pt", "min_length") => "deve ter pelo menos {min} caracteres",
+        ("pt", "max_length") => "deve ter no máximo {max} caracteres",
+        ("pt", "email") => "deve ser um endereço de e-mail válido",
+        ("pt", "greater_than") => "deve ser maior que {min}",
+        ("pt", "greater_than_or_equal") => "deve ser maior ou igual a {min}",
+        ("pt", "less_than") => "deve ser menor que {max}",
+        ("pt", "less_than_or_equal") => "deve ser menor ou igual a {max}",
+        ("pt", "inclusive_between") => "deve estar entre {min} e {max}",
+        ("pt", "same_length") => "deve ter o mesmo número de elementos ({left_len} contra {right_len})",
+        ("pt", "sorted_ascending") => "deve estar em ordem crescente (o elemento {index} está fora de ordem)",
+        ("pt", "strictly_increasing") => "deve ser estritamente crescente (o elemento {index} está fora de ordem)",
+        ("pt", "non_overlapping") => "sobrepõe-se ao elemento {first}",
+        ("pt", "date_range") => "deve ser posterior a {start}",
+        ("pt", "date_range_inclusive") => "não deve ser anterior a {start}",
+        ("pt", "date_range_span") => "deve estar dentro de {max_span} a partir de {start}",
+        ("pt", "at_most") => "deve ser no máximo {max}",
+        ("pt", "at_least") => "deve ser no mínimo {min}",
+        ("pt", "quota") => "deve conter no máximo {limit} itens no plano {plan}",
+        ("pt", "sum_equals") => "deve ser igual a {expression} ({expected})",
+        ("pt", "product_equals") => "deve ser igual a {expression} ({expected})",
+        ("pt", "approx_equal") => "deve estar a no máximo {epsilon} de {expected}",
+        ("pt", "fits_in") => "deve caber em {type}",
+        ("pt", "only_flags") => "deve ativar apenas sinalizadores dentro de {mask} (bits desconhecidos {unknown})",
+        ("pt", "has_flag") => "deve ter o sinalizador {flag} ativado",
+        ("pt", "known_code") => "deve ser um de {codes}",
+        ("pt", "http_header_name") => "deve ser um nome de cabeçalho HTTP válido",
+        ("pt", "http_header_value") => "deve ser um valor de cabeçalho HTTP válido",
+        ("pt", "http_method") => "deve ser um método HTTP válido",
+        ("pt", "safe_outbound_url") => "deve ser uma URL http ou https pública",
+        ("pt", "not_disposable_email") => "não deve usar um domínio de e-mail descartável",
+        ("pt", "title_case") => "deve ter cada palavra com inicial maiúscula",
+        ("pt", "sentence_case") => "deve ter maiúscula apenas no início de cada frase",
+        ("pt", "expression") => "deve satisfazer {expression}",
+        ("pt", "not_similar_to") => "não deve ser muito parecido com {other}",
+        ("pt", "no_repeated_chars") => "não deve repetir um caractere mais de {max_run} vezes seguidas",
+        ("pt", "no_keyboard_sequences") => "não deve conter sequências de teclado",
+        ("pt", "not_in_breach_list") => "apareceu em um vazamento de dados e não deve ser usado",
+        ("pt", "not_blank") => "não deve estar em branco",
+        ("pt", "blank") => "deve estar em branco",
+        ("pt", "exact_length") => "o comprimento deve ser exatamente {length}",
+        ("pt", "length_one_of") => "o comprimento deve ser um de {lengths}",
+        ("pt", "starts_with") => "deve começar com {prefix}",
+        ("pt", "ends_with") => "deve terminar com {suffix}",
+        ("pt", "any_format") => "deve corresponder a um dos formatos aceitos: {formats}",
+        ("pt", "date_format") => "deve ser uma data no formato {format}",
+        ("pt", "time_format") => "deve ser um horário no formato {format}",
+        ("pt", "matches") => "deve corresponder ao padrão {pattern}",
+        ("pt", "resolvable_host") => "deve ser um nome de host resolúvel",
+        ("pt", "email_has_mx") => "deve usar um domínio que aceite e-mail",
+        ("pt", "in_past") => "deve estar no passado",
+        ("pt", "in_future") => "deve estar no futuro",
+        ("pt", "age_at_least") => "deve ter pelo menos {years} anos",
+        ("pt", "rule_panicked") => "não pôde ser validado",
+        ("pt", "resource_limit") => "é grande demais para ser validado (limite {limit})",
+        ("pt", "context_missing") => "não pôde ser validado sem o seu contexto",
+
+        ("zh", "not_empty") => "不能为空",
+        ("zh", "not_null") => "不能为 null",
+        ("zh", "min_length") => "长度不能少于 {min} 个字符",
+        ("zh", "max_length") => "长度不能超过 {max} 个字符",
+        ("zh", "email") => "必须是有效的电子邮件地址",
+        ("zh", "greater_than") => "必须大于 {min}",
+        ("zh", "greater_than_or_equal") => "必须大于或等于 {min}",
+        ("zh", "less_than") => "必须小于 {max}",
+        ("zh", "less_than_or_equal") => "必须小于或等于 {max}",
+        ("zh", "inclusive_between") => "必须介于 {min} 和 {max} 之间",
+        ("zh", "same_length") => "元素数量必须相同（{left_len} 对 {right_len}）",
+        ("zh", "sorted_ascending") => "必须按升序排列（第 {index} 个元素顺序错误）",
+        ("zh", "strictly_increasing") => "必须严格递增（第 {index} 个元素顺序错误）",
+        ("zh", "non_overlapping") => "与第 {first} 个元素重叠",
+        ("zh", "date_range") => "必须晚于 {start}",
+        ("zh", "date_range_inclusive") => "不得早于 {start}",
+        ("zh", "date_range_span") => "必须在 {start} 之后的 {max_span} 以内",
+        ("zh", "at_most") => "不得大于 {max}",
+        ("zh", "at_least") => "不得小于 {min}",
+        ("zh", "quota") => "在 {plan} 套餐下最多只能包含 {limit} 项",
+        ("zh", "sum_equals") => "必须等于 {expression}（{expected}）",
+        ("zh", "product_equals") => "必须等于 {expression}（{expected}）",
+        ("zh", "approx_equal") => "与 {expected} 的差值不得超过 {epsilon}",
+        ("zh", "fits_in") => "必须能以 {type} 表示",
+        ("zh", "only_flags") => "只能设置 {mask} 范围内的标志（未知位 {unknown}）",
+        ("zh", "has_flag") => "必须设置标志 {flag}",
+        ("zh", "known_code") => "必须是 {codes} 之一",
+        ("zh", "http_header_name") => "必须是有效的 HTTP 头名称",
+        ("zh", "http_header_value") => "必须是有效的 HTTP 头值",
+        ("zh", "http_method") => "必须是有效的 HTTP 方法",
+        ("zh", "safe_outbound_url") => "必须是公共的 http 或 https URL",
+        ("zh", "not_disposable_email") => "不得使用一次性电子邮件域名",
+        ("zh", "title_case") => "每个单词必须首字母大写",
+        ("zh", "sentence_case") => "只能在句首使用大写字母",
+        ("zh", "expression") => "必须满足 {expression}",
+        ("zh", "not_similar_to") => "不能与 {other} 过于相似",
+        ("zh", "no_repeated_chars") => "同一字符不能连续重复超过 {max_run} 次",
+        ("zh", "no_keyboard_sequences") => "不能包含键盘序列",
+        ("zh", "not_in_breach_list") => "已出现在数据泄露中，不能使用",
+        ("zh", "not_blank") => "不能为空白",
+        ("zh", "blank") => "必须为空白",
+        ("zh", "exact_length") => "长度必须正好为 {length}",
+        ("zh", "length_one_of") => "长度必须是 {lengths} 之一",
+        ("zh", "starts_with") => "必须以 {prefix} 开头",
+        ("zh", "ends_with") => "必须以 {suffix} 结尾",
+        ("zh", "any_format") => "必须符合以下格式之一：{formats}",
+        ("zh", "date_format") => "必须是格式为 {format} 的日期",
+        ("zh", "time_format") => "必须是格式为 {format} 的时间",
+        ("zh", "matches") => "必须匹配模式 {pattern}",
+        ("zh", "resolvable_host") => "必须是可解析的主机名",
+        ("zh", "email_has_mx") => "必须使用可接收电子邮件的域名",
+        ("zh", "in_past") => "必须是过去的时间",
+        ("zh", "in_future") => "必须是将来的时间",
+        ("zh", "age_at_least") => "必须年满 {years} 岁",
+        ("zh", "rule_panicked") => "无法验证",
+        ("zh", "resource_limit") => "过大，无法验证（上限 {limit}）",
+        ("zh", "context_missing") => "缺少上下文，无法验证",
+
+        _ => return None,
+    };
+    Some(template)
+}
+
+/// The built-in English template for a rule kind
+pub fn default_template(kind: &str) -> Option<&'static str> {
+    let template = match kind {
+        "not_empty" => "must not be empty",
+        "not_null" => "must not be null",
+        "min_length" => "must be at least {min} characters long",
+        "max_length" => "must be at most {max} characters long",
+        "email" => "must be a valid email address",
+        "greater_than" => "must be greater than {min}",
+        "greater_than_or_equal" => "must be greater than or equal to {min}",
+        "less_than" => "must be less than {max}",
+        "less_than_or_equal" => "must be less than or equal to {max}",
+        "inclusive_between" => "must be between {min} and {max}",
+        "approx_equal" => "must be within {epsilon} of {expected}",
+        "fits_in" => "must fit in {type}",
+        "only_flags" => "must only set flags within {mask} (unknown bits {unknown})",
+        "has_flag" => "must have flag {flag} set",
+        "known_code" => "must be one of {codes}",
+        "http_header_name" => "must be a valid HTTP header name",
+        "http_header_value" => "must be a valid HTTP header value",
+        "http_method" => "must be a valid HTTP method",
+        "safe_outbound_url" => "must be a public http or https URL",
+        "not_disposable_email" => "must not use a disposable email domain",
+        "title_case" => "must be in title case",
+        "sentence_case" => "must be in sentence case",
+        "expression" => "must satisfy {expression}",
+        "not_similar_to" => "must not be too similar to {other}",
+        "no_repeated_chars" => "must not repeat a character more than {max_run} times in a row",
+        "no_keyboard_sequences" => "must not contain keyboard sequences",
+        "not_in_breach_list" => "has appeared in a data breach and must not be used",
+        "not_blank" => "must not be blank",
+        "blank" => "must be blank",
+        "exact_length" => "length must be exactly {length}",
+        "length_one_of" => "length must be one of {lengths}",
+        "starts_with" => "must start with {prefix}",
+        "ends_with" => "must end with {suffix}",
+        "any_format" => "must match one of the accepted formats: {formats}",
+        "date_format" => "must be a date in the format {format}",
+        "time_format" => "must be a time in the format {format}",
+        "matches" => "must match the pattern {pattern}",
+        "resolvable_host" => "must be a resolvable host name",
+        "email_has_mx" => "must use a domain that accepts email",
+        "in_past" => "must be in the past",
+        "in_future" => "must be in the future",
+        "age_at_least" => "must be at least {years} years old",
+        "rule_panicked" => "could not be validated",
+        "resource_limit" => "is too large to validate (limit {limit})",
+        "context_missing" => "could not be validated without its context",
+        "same_length" => "must have the same number of elements ({left_len} vs {right_len})",
+        "sorted_ascending" => "must be sorted in ascending order (element {index} is out of order)",
+        "strictly_increasing" => "must be strictly increasing (element {index} is out of order)",
+        "non_overlapping" => "overlaps element {first}",
+        "date_range" => "must be after {start}",
+        "date_range_inclusive" => "must not be before {start}",
+        "date_range_span" => "must be within {max_span} of {start}",
+        "at_most" => "must be at most {max}",
+        "at_least" => "must be at least {min}",
+        "quota" => "must contain at most {limit} items on the {plan} plan",
+        "sum_equals" => "must equal {expression} ({expected})",
+        "product_equals" => "must equal {expression} ({expected})",
+        _ => return None,
+    };
+    Some(template)
+}
+
+/// The English requirement `Validator::describe` shows for a rule kind, when it differs from
+/// the failure message because that names failure details such as `{index}` or `{expected}`
+fn requirement_template(kind: &str, params: &[(String, String)]) -> Option<&'static str> {
+    let has = |name: &str| params.iter().any(|(k, _)| k == name);
+    let template = match kind {
+        "approx_equal" if has("other") => "must be within {epsilon} of {other}",
+        "only_flags" => "must only set flags within {mask}",
+        "same_length" => "must have the same number of elements as its counterpart",
+        "sorted_ascending" => "must be sorted in ascending order",
+        "strictly_increasing" => "must be strictly increasing",
+        "non_overlapping" => "must not contain overlapping intervals",
+        "date_range" if has("allow_equal") => "must not be before the start",
+        "date_range" => "must be after the start",
+        "at_most" => "must not exceed the configured limit",
+        "at_least" => "must reach the configured minimum",
+        "quota" => "must stay within the plan's quota",
+        "not_similar_to" => "must not resemble {other}",
+        "sum_equals" | "product_equals" => "must equal {expression}",
+        _ => return None,
+    };
+    Some(template)
+}
+
+/// Describe what a rule requires, or `None` for custom rules that carry no message
+///
+/// Uses the rule's custom message as-is when configured, and otherwise the English default
+/// (honouring [`set_default`] overrides) rendered with the rule's parameters.
+pub(crate) fn requirement(rule: &RuleDescriptor) -> Option<String> {
+    let template = match &rule.message {
+        Some(message) => return Some(message.clone()),
+        None if rule.kind == "custom" => return None,
+        None => requirement_template(&rule.kind, &rule.params)
+            .map(str::to_string)
+            .or_else(|| default_message(&rule.kind))
+            .unwrap_or_else(|| "is invalid".to_string()),
+    };
+    Some(render(&template, &rule.params))
+}
+
+/// Replace `{name}` placeholders in a template with parameter values
+pub fn render(template: &str, params: &[(String, String)]) -> String {
+    let mut message = template.to_string();
+    for (name, value) in params {
+        message = message.replace(&format!("{{{}}}", name), value);
+    }
+    message
+}
+
+/// Parse an `Accept-Language` header into language tags, most preferred first
+///
+/// Tags are ordered by their `q` weight (default 1.0), keeping header order for ties.
+/// The wildcard `*` and tags with `q=0` are dropped.
+pub fn accept_language(header: &str) -> Vec<String> {
+    let mut tags: Vec<(String, f32)> = header
+        .split(',')
+        .filter_map(|part| {
+            let mut pieces = part.split(';');
+            let tag = pieces.next()?.trim();
+            let quality = pieces
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (!tag.is_empty() && tag != "*" && quality > 0.0).then(|| (tag.to_string(), quality))
+        })
+        .collect();
+    tags.sort_by(|a, b| b.1.total_cmp(&a.1));
+    tags.into_iter().map(|(tag, _)| tag).collect()
+}
+
+/// Pick the best supported locale for an `Accept-Language` header
+///
+/// A requested tag matches a supported locale exactly (case-insensitively) or by language,
+/// so `de-AT` selects a supported `de`, and `de` selects a supported `de-DE`.
+/// Returns `None` when nothing matches, in which case callers use `DEFAULT_LOCALE`.
+pub fn negotiate_locale(header: &str, supported: &[&str]) -> Option<String> {
+    let language = |tag: &str| tag.split(['-', '_']).next().unwrap_or(tag).to_ascii_lowercase();
+    for requested in accept_language(header) {
+        if let Some(found) = supported.iter().find(|s| s.eq_ignore_ascii_case(&requested)) {
+            return Some(found.to_string());
+        }
+        if let Some(found) = supported.iter().find(|s| language(s) == language(&requested)) {
+            return Some(found.to_string());
+        }
+    }
+    None
+}
+
+/// The locales default messages can be served in: those of `provider`, the bundled catalogs
+/// (feature `i18n`) and `DEFAULT_LOCALE`
+pub fn available_locales(provider: Option<&dyn MessageProvider>) -> Vec<String> {
+    let mut locales = provider.map(|provider| provider.locales()).unwrap_or_default();
+    #[cfg(feature = "i18n")]
+    locales.extend(BundledMessages.locales());
+    locales.push(DEFAULT_LOCALE.to_string());
+    let mut seen = HashSet::new();
+    locales.retain(|locale| seen.insert(locale.clone()));
+    locales
+}
+
+/// Locale and provider used to resolve default messages during a validation run, plus
+/// the caller's context for context-aware rules, the clock for time-dependent ones, the
+/// validator's empty-value and panic policies and resource limits, and where to record
+/// skipped rules when the validator records them
+pub(crate) struct MessageContext<'a> {
+    pub(crate) locale: &'a str,
+    pub(crate) provider: Option<&'a dyn MessageProvider>,
+    pub(crate) data: Option<&'a dyn Any>,
+    pub(crate) clock: Option<&'a dyn Clock>,
+    pub(crate) empty_values: EmptyValues,
+    pub(crate) isolate_panics: bool,
+    pub(crate) limits: ResourceLimits,
+    pub(crate) skipped: Option<&'a RefCell<Vec<SkippedRule>>>,
+}
+
+impl Default for MessageContext<'_> {
+    fn default() -> Self {
+        Self {
+            locale: DEFAULT_LOCALE,
+            provider: None,
+            data: None,
+            clock: None,
+            empty_values: EmptyValues::Validate,
+            isolate_panics: false,
+            limits: ResourceLimits::default(),
+            skipped: None,
+        }
+    }
+}
+
+impl MessageContext<'_> {
+    /// The current time by the validator's clock, or the system clock when none is set
+    pub(crate) fn now(&self) -> SystemTime {
+        self.clock.map_or_else(SystemTime::now, |clock| clock.now())
+    }
+
+    /// The context passed to `validate_with_context`, if it has type `C` or is a
+    /// `ValidationContext` with an extension of type `C`
+    pub(crate) fn data<C: 'static>(&self) -> Option<&C> {
+        let data = self.data?;
+        data.downcast_ref::<C>()
+            .or_else(|| data.downcast_ref::<ValidationContext>().and_then(|context| context.extension::<C>()))
+    }
+
+    /// Record that rules of the given kinds did not run for `property`, if skipped rules are
+    /// recorded
+    pub(crate) fn skip<'k>(&self, property: &Arc<str>, kinds: impl IntoIterator<Item = &'k str>, reason: SkipReason) {
+        if let Some(skipped) = self.skipped {
+            skipped.borrow_mut().extend(kinds.into_iter().map(|kind| SkippedRule { property: Arc::clone(property), rule_kind: kind.to_string(), reason }));
+        }
+    }
+
+    /// Number of skipped rules recorded so far
+    pub(crate) fn skipped_len(&self) -> usize {
+        self.skipped.map_or(0, |skipped| skipped.borrow().len())
+    }
+
+    /// Rename the properties of the rules skipped since the first `start` were recorded
+    pub(crate) fn rename_skipped(&self, start: usize, rename: impl Fn(&str) -> Arc<str>) {
+        if let Some(skipped) = self.skipped {
+            for skipped in &mut skipped.borrow_mut()[start..] {
+                skipped.property = rename(&skipped.property);
+            }
+        }
+    }
+
+    /// Resolve and render the default message for a rule kind
+    ///
+    /// `provider` takes precedence over the context's provider when given, and both take
+    /// precedence over the bundled catalogs and then the (possibly overridden) defaults.
+    pub(crate) fn message(&self, provider: Option<&dyn MessageProvider>, kind: &str, params: &[(String, String)]) -> String {
+        #[cfg(feature = "i18n")]
+        let providers = [provider, self.provider, Some(&BundledMessages as &dyn MessageProvider)];
+        #[cfg(not(feature = "i18n"))]
+        let providers = [provider, self.provider];
+        for provider in providers.into_iter().flatten() {
+            for locale in locale_chain(self.locale) {
+                if let Some(template) = provider.message(kind, locale) {
+                    return render(&template, params);
+                }
+            }
+        }
+        render(default_message(kind).as_deref().unwrap_or("is invalid"), params)
+    }
+}
+
+/// The locale followed by its language, e.g. `["de-DE", "de"]`
+fn locale_chain(locale: &str) -> Vec<&str> {
+    let mut chain = vec![locale];
+    if let Some((language, _)) = locale.split_once(['-', '_']) {
+        chain.push(language);
+    }
+    chain
+}
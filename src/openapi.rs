@@ -0,0 +1,111 @@
+//! OpenAPI component schemas reflecting validator constraints, via utoipa
+//!
+//! Lengths, numeric ranges, the `email` format and required properties are written into a
+//! utoipa [`Schema`], either merged into the schema derived with `ToSchema` or built from
+//! scratch, so documented APIs reflect the validation that actually runs.
+//!
+//! # Example
+//! ```rust,ignore
+//! use fluentval::openapi::apply_constraints;
+//!
+//! let mut schema = match CreateUser::schema() {
+//!     RefOr::T(schema) => schema,
+//!     RefOr::Ref(_) => unreachable!(),
+//! };
+//! apply_constraints(&create_user_validator(), &mut schema);
+//! openapi.components.as_mut().unwrap().schemas.insert("CreateUser".into(), schema.into());
+//! ```
+
+use utoipa::openapi::schema::{ArrayItems, KnownFormat, Object, Schema, SchemaFormat, SchemaType, Type};
+use utoipa::openapi::{Array, RefOr};
+use utoipa::Number;
+
+use crate::schema::{analyze, Constraints};
+use crate::traits::Validator;
+
+/// Build an object component schema from a validator's constraints
+pub fn component_schema<T, V: Validator<T> + ?Sized>(validator: &V) -> Schema {
+    let mut schema = Schema::Object(Object::with_type(Type::Object));
+    apply_constraints(validator, &mut schema);
+    schema
+}
+
+/// Merge a validator's constraints into an object schema, such as one derived with `ToSchema`
+///
+/// Existing property schemas are tightened in place; properties the schema lacks are added.
+/// Properties behind a `$ref` and non-object schemas are left unchanged.
+pub fn apply_constraints<T, V: Validator<T> + ?Sized>(validator: &V, schema: &mut Schema) {
+    let Schema::Object(object) = schema else { return };
+    let rules = validator.rules();
+    let (properties, required) = analyze(&rules);
+    for (name, constraints) in &properties {
+        let property = object
+            .properties
+            .entry(name.to_string())
+            .or_insert_with(|| RefOr::T(new_schema(constraints)));
+        if let RefOr::T(property) = property {
+            apply(constraints, property);
+        }
+    }
+    for name in required {
+        if !object.required.iter().any(|r| r == name) {
+            object.required.push(name.to_string());
+        }
+    }
+}
+
+/// An empty schema of the constraints' type
+fn new_schema(constraints: &Constraints) -> Schema {
+    match (constraints.ty, &constraints.items) {
+        (Some("array"), Some(items)) => Schema::Array(Array::new(RefOr::T(new_schema(items)))),
+        (Some("string"), _) => Schema::Object(Object::with_type(Type::String)),
+        (Some("number"), _) => Schema::Object(Object::with_type(Type::Number)),
+        _ => Schema::Object(Object::new()),
+    }
+}
+
+fn apply(constraints: &Constraints, schema: &mut Schema) {
+    match schema {
+        Schema::Array(array) => {
+            if let (Some(items), ArrayItems::RefOrSchema(item)) = (&constraints.items, &mut array.items) {
+                if let RefOr::T(item) = item.as_mut() {
+                    apply(items, item);
+                }
+            }
+        }
+        Schema::Object(object) => {
+            if object.schema_type == SchemaType::AnyValue {
+                match constraints.ty {
+                    Some("string") => object.schema_type = SchemaType::Type(Type::String),
+                    Some("number") => object.schema_type = SchemaType::Type(Type::Number),
+                    _ => {}
+                }
+            }
+            if constraints.format == Some("email") {
+                object.format = Some(SchemaFormat::KnownFormat(KnownFormat::Email));
+            }
+            if let Some(pattern) = &constraints.pattern {
+                object.pattern = Some(pattern.clone());
+            }
+            for (keyword, value) in &constraints.bounds {
+                match *keyword {
+                    "minLength" => object.min_length = value.parse().ok(),
+                    "maxLength" => object.max_length = value.parse().ok(),
+                    "minimum" => object.minimum = Some(number(value)),
+                    "maximum" => object.maximum = Some(number(value)),
+                    "exclusiveMinimum" => object.exclusive_minimum = Some(number(value)),
+                    "exclusiveMaximum" => object.exclusive_maximum = Some(number(value)),
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn number(value: &str) -> Number {
+    match value.parse::<isize>() {
+        Ok(int) => Number::Int(int),
+        Err(_) => Number::Float(value.parse().unwrap_or_default()),
+    }
+}
@@ -0,0 +1,214 @@
+//! Password policy rules
+
+#[cfg(feature = "breach")]
+use std::sync::Arc;
+
+#[cfg(feature = "breach")]
+use sha1::{Digest, Sha1};
+
+#[cfg(feature = "breach")]
+use crate::async_rule::{AsyncRuleBuilder, BoxFuture};
+use crate::builder::ValidatorBuilder;
+use crate::error::ValidationError;
+use crate::messages::{render, MessageContext};
+use crate::rule::{str_is_empty, RuleBuilder, RuleDescriptor};
+
+/// Keyboard rows and the alphabet, in which `no_keyboard_sequences` looks for runs
+const SEQUENCES: [&str; 5] = ["qwertyuiop", "asdfghjkl", "zxcvbnm", "1234567890", "abcdefghijklmnopqrstuvwxyz"];
+
+/// Shortest run of adjacent keys that counts as a keyboard sequence
+const MIN_SEQUENCE: usize = 4;
+
+impl<T> RuleBuilder<T> {
+    /// Validate that no character repeats more than `max_run` times in a row, e.g. `"aaaa"`
+    ///
+    /// # Arguments
+    /// * `max_run` - Longest allowed run of the same character
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn no_repeated_chars(self, max_run: usize, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.empty_when(str_is_empty::<T>).built_in("no_repeated_chars", vec![("max_run", max_run.to_string())], message.map(|m| m.into()),
+            move |value| longest_run(value.as_ref()) <= max_run,
+            None)
+    }
+
+    /// Validate that the value contains no run of four or more adjacent keys or letters,
+    /// such as `"qwer"`, `"4321"` or `"abcd"`
+    ///
+    /// Runs are looked for along the rows of a QWERTY keyboard, the digits and the
+    /// alphabet, in either direction and ignoring case.
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn no_keyboard_sequences(self, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.empty_when(str_is_empty::<T>).built_in("no_keyboard_sequences", Vec::new(), message.map(|m| m.into()),
+            |value| !has_keyboard_sequence(value.as_ref()),
+            None)
+    }
+}
+
+/// Length of the longest run of one repeated character
+fn longest_run(value: &str) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous = None;
+    for c in value.chars() {
+        run = if previous == Some(c) { run + 1 } else { 1 };
+        longest = longest.max(run);
+        previous = Some(c);
+    }
+    longest
+}
+
+fn has_keyboard_sequence(value: &str) -> bool {
+    let chars: Vec<char> = value.to_lowercase().chars().collect();
+    chars.windows(MIN_SEQUENCE).any(|window| {
+        let window: String = window.iter().collect();
+        let reversed: String = window.chars().rev().collect();
+        SEQUENCES.iter().any(|sequence| sequence.contains(&window) || sequence.contains(&reversed))
+    })
+}
+
+/// Source of leaked password hashes, queried with the k-anonymity range protocol of
+/// [Pwned Passwords](https://haveibeenpwned.com/API/v3#PwnedPasswords)
+///
+/// Only the first five hex digits of the password's SHA-1 hash are sent; the provider
+/// returns the suffixes of every leaked hash with that prefix. Implement it with the HTTP
+/// client of your choice, or over a local copy of the data set.
+///
+/// # Example
+/// ```rust,ignore
+/// struct PwnedPasswords(reqwest::Client);
+///
+/// impl BreachRangeProvider for PwnedPasswords {
+///     fn range<'a>(&'a self, prefix: &'a str) -> BoxFuture<'a, std::io::Result<String>> {
+///         Box::pin(async move {
+///             let url = format!("https://api.pwnedpasswords.com/range/{}", prefix);
+///             let response = self.0.get(url).send().await.map_err(std::io::Error::other)?;
+///             response.text().await.map_err(std::io::Error::other)
+///         })
+///     }
+/// }
+/// ```
+#[cfg(feature = "breach")]
+pub trait BreachRangeProvider: Send + Sync {
+    /// The hashes for an uppercase five-digit prefix, one `SUFFIX:COUNT` line per hash
+    fn range<'a>(&'a self, prefix: &'a str) -> BoxFuture<'a, std::io::Result<String>>;
+}
+
+#[cfg(feature = "breach")]
+impl<T> AsyncRuleBuilder<T> {
+    /// Validate that a password doesn't appear in a breach corpus
+    ///
+    /// The password's SHA-1 hash is looked up through `provider` without revealing it (see
+    /// [`BreachRangeProvider`]). The number of breaches is reported in the error state as
+    /// `count`. When the provider fails, the password is accepted, so an outage of the
+    /// breach API doesn't block sign-ups.
+    ///
+    /// # Arguments
+    /// * `provider` - The breach corpus, e.g. a client for the Pwned Passwords API
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn not_in_breach_list(self, provider: impl BreachRangeProvider + 'static, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let provider = Arc::new(provider);
+        self.built_in("not_in_breach_list", Vec::new(), message.map(|m| m.into()),
+            move |value| {
+                let hash: String = Sha1::digest(value.as_ref().as_bytes()).iter().map(|byte| format!("{:02X}", byte)).collect();
+                let provider = Arc::clone(&provider);
+                Box::pin(async move {
+                    let (prefix, suffix) = hash.split_at(5);
+                    let range = provider.range(prefix).await.ok()?;
+                    let count = range.lines().find_map(|line| {
+                        let (candidate, count) = line.trim().split_once(':')?;
+                        candidate.eq_ignore_ascii_case(suffix).then(|| count.trim().parse::<u64>().unwrap_or(1))
+                    })?;
+                    (count > 0).then(|| vec![("count", count.to_string())])
+                })
+            })
+    }
+}
+
+impl<T> ValidatorBuilder<T> {
+    /// Validate that a property doesn't resemble another one, e.g. a password the username
+    ///
+    /// Values are compared case-insensitively. Similarity is `1 - distance / length`, with the
+    /// Levenshtein edit distance and the length of the longer value in characters, so `1.0`
+    /// means identical. A value containing the other counts as identical, and an empty other
+    /// value never matches. The error is reported on `property` with the other property's
+    /// name and the similarity in the state; the value itself is not echoed.
+    ///
+    /// # Arguments
+    /// * `max_similarity` - Highest similarity that still passes, between `0.0` and `1.0`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .not_similar_to("password", |u| &u.password, "username", |u| &u.username, 0.6, None::<String>)
+    /// ```
+    pub fn not_similar_to<V, W, FA, FB>(self, property_name: impl Into<String>, actual: FA, other_name: impl Into<String>, other: FB, max_similarity: f64, message: Option<impl Into<String>>) -> Self
+    where
+        FA: Fn(&T) -> &V + Send + Sync + 'static,
+        FB: Fn(&T) -> &W + Send + Sync + 'static,
+        V: AsRef<str> + ?Sized + 'static,
+        W: AsRef<str> + ?Sized + 'static,
+    {
+        let property_name = property_name.into();
+        let other_name = other_name.into();
+        let message = message.map(|m| m.into());
+        let descriptor = RuleDescriptor::new(property_name.clone(), "not_similar_to", vec![("other", other_name.clone()), ("max_similarity", max_similarity.to_string())]).with_message(message.clone());
+        self.add_rule(descriptor, move |instance: &T, messages: &MessageContext| {
+            let similarity = similarity(actual(instance).as_ref(), other(instance).as_ref());
+            if similarity <= max_similarity {
+                return Vec::new();
+            }
+            let similarity = format!("{:.2}", similarity);
+            let params = vec![
+                ("other".to_string(), other_name.clone()),
+                ("similarity".to_string(), similarity.clone()),
+            ];
+            let message = match &message {
+                Some(message) => render(message, &params),
+                None => messages.message(None, "not_similar_to", &params),
+            };
+            vec![ValidationError::new(property_name.clone(), message)
+                .with_code("NOT_SIMILAR_TO")
+                .with_state("other", &other_name)
+                .with_state("similarity", similarity)]
+        })
+    }
+}
+
+/// Case-insensitive similarity of two strings between `0.0` and `1.0`
+fn similarity(value: &str, other: &str) -> f64 {
+    let value = value.to_lowercase();
+    let other = other.to_lowercase();
+    if other.is_empty() {
+        return 0.0;
+    }
+    if value.contains(&other) {
+        return 1.0;
+    }
+    let value: Vec<char> = value.chars().collect();
+    let other: Vec<char> = other.chars().collect();
+    1.0 - levenshtein(&value, &other) as f64 / value.len().max(other.len()) as f64
+}
+
+/// Number of single-character insertions, deletions and substitutions turning `a` into `b`
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
@@ -0,0 +1,145 @@
+//! Validators run in stages, each only if the previous ones passed
+
+use std::any::Any;
+use std::collections::HashSet;
+
+#[cfg(feature = "async")]
+use crate::async_rule::BoxFuture;
+use crate::error::ValidationResult;
+use crate::rule::RuleDescriptor;
+use crate::traits::Validator;
+
+/// A sequence of named validation stages, e.g. syntactic → semantic → remote
+///
+/// Stages run in the order they were added, and a stage only runs if every earlier stage
+/// passed, so expensive checks (database lookups, remote calls) are skipped for payloads
+/// that are malformed anyway. Each error records the name of the stage that reported it in
+/// its `stage` state, and the result keeps the skipped rules of every stage that ran.
+///
+/// # Example
+/// ```rust,ignore
+/// let pipeline = ValidationPipeline::<Order>::new()
+///     .stage("syntactic", shape_validator)
+///     .stage("semantic", business_rules)
+///     .stage("remote", inventory_checks);
+///
+/// let result = pipeline.validate(&order);
+/// if let Some(error) = result.errors().first() {
+///     println!("rejected in the {} stage", error.state["stage"]);
+/// }
+/// ```
+pub struct ValidationPipeline<T> {
+    stages: Vec<(String, Box<dyn Validator<T>>)>,
+}
+
+impl<T> ValidationPipeline<T> {
+    /// Create a pipeline without stages
+    pub fn new() -> Self {
+        Self { stages: Vec::new() }
+    }
+
+    /// Add a stage, run after the stages added before it
+    pub fn stage(mut self, name: impl Into<String>, validator: impl Validator<T> + 'static) -> Self {
+        self.stages.push((name.into(), Box::new(validator)));
+        self
+    }
+
+    /// Names of the stages, in order
+    pub fn stage_names(&self) -> Vec<&str> {
+        self.stages.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Run the stages until one fails, tagging its errors with the stage name
+    fn run(&self, mut validate: impl FnMut(&dyn Validator<T>) -> ValidationResult) -> ValidationResult {
+        let mut result = ValidationResult::new();
+        for (name, validator) in &self.stages {
+            result = tag(validate(validator.as_ref()), name, &result);
+            if !result.is_valid() {
+                break;
+            }
+        }
+        result
+    }
+}
+
+impl<T> Default for ValidationPipeline<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Validator<T> for ValidationPipeline<T> {
+    fn validate(&self, instance: &T) -> ValidationResult {
+        self.run(|validator| validator.validate(instance))
+    }
+
+    fn validate_with_locale(&self, instance: &T, locale: &str) -> ValidationResult {
+        self.run(|validator| validator.validate_with_locale(instance, locale))
+    }
+
+    fn validate_with_context(&self, instance: &T, context: &dyn Any) -> ValidationResult {
+        self.run(|validator| validator.validate_with_context(instance, context))
+    }
+
+    fn validate_with_locale_and_context(&self, instance: &T, locale: &str, context: &dyn Any) -> ValidationResult {
+        self.run(|validator| validator.validate_with_locale_and_context(instance, locale, context))
+    }
+
+    fn validate_property(&self, instance: &T, property: &str) -> ValidationResult {
+        self.run(|validator| validator.validate_property(instance, property))
+    }
+
+    fn validate_property_with_context(&self, instance: &T, property: &str, context: &dyn Any) -> ValidationResult {
+        self.run(|validator| validator.validate_property_with_context(instance, property, context))
+    }
+
+    #[cfg(feature = "async")]
+    fn validate_async<'a>(&'a self, instance: &'a T) -> BoxFuture<'a, ValidationResult> {
+        Box::pin(async move {
+            let mut result = ValidationResult::new();
+            for (name, validator) in &self.stages {
+                result = tag(validator.validate_async(instance).await, name, &result);
+                if !result.is_valid() {
+                    break;
+                }
+            }
+            result
+        })
+    }
+
+    #[cfg(feature = "async")]
+    fn validate_async_with_context<'a>(&'a self, instance: &'a T, context: &'a dyn Any) -> BoxFuture<'a, ValidationResult> {
+        Box::pin(async move {
+            let mut result = ValidationResult::new();
+            for (name, validator) in &self.stages {
+                result = tag(validator.validate_async_with_context(instance, context).await, name, &result);
+                if !result.is_valid() {
+                    break;
+                }
+            }
+            result
+        })
+    }
+
+    /// Locales of every stage, in stage order
+    fn locales(&self) -> Vec<String> {
+        let mut locales: Vec<String> = self.stages.iter().flat_map(|(_, validator)| validator.locales()).collect();
+        let mut seen = HashSet::new();
+        locales.retain(|locale| seen.insert(locale.clone()));
+        locales
+    }
+
+    /// Rules of every stage, in stage order
+    fn rules(&self) -> Vec<RuleDescriptor> {
+        self.stages.iter().flat_map(|(_, validator)| validator.rules()).collect()
+    }
+}
+
+/// Tag the errors of a stage with its name, keeping the rules skipped by the stages before it
+fn tag(mut result: ValidationResult, stage: &str, earlier: &ValidationResult) -> ValidationResult {
+    for error in result.errors_mut() {
+        error.state.insert("stage".to_string(), stage.to_string());
+    }
+    result.add_earlier_skipped_rules(earlier.skipped_rules());
+    result
+}
@@ -0,0 +1,82 @@
+//! Plan limits read from the validation context
+
+use crate::rule::{context, RuleBuilder};
+
+/// A plan limit, as returned by the context accessor of [`RuleBuilder::within_quota`]
+///
+/// When the limit is exceeded, `limit`, `plan` and `upgrade_url` are attached to the error's
+/// state so the API layer can render an actionable upgrade message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quota {
+    /// Maximum number of elements allowed
+    pub limit: usize,
+    /// Name of the caller's plan, e.g. `"free"`
+    pub plan: String,
+    /// Where the caller can upgrade to a higher limit
+    pub upgrade_url: Option<String>,
+}
+
+impl Quota {
+    /// Create a quota without an upgrade URL
+    pub fn new(limit: usize, plan: impl Into<String>) -> Self {
+        Self {
+            limit,
+            plan: plan.into(),
+            upgrade_url: None,
+        }
+    }
+
+    /// Set the URL where the caller can upgrade
+    pub fn upgrade_url(mut self, url: impl Into<String>) -> Self {
+        self.upgrade_url = Some(url.into());
+        self
+    }
+}
+
+impl<T> RuleBuilder<T> {
+    /// Validate that a collection has no more elements than the caller's plan allows
+    ///
+    /// The quota is read from the context passed to `Validator::validate_with_context`.
+    /// Errors have the code `QUOTA`, and carry `limit`, `plan`, `count` and (when set)
+    /// `upgrade_url` in their state.
+    ///
+    /// # Arguments
+    /// * `quota` - Function reading the quota from the context
+    /// * `message` - Optional custom error message. `{limit}`, `{plan}`, `{count}` and `{upgrade_url}` are replaced.
+    ///
+    /// A validation without a context of type `C` fails with a `CONTEXT_MISSING` error,
+    /// since skipping the rule would lift the quota.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::for_property("projects")
+    ///     .within_quota(|ctx: &Ctx| Quota::new(ctx.plan.max_projects, &ctx.plan.name)
+    ///         .upgrade_url("https://example.com/billing"), None::<String>)
+    /// ```
+    pub fn within_quota<C, E>(self, quota: impl Fn(&C) -> Quota + Send + Sync + 'static, message: Option<impl Into<String>>) -> Self
+    where
+        for<'a> &'a T: IntoIterator<Item = &'a E>,
+        C: 'static,
+        E: 'static,
+    {
+        self.built_in_detailed("quota", Vec::new(), message.map(|m| m.into()),
+            move |value, messages| {
+                let quota = quota(context::<C>(messages));
+                let count = value.into_iter().count();
+                if count <= quota.limit {
+                    return None;
+                }
+                let mut details = vec![
+                    ("limit", quota.limit.to_string()),
+                    ("plan", quota.plan),
+                    ("count", count.to_string()),
+                ];
+                if let Some(url) = quota.upgrade_url {
+                    details.push(("upgrade_url", url));
+                }
+                Some(details)
+            },
+            None)
+            .requires_context::<C>()
+    }
+}
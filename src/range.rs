@@ -0,0 +1,117 @@
+//! Rules relating a pair of start/end properties
+
+use std::fmt::Display;
+use std::ops::Sub;
+
+use crate::builder::ValidatorBuilder;
+use crate::error::ValidationError;
+use crate::messages::{render, MessageContext};
+use crate::rule::RuleDescriptor;
+
+/// Options for [`ValidatorBuilder::date_range`]
+///
+/// `D` is the span type produced by `end - start`, e.g. `chrono::TimeDelta` for dates or
+/// `i64` for Unix timestamps.
+///
+/// # Example
+/// ```rust,ignore
+/// DateRange::new()
+///     .allow_equal(true)
+///     .max_span(TimeDelta::days(30))
+///     .message("the booking must end after it starts")
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateRange<D> {
+    allow_equal: bool,
+    max_span: Option<D>,
+    message: Option<String>,
+}
+
+impl<D> DateRange<D> {
+    /// Require the end to be strictly after the start, with no maximum span
+    pub fn new() -> Self {
+        Self {
+            allow_equal: false,
+            max_span: None,
+            message: None,
+        }
+    }
+
+    /// Accept ranges whose end equals their start
+    pub fn allow_equal(mut self, allow: bool) -> Self {
+        self.allow_equal = allow;
+        self
+    }
+
+    /// Reject ranges longer than `span`
+    pub fn max_span(mut self, span: D) -> Self {
+        self.max_span = Some(span);
+        self
+    }
+
+    /// Use a custom error message for every failure. `{start}`, `{end}` and `{max_span}` are replaced.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+impl<D> Default for DateRange<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ValidatorBuilder<T> {
+    /// Validate that a start property comes before an end property
+    ///
+    /// The error is reported on `property`, typically the end. Failures have the code
+    /// `DATE_RANGE` (end before start, or equal unless `allow_equal`) or `DATE_RANGE_SPAN`
+    /// (longer than `max_span`), with `start` and `end` in their state.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .date_range("end", |b| &b.start, |b| &b.end, DateRange::new().max_span(TimeDelta::days(30)))
+    /// ```
+    pub fn date_range<K, D, FS, FE>(self, property_name: impl Into<String>, start: FS, end: FE, options: DateRange<D>) -> Self
+    where
+        FS: Fn(&T) -> &K + Send + Sync + 'static,
+        FE: Fn(&T) -> &K + Send + Sync + 'static,
+        K: PartialOrd + Clone + Sub<Output = D> + Display + 'static,
+        D: PartialOrd + Display + Send + Sync + 'static,
+    {
+        let property_name = property_name.into();
+        let mut params = Vec::new();
+        if options.allow_equal {
+            params.push(("allow_equal", "true".to_string()));
+        }
+        if let Some(max_span) = &options.max_span {
+            params.push(("max_span", max_span.to_string()));
+        }
+        let descriptor = RuleDescriptor::new(property_name.clone(), "date_range", params).with_message(options.message.clone());
+        self.add_rule(descriptor, move |instance: &T, messages: &MessageContext| {
+            let (start, end) = (start(instance), end(instance));
+            let kind = if end < start || (end == start && !options.allow_equal) {
+                if options.allow_equal { "date_range_inclusive" } else { "date_range" }
+            } else if options.max_span.as_ref().is_some_and(|max_span| end.clone() - start.clone() > *max_span) {
+                "date_range_span"
+            } else {
+                return Vec::new();
+            };
+            let mut params = vec![("start".to_string(), start.to_string()), ("end".to_string(), end.to_string())];
+            if let Some(max_span) = &options.max_span {
+                params.push(("max_span".to_string(), max_span.to_string()));
+            }
+            let message = match &options.message {
+                Some(message) => render(message, &params),
+                None => messages.message(None, kind, &params),
+            };
+            let code = if kind == "date_range_span" { "DATE_RANGE_SPAN" } else { "DATE_RANGE" };
+            vec![ValidationError::new(property_name.clone(), message)
+                .with_code(code)
+                .with_attempted_value(end)
+                .with_state("start", start)
+                .with_state("end", end)]
+        })
+    }
+}
@@ -0,0 +1,182 @@
+//! Translating errors into another field vocabulary
+
+use crate::error::ValidationResult;
+use crate::format::is_within;
+
+/// A mapping table rewriting property paths and error codes
+///
+/// Lets results of an internal domain validator be surfaced in a public API's field names.
+/// A property mapping also applies to nested and indexed properties: with
+/// `"customer.address" -> "shippingAddress"`, `customer.address.zip` becomes
+/// `shippingAddress.zip`. When several mappings apply, the longest one wins. Unmapped
+/// properties and codes are kept.
+///
+/// # Example
+/// ```rust,ignore
+/// let public = ErrorMap::new()
+///     .property("customer.email", "buyerEmail")
+///     .property("customer.address", "shippingAddress")
+///     .code("EMAIL", "INVALID_EMAIL");
+///
+/// let result = domain_validator.validate(&order).remap(&public);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorMap {
+    properties: Vec<(String, String)>,
+    codes: Vec<(String, String)>,
+}
+
+impl ErrorMap {
+    /// Create an empty mapping
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report errors on `from` (and its nested properties) under `to`
+    pub fn property(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.properties.push((from.into(), to.into()));
+        self
+    }
+
+    /// Report the error code `from` as `to`
+    pub fn code(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.codes.push((from.into(), to.into()));
+        self
+    }
+
+    /// The public name of a property path
+    pub fn map_property(&self, property: &str) -> String {
+        let mapping = self
+            .properties
+            .iter()
+            .filter(|(from, _)| is_within(property, from))
+            .max_by_key(|(from, _)| from.len());
+        match mapping {
+            Some((from, to)) => format!("{}{}", to, &property[from.len()..]),
+            None => property.to_string(),
+        }
+    }
+
+    /// The public name of an error code
+    pub fn map_code<'a>(&'a self, code: &'a str) -> &'a str {
+        self.codes.iter().find(|(from, _)| from == code).map_or(code, |(_, to)| to)
+    }
+}
+
+/// A naming convention for property names, applied with `ValidationResult::with_property_case`
+///
+/// Each name of a path is recased on its own, so `billing_address.postal_code` becomes
+/// `billingAddress.postalCode` in [`Camel`](Case::Camel). Indexes and map keys in brackets,
+/// as in `line_items[2]` or `labels[build_id]`, are kept as they are. Words are split at
+/// underscores, hyphens and case changes, so `userID`, `user_id` and `UserId` all convert
+/// to the same names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `postalCode`
+    Camel,
+    /// `postal_code`
+    Snake,
+    /// `PostalCode`
+    Pascal,
+}
+
+impl Case {
+    /// A property path with each of its names in this case
+    pub fn convert(self, property: &str) -> String {
+        let mut converted = String::with_capacity(property.len());
+        let mut rest = property;
+        while !rest.is_empty() {
+            if rest.starts_with('[') {
+                let end = rest.find(']').map_or(rest.len(), |end| end + 1);
+                converted.push_str(&rest[..end]);
+                rest = &rest[end..];
+            } else if let Some(after) = rest.strip_prefix('.') {
+                converted.push('.');
+                rest = after;
+            } else {
+                let end = rest.find(['.', '[']).unwrap_or(rest.len());
+                self.convert_name(&rest[..end], &mut converted);
+                rest = &rest[end..];
+            }
+        }
+        converted
+    }
+
+    /// Append one name of a path in this case
+    fn convert_name(self, name: &str, converted: &mut String) {
+        for (index, word) in words(name).into_iter().enumerate() {
+            let mut chars = word.chars();
+            let Some(first) = chars.next() else { continue };
+            match self {
+                Case::Snake => {
+                    if index > 0 {
+                        converted.push('_');
+                    }
+                    converted.extend(word.chars().flat_map(char::to_lowercase));
+                }
+                Case::Camel if index == 0 => converted.extend(word.chars().flat_map(char::to_lowercase)),
+                Case::Camel | Case::Pascal => {
+                    converted.extend(first.to_uppercase());
+                    converted.extend(chars.flat_map(char::to_lowercase));
+                }
+            }
+        }
+    }
+}
+
+/// The words of a name, split at `_`, `-`, lower-to-upper case changes and the end of
+/// acronyms (`HTTPServer` is `HTTP`, `Server`)
+fn words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in name.split(['_', '-']).filter(|part| !part.is_empty()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for window in 1..chars.len() {
+            let (position, current) = chars[window];
+            let previous = chars[window - 1].1;
+            let next = chars.get(window + 1).map(|&(_, next)| next);
+            let starts_word = current.is_uppercase()
+                && (previous.is_lowercase() || previous.is_numeric() || (previous.is_uppercase() && next.is_some_and(char::is_lowercase)));
+            if starts_word {
+                words.push(&part[start..position]);
+                start = position;
+            }
+        }
+        words.push(&part[start..]);
+    }
+    words
+}
+
+impl ValidationResult {
+    /// Rewrite the properties and codes of the errors with a mapping table
+    pub fn remap(mut self, map: &ErrorMap) -> Self {
+        for error in self.errors_mut() {
+            error.property = map.map_property(&error.property).into();
+            if let Some(code) = &error.code {
+                error.code = Some(map.map_code(code).to_string());
+            }
+        }
+        for skipped in self.skipped_rules_mut() {
+            skipped.property = map.map_property(&skipped.property).into();
+        }
+        self
+    }
+
+    /// Report the properties of the errors in another naming convention, e.g. the camelCase
+    /// field names of a JSON API for a validator written against snake_case Rust fields
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let result = validator.validate(&order).with_property_case(Case::Camel);
+    /// assert_eq!(result.first_error_for("shippingAddress.postalCode"), Some("must not be empty"));
+    /// ```
+    pub fn with_property_case(mut self, case: Case) -> Self {
+        for error in self.errors_mut() {
+            error.property = case.convert(&error.property).into();
+        }
+        for skipped in self.skipped_rules_mut() {
+            skipped.property = case.convert(&skipped.property).into();
+        }
+        self
+    }
+}
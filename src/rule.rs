@@ -1,13 +1,240 @@
-use crate::error::ValidationError;
-use crate::traits::{Numeric, OptionLike};
+use std::collections::BTreeMap;
+use std::fmt::{Display, LowerHex};
+use std::hash::Hash;
+use std::ops::Bound;
+use std::panic::{self, AssertUnwindSafe};
+
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime};
+
+use regex::Regex;
+
+use crate::cache::RuleMemo;
+use crate::context::ValidationContext;
+use crate::error::{Severity, SkipReason, ValidationError};
+use crate::limits::{self, InputSize};
+use crate::messages::{render, MessageContext, MessageProvider};
+use crate::traits::{Flags, HasLength, Numeric, OptionLike};
 
 /// Rule function type that validates a value and returns an optional error message
-pub type Rule<T> = Box<dyn Fn(&T) -> Option<String>>;
+pub type Rule<T> = Box<dyn Fn(&T) -> Option<String> + Send + Sync>;
+
+/// Formats the offending value of a failed rule for error reports
+type ValueFormatter<T> = fn(&T) -> String;
+
+/// Produces the custom state attached to a failed rule's error
+type StateFn<T> = Box<dyn Fn(&T) -> BTreeMap<String, String> + Send + Sync>;
+
+/// Tells whether a validation was passed the context a rule reads from, and names its type
+type ContextProbe = (fn(&MessageContext) -> bool, &'static str);
+
+/// Decides from the validation context whether a rule chain is skipped
+type ContextPredicate = Box<dyn Fn(&ValidationContext) -> bool + Send + Sync>;
+
+/// Tells whether a property's value is empty, for [`EmptyValues::Skip`]
+type EmptyProbe<T> = fn(&T) -> bool;
+
+/// Measures a value against the validator's resource limits
+pub(crate) type SizeProbe<T> = fn(&T) -> InputSize;
+
+/// Structured description of a configured rule, used for introspection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleDescriptor {
+    /// Property the rule reports errors for
+    pub property: String,
+    /// Rule kind, e.g. `"min_length"`, `"must"` or `"custom"`
+    pub kind: String,
+    /// Rule parameters in declaration order, e.g. `[("min", "2")]`
+    pub params: Vec<(String, String)>,
+    /// Custom message configured for the rule, or `None` when the default message is used
+    pub message: Option<String>,
+    /// Error code reported when the rule fails, if any
+    pub code: Option<String>,
+    /// Documentation attached with `RuleBuilder::describe`
+    pub description: Option<String>,
+    /// Severity of the rule's errors
+    pub severity: Severity,
+    /// Whether the rule runs on empty values the validator otherwise skips
+    pub even_if_empty: bool,
+    /// Whether the rule is skipped under a `skip_when_context` condition
+    pub conditional: bool,
+    /// When the rule starts being enforced, if set with `RuleBuilder::enforced_after`
+    pub enforced_after: Option<SystemTime>,
+}
+
+/// Whether `not_empty_opts` trims whitespace before checking for emptiness
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrimMode {
+    /// Check the value as-is, so `" "` is not empty
+    None,
+    /// Trim leading and trailing whitespace first, so `" "` is empty
+    #[default]
+    Trim,
+}
+
+/// Whether rules run on empty values (`""`, whitespace, empty collections, `None`)
+///
+/// Set validator-wide with `ValidatorBuilder::empty_values`. Presence rules (`not_empty`,
+/// `not_blank`, `blank`, `not_null`) and rules marked with
+/// [`RuleBuilder::even_if_empty`] always run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmptyValues {
+    /// Run every rule, so `email` fails on `""` alongside `not_empty`
+    #[default]
+    Validate,
+    /// Skip the other rules of a property whose value is empty, making properties without a
+    /// presence rule optional
+    Skip,
+}
+
+impl TrimMode {
+    fn apply(self, value: &str) -> &str {
+        match self {
+            TrimMode::None => value,
+            TrimMode::Trim => value.trim(),
+        }
+    }
+}
+
+impl RuleDescriptor {
+    pub(crate) fn new(property: impl Into<String>, kind: &str, params: Vec<(&str, String)>) -> Self {
+        Self {
+            property: property.into(),
+            kind: kind.to_string(),
+            params: params.into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+            message: None,
+            code: Some(kind.to_uppercase()),
+            description: None,
+            severity: Severity::Error,
+            even_if_empty: false,
+            conditional: false,
+            enforced_after: None,
+        }
+    }
+
+    /// Record the custom message configured for the rule
+    pub(crate) fn with_message(mut self, message: Option<String>) -> Self {
+        self.message = message;
+        self
+    }
+
+    /// Record that the rule's errors carry no code
+    pub(crate) fn without_code(mut self) -> Self {
+        self.code = None;
+        self
+    }
+}
+
+/// Named values describing a particular failure, e.g. `[("index", "3")]`
+pub(crate) type FailureDetails = Vec<(&'static str, String)>;
+
+/// A built-in check returning failure details, or `None` when the value passes
+type DetailedCheck<T> = Box<dyn Fn(&T, &MessageContext) -> Option<FailureDetails> + Send + Sync>;
+
+/// A built-in rule that is evaluated directly, without a boxed closure
+///
+/// Covers the most common checks; their values are read through monomorphized function
+/// pointers (e.g. `<T as AsRef<str>>::as_ref`) rather than captured closures.
+enum RuleKind<T> {
+    /// Byte length of a string within `min..=max`
+    Length { text: fn(&T) -> &str, min: usize, max: usize },
+    /// Number not beyond either bound, so NaN, which compares false against both, passes as it
+    /// did with the closure-based rules
+    Range { number: fn(&T) -> f64, min: Bound<f64>, max: Bound<f64> },
+    /// String matching a regular expression
+    Pattern { text: fn(&T) -> &str, regex: Arc<Regex> },
+    /// Syntactically valid email address
+    Email { text: fn(&T) -> &str },
+}
+
+impl<T> RuleKind<T> {
+    #[inline]
+    fn passes(&self, value: &T) -> bool {
+        match self {
+            RuleKind::Length { text, min, max } => (*min..=*max).contains(&text(value).len()),
+            RuleKind::Range { number, min, max } => {
+                let number = number(value);
+                let below = match *min {
+                    Bound::Included(min) => number < min,
+                    Bound::Excluded(min) => number <= min,
+                    Bound::Unbounded => false,
+                };
+                let above = match *max {
+                    Bound::Included(max) => number > max,
+                    Bound::Excluded(max) => number >= max,
+                    Bound::Unbounded => false,
+                };
+                !below && !above
+            }
+            RuleKind::Pattern { text, regex } => regex.is_match(text(value)),
+            RuleKind::Email { text } => is_email(text(value)),
+        }
+    }
+}
+
+/// Rules applied to a value derived from the property, reporting their own errors
+type NestedRules<T> = Box<dyn Fn(&T, &MessageContext) -> Vec<ValidationError> + Send + Sync>;
+
+/// How a rule decides whether a value is valid and what message it reports
+enum Check<T> {
+    /// A user rule that produces its own message
+    Custom(Rule<T>),
+    /// A built-in check whose message is either custom or looked up by rule kind
+    ///
+    /// The check returns `Some(details)` on failure; the details fill message placeholders
+    /// alongside the rule's parameters and are copied into the error's state.
+    BuiltIn {
+        check: DetailedCheck<T>,
+        message: Option<String>,
+    },
+    /// A built-in check from the [`RuleKind`] table, with the same message handling as `BuiltIn`
+    Native {
+        rule: RuleKind<T>,
+        message: Option<String>,
+    },
+    /// The rules of another builder, run on a value derived from this one
+    Nested {
+        rules: NestedRules<T>,
+        descriptors: Vec<RuleDescriptor>,
+    },
+}
+
+/// A rule together with its description and the error code, attempted value and state it reports on failure
+struct RuleEntry<T> {
+    check: Check<T>,
+    kind: String,
+    params: Vec<(String, String)>,
+    description: Option<String>,
+    code: Option<String>,
+    severity: Severity,
+    attempted_value: Option<ValueFormatter<T>>,
+    state: Option<StateFn<T>>,
+    /// Runs on empty values even when the validator skips them
+    even_if_empty: bool,
+    /// The context the rule reads from, reported missing instead of running without it
+    context: Option<ContextProbe>,
+}
+
+impl<T> RuleEntry<T> {
+    /// The kinds of rules this entry runs, as reported when it is skipped
+    fn kinds(&self) -> Vec<&str> {
+        match &self.check {
+            Check::Nested { descriptors, .. } => descriptors.iter().map(|descriptor| descriptor.kind.as_str()).collect(),
+            _ => vec![self.kind.as_str()],
+        }
+    }
+}
 
 /// Builder for creating validation rules in a fluent style
 pub struct RuleBuilder<T> {
     property_name: String,
-    rules: Vec<Rule<T>>,
+    rules: Vec<RuleEntry<T>>,
+    message_provider: Option<Arc<dyn MessageProvider>>,
+    skip_when: Vec<ContextPredicate>,
+    enforced_after: Option<SystemTime>,
+    is_empty: Option<EmptyProbe<T>>,
+    size: Option<SizeProbe<T>>,
+    sensitive: bool,
 }
 
 impl<T> RuleBuilder<T> {
@@ -16,16 +243,353 @@ impl<T> RuleBuilder<T> {
         Self {
             property_name: property_name.into(),
             rules: Vec::new(),
+            message_provider: None,
+            skip_when: Vec::new(),
+            enforced_after: None,
+            is_empty: None,
+            size: None,
+            sensitive: false,
         }
     }
 
+    /// Use a message provider for the default messages of this property's rules
+    ///
+    /// Takes precedence over the provider configured on the `ValidatorBuilder`.
+    pub fn with_message_provider(mut self, provider: impl MessageProvider + 'static) -> Self {
+        self.message_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Skip every rule of this property when `condition` holds for the validation context
+    ///
+    /// Lets bulk imports bypass expensive or strict rules that interactive paths keep. Without a
+    /// `ValidationContext`, the condition sees an empty context.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::for_property("email")
+    ///     .email(None::<String>)
+    ///     .skip_when_context(|ctx| ctx.flag("import_mode"))
+    /// ```
+    pub fn skip_when_context(mut self, condition: impl Fn(&ValidationContext) -> bool + Send + Sync + 'static) -> Self {
+        self.skip_when.push(Box::new(condition));
+        self
+    }
+
+    /// Keep this property's values out of its errors, e.g. for passwords and secrets
+    ///
+    /// Failed rules don't record an `attempted_value`, so the value appears neither in
+    /// error messages formatted with `Display` nor in serialized results.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::for_property("password").min_length(12, None::<String>).sensitive()
+    /// ```
+    pub fn sensitive(mut self) -> Self {
+        self.sensitive = true;
+        self
+    }
+
+    /// Only enforce this property's rules from `start` on, e.g. a stricter policy announced in advance
+    ///
+    /// Before `start`, by the validator's clock (see `ValidatorBuilder::with_clock`), every rule
+    /// of this property is skipped.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::for_property("password")
+    ///     .min_length(12, None::<String>)
+    ///     .enforced_after(UNIX_EPOCH + Duration::from_secs(1_767_225_600))
+    /// ```
+    pub fn enforced_after(mut self, start: SystemTime) -> Self {
+        self.enforced_after = Some(start);
+        self
+    }
+
     /// Add a custom rule
-    pub fn rule(mut self, rule: impl Fn(&T) -> Option<String> + 'static) -> Self {
-        self.rules.push(Box::new(rule));
+    pub fn rule(mut self, rule: impl Fn(&T) -> Option<String> + Send + Sync + 'static) -> Self {
+        self.rules.push(RuleEntry {
+            check: Check::Custom(Box::new(rule)),
+            kind: "custom".to_string(),
+            params: Vec::new(),
+            description: None,
+            code: None,
+            severity: Severity::Error,
+            attempted_value: None,
+            state: None,
+            even_if_empty: false,
+            context: None,
+        });
         self
     }
 
+    /// Set the error code reported by the most recently added rule
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        if let Some(entry) = self.rules.last_mut() {
+            entry.code = Some(code.into());
+        }
+        self
+    }
+
+    /// Set the severity reported by the most recently added rule
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        if let Some(entry) = self.rules.last_mut() {
+            entry.severity = severity;
+        }
+        self
+    }
+
+    /// Document why the most recently added rule exists (shown in `to_markdown` exports)
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::<String>::for_property("password")
+    ///     .min_length(12, None::<String>)
+    ///     .describe("Security policy SEC-42: passwords need at least 12 characters")
+    /// ```
+    pub fn describe(mut self, description: impl Into<String>) -> Self {
+        if let Some(entry) = self.rules.last_mut() {
+            entry.description = Some(description.into());
+        }
+        self
+    }
+
+    /// Run the most recently added rule on empty values, even under [`EmptyValues::Skip`]
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // An optional field that may be left out, but not filled with spaces
+    /// RuleBuilder::<String>::for_property("nickname")
+    ///     .length(3, 20, None::<String>, None::<String>)
+    ///     .must(|name| name.is_empty() || !name.trim().is_empty(), "must not be only whitespace")
+    ///     .even_if_empty()
+    /// ```
+    pub fn even_if_empty(mut self) -> Self {
+        if let Some(entry) = self.rules.last_mut() {
+            entry.even_if_empty = true;
+        }
+        self
+    }
+
+    /// Memoize the outcome of the most recently added rule by the hash of the checked value
+    ///
+    /// For custom rules and built-ins doing lookup- or regex-heavy work on values that repeat,
+    /// e.g. the same country code across thousands of rows of a batch. Up to `capacity`
+    /// outcomes are kept, each for `ttl`; when full, the oldest is dropped. The rule must
+    /// only depend on the value: don't cache rules reading the validation context or the
+    /// clock. Values are told apart by their hash alone, so a hash collision (vanishingly
+    /// unlikely with 64-bit hashes) reuses another value's outcome.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::<String>::for_property("country")
+    ///     .must(|code| sanctions_list().allows(code), "is not a supported country")
+    ///     .cached(1_000, Duration::from_secs(600))
+    /// ```
+    pub fn cached(mut self, capacity: usize, ttl: Duration) -> Self
+    where
+        T: Hash + 'static,
+    {
+        let Some(entry) = self.rules.last_mut() else {
+            return self;
+        };
+        entry.check = match std::mem::replace(&mut entry.check, Check::Custom(Box::new(|_| None))) {
+            Check::Custom(rule) => {
+                let memo = RuleMemo::new(capacity, ttl);
+                Check::Custom(Box::new(move |value| memo.get_or_insert_with(value, || rule(value))))
+            }
+            Check::BuiltIn { check, message } => {
+                let memo = RuleMemo::new(capacity, ttl);
+                Check::BuiltIn {
+                    check: Box::new(move |value, messages| memo.get_or_insert_with(value, || check(value, messages))),
+                    message,
+                }
+            }
+            // Table rules are cheaper than a lookup, and nested rules are cached individually
+            check => check,
+        };
+        self
+    }
+
+    /// Attach custom state to errors produced by the most recently added rule
+    ///
+    /// The closure receives the property value and is only called when the rule fails.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::<Vec<Item>>::for_property("items")
+    ///     .must(|items| items.len() <= 10, "too many items")
+    ///     .with_state(|items| [("limit", 10), ("count", items.len())])
+    /// ```
+    pub fn with_state<I, K, V>(mut self, state: impl Fn(&T) -> I + Send + Sync + 'static) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Display,
+    {
+        if let Some(entry) = self.rules.last_mut() {
+            entry.state = Some(Box::new(move |value| {
+                state(value)
+                    .into_iter()
+                    .map(|(k, v)| (k.into(), v.to_string()))
+                    .collect()
+            }));
+        }
+        self
+    }
+
+    /// Add a built-in rule
+    ///
+    /// Without a custom `message`, the message is looked up by `kind` when the rule fails,
+    /// with `params` filling the template's placeholders. The error code is the upper-cased
+    /// rule kind, e.g. `min_length` reports `MIN_LENGTH`.
+    pub(crate) fn built_in(
+        self,
+        kind: &str,
+        params: Vec<(&str, String)>,
+        message: Option<String>,
+        passes: impl Fn(&T) -> bool + Send + Sync + 'static,
+        attempted_value: Option<ValueFormatter<T>>,
+    ) -> Self {
+        self.built_in_detailed(kind, params, message, move |value, _| (!passes(value)).then(Vec::new), attempted_value)
+    }
+
+    /// Add a built-in rule evaluated from the [`RuleKind`] table
+    fn native(mut self, kind: &str, params: Vec<(&str, String)>, message: Option<String>, rule: RuleKind<T>, attempted_value: Option<ValueFormatter<T>>) -> Self {
+        self.rules.push(RuleEntry {
+            check: Check::Native { rule, message },
+            kind: kind.to_string(),
+            params: params.into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+            description: None,
+            code: Some(kind.to_uppercase()),
+            severity: Severity::Error,
+            attempted_value,
+            state: None,
+            even_if_empty: false,
+            context: None,
+        });
+        self
+    }
+
+    /// Run the rules of `inner` on a value derived from this property, when there is one
+    ///
+    /// Their errors are reported on this property.
+    pub(crate) fn nested<V: 'static>(mut self, inner: RuleBuilder<V>, derive: impl Fn(&T) -> Option<V> + Send + Sync + 'static) -> Self {
+        let descriptors = inner.descriptors();
+        let inner = inner.build_with_messages();
+        self.rules.push(RuleEntry {
+            check: Check::Nested {
+                rules: Box::new(move |value, messages| derive(value).map(|derived| inner(&derived, messages)).unwrap_or_default()),
+                descriptors,
+            },
+            kind: "nested".to_string(),
+            params: Vec::new(),
+            description: None,
+            code: None,
+            severity: Severity::Error,
+            attempted_value: None,
+            state: None,
+            even_if_empty: false,
+            context: None,
+        });
+        self
+    }
+
+    /// Record that the last rule reads a context of type `C`, so validating without one
+    /// reports `CONTEXT_MISSING` instead of running it
+    pub(crate) fn requires_context<C: 'static>(mut self) -> Self {
+        if let Some(entry) = self.rules.last_mut() {
+            let probe: ContextProbe = (has_context::<C>, std::any::type_name::<C>());
+            entry.context = Some(probe);
+        }
+        self
+    }
+
+    /// Record how to tell whether the property's value is empty, for [`EmptyValues::Skip`]
+    ///
+    /// Called by rules whose bounds know the value's shape; the first probe wins.
+    pub(crate) fn empty_when(mut self, probe: EmptyProbe<T>) -> Self {
+        self.is_empty.get_or_insert(probe);
+        self
+    }
+
+    /// Record how to measure the property's value, for the validator's resource limits
+    ///
+    /// Called by rules whose cost grows with the value; the first probe wins.
+    pub(crate) fn sized_by(mut self, probe: SizeProbe<T>) -> Self {
+        self.size.get_or_insert(probe);
+        self
+    }
+
+    /// Name of the property the rules apply to
+    pub(crate) fn property_name(&self) -> &str {
+        &self.property_name
+    }
+
+    /// Add a built-in rule whose check reports details about each failure
+    pub(crate) fn built_in_detailed(
+        mut self,
+        kind: &str,
+        params: Vec<(&str, String)>,
+        message: Option<String>,
+        check: impl Fn(&T, &MessageContext) -> Option<FailureDetails> + Send + Sync + 'static,
+        attempted_value: Option<ValueFormatter<T>>,
+    ) -> Self {
+        self.rules.push(RuleEntry {
+            check: Check::BuiltIn {
+                check: Box::new(check),
+                message,
+            },
+            kind: kind.to_string(),
+            params: params.into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+            description: None,
+            code: Some(kind.to_uppercase()),
+            severity: Severity::Error,
+            attempted_value,
+            state: None,
+            even_if_empty: false,
+            context: None,
+        });
+        self
+    }
+
+    /// Describe the configured rules
+    pub(crate) fn descriptors(&self) -> Vec<RuleDescriptor> {
+        self.rules
+            .iter()
+            .flat_map(|entry| match &entry.check {
+                Check::Nested { descriptors, .. } => descriptors
+                    .iter()
+                    .map(|descriptor| RuleDescriptor {
+                        property: self.property_name.clone(),
+                        conditional: descriptor.conditional || !self.skip_when.is_empty(),
+                        enforced_after: descriptor.enforced_after.max(self.enforced_after),
+                        ..descriptor.clone()
+                    })
+                    .collect(),
+                check => vec![RuleDescriptor {
+                    property: self.property_name.clone(),
+                    kind: entry.kind.clone(),
+                    params: entry.params.clone(),
+                    message: match check {
+                        Check::BuiltIn { message, .. } | Check::Native { message, .. } => message.clone(),
+                        _ => None,
+                    },
+                    code: entry.code.clone(),
+                    description: entry.description.clone(),
+                    severity: entry.severity,
+                    even_if_empty: entry.even_if_empty,
+                    conditional: !self.skip_when.is_empty(),
+                    enforced_after: self.enforced_after,
+                }],
+            })
+            .collect()
+    }
+
     /// Validate that the value is not empty (for strings)
+    ///
+    /// Whitespace is trimmed first, so `" "` is empty; use [`not_empty_opts`](Self::not_empty_opts)
+    /// with [`TrimMode::None`] to accept it.
     /// 
     /// # Arguments
     /// * `message` - Optional custom error message. If not provided, uses default message.
@@ -33,14 +597,54 @@ impl<T> RuleBuilder<T> {
     where
         T: AsRef<str>,
     {
-        let msg = message.map(|m| m.into()).unwrap_or_else(|| "must not be empty".to_string());
-        self.rule(move |value| {
-            if value.as_ref().trim().is_empty() {
-                Some(msg.clone())
-            } else {
-                None
-            }
-        })
+        self.not_empty_opts(TrimMode::Trim, message)
+    }
+
+    /// Validate that the value is not empty, choosing whether whitespace is trimmed first
+    ///
+    /// # Arguments
+    /// * `trim` - [`TrimMode::Trim`] rejects whitespace-only values, [`TrimMode::None`] only the empty string
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn not_empty_opts(self, trim: TrimMode, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let params = match trim {
+            TrimMode::Trim => Vec::new(),
+            TrimMode::None => vec![("trim", "none".to_string())],
+        };
+        self.empty_when(str_is_empty::<T>).built_in("not_empty", params, message.map(|m| m.into()),
+            move |value| !trim.apply(value.as_ref()).is_empty(),
+            Some(str_value::<T>))
+            .even_if_empty()
+    }
+
+    /// Validate that the value contains something other than whitespace
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn not_blank(self, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.empty_when(str_is_empty::<T>).built_in("not_blank", Vec::new(), message.map(|m| m.into()),
+            |value| !value.as_ref().trim().is_empty(),
+            Some(str_value::<T>))
+            .even_if_empty()
+    }
+
+    /// Validate that the value is empty or only whitespace
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message. If not provided, uses default message.
+    pub fn blank(self, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.empty_when(str_is_empty::<T>).built_in("blank", Vec::new(), message.map(|m| m.into()),
+            |value| value.as_ref().trim().is_empty(),
+            Some(str_value::<T>))
+            .even_if_empty()
     }
 
     /// Validate that the value is not null/empty (for Option types)
@@ -51,14 +655,10 @@ impl<T> RuleBuilder<T> {
     where
         T: OptionLike,
     {
-        let msg = message.map(|m| m.into()).unwrap_or_else(|| "must not be null".to_string());
-        self.rule(move |value| {
-            if value.is_none() {
-                Some(msg.clone())
-            } else {
-                None
-            }
-        })
+        self.empty_when(option_is_none::<T>).built_in("not_null", Vec::new(), message.map(|m| m.into()),
+            |value| !value.is_none(),
+            None)
+            .even_if_empty()
     }
 
     /// Validate minimum length
@@ -70,15 +670,9 @@ impl<T> RuleBuilder<T> {
     where
         T: AsRef<str>,
     {
-        let msg = message.map(|m| m.into());
-        self.rule(move |value| {
-            let len = value.as_ref().len();
-            if len < min {
-                Some(msg.clone().unwrap_or_else(|| format!("must be at least {} characters long", min)))
-            } else {
-                None
-            }
-        })
+        self.empty_when(str_is_empty::<T>).native("min_length", vec![("min", min.to_string())], message.map(|m| m.into()),
+            RuleKind::Length { text: T::as_ref, min, max: usize::MAX },
+            Some(str_value::<T>))
     }
 
     /// Validate maximum length
@@ -90,15 +684,9 @@ impl<T> RuleBuilder<T> {
     where
         T: AsRef<str>,
     {
-        let msg = message.map(|m| m.into());
-        self.rule(move |value| {
-            let len = value.as_ref().len();
-            if len > max {
-                Some(msg.clone().unwrap_or_else(|| format!("must be at most {} characters long", max)))
-            } else {
-                None
-            }
-        })
+        self.empty_when(str_is_empty::<T>).native("max_length", vec![("max", max.to_string())], message.map(|m| m.into()),
+            RuleKind::Length { text: T::as_ref, min: 0, max },
+            Some(str_value::<T>))
     }
 
     /// Validate length range
@@ -115,6 +703,49 @@ impl<T> RuleBuilder<T> {
         self.min_length(min, min_message).max_length(max, max_message)
     }
 
+    /// Validate that a string or collection has exactly `length` bytes or elements,
+    /// e.g. fixed-width codes
+    ///
+    /// The actual length is reported in the error state as `actual_length`.
+    ///
+    /// # Arguments
+    /// * `length` - Required length
+    /// * `message` - Optional custom error message. `{length}` and `{actual_length}` are replaced.
+    pub fn exact_length(self, length: usize, message: Option<impl Into<String>>) -> Self
+    where
+        T: HasLength,
+    {
+        self.empty_when(length_is_empty::<T>).built_in_detailed("exact_length", vec![("length", length.to_string())], message.map(|m| m.into()),
+            move |value, _| {
+                let actual = value.length();
+                (actual != length).then(|| vec![("actual_length", actual.to_string())])
+            },
+            None)
+    }
+
+    /// Validate that a string or collection has one of the listed lengths, e.g. card numbers
+    /// of 15, 16 or 19 digits
+    ///
+    /// The default message lists the allowed lengths, and the actual length is reported in
+    /// the error state as `actual_length`.
+    ///
+    /// # Arguments
+    /// * `lengths` - The allowed lengths
+    /// * `message` - Optional custom error message. `{lengths}` and `{actual_length}` are replaced.
+    pub fn length_one_of(self, lengths: &[usize], message: Option<impl Into<String>>) -> Self
+    where
+        T: HasLength,
+    {
+        let lengths = lengths.to_vec();
+        let listed = lengths.iter().map(|l| l.to_string()).collect::<Vec<_>>().join(", ");
+        self.empty_when(length_is_empty::<T>).built_in_detailed("length_one_of", vec![("lengths", listed)], message.map(|m| m.into()),
+            move |value, _| {
+                let actual = value.length();
+                (!lengths.contains(&actual)).then(|| vec![("actual_length", actual.to_string())])
+            },
+            None)
+    }
+
     /// Validate email format
     /// 
     /// # Arguments
@@ -123,18 +754,34 @@ impl<T> RuleBuilder<T> {
     where
         T: AsRef<str>,
     {
-        let msg = message.map(|m| m.into()).unwrap_or_else(|| "must be a valid email address".to_string());
-        self.rule(move |value| {
-            let email_regex = regex::Regex::new(
-                r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$"
-            )
-            .unwrap();
-            if !email_regex.is_match(value.as_ref()) {
-                Some(msg.clone())
-            } else {
-                None
-            }
-        })
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).native("email", Vec::new(), message.map(|m| m.into()),
+            RuleKind::Email { text: T::as_ref },
+            Some(str_value::<T>))
+    }
+
+    /// Validate that value matches a regular expression
+    ///
+    /// The regex is compiled by the caller, once; pass an `Arc<Regex>` to share it between rules.
+    /// Use anchors (`^...$`) to match the whole value.
+    ///
+    /// # Arguments
+    /// * `regex` - The pattern, as a `Regex` or `Arc<Regex>`
+    /// * `message` - Optional custom error message. If not provided, uses default message with the pattern.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// static SKU: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Z]{3}-\d{4}$").unwrap());
+    ///
+    /// RuleBuilder::for_property("sku").matches(SKU.clone(), None::<String>)
+    /// ```
+    pub fn matches(self, regex: impl Into<Arc<Regex>>, message: Option<impl Into<String>>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let regex = regex.into();
+        self.empty_when(str_is_empty::<T>).sized_by(str_size::<T>).native("matches", vec![("pattern", regex.as_str().to_string())], message.map(|m| m.into()),
+            RuleKind::Pattern { text: T::as_ref, regex },
+            Some(str_value::<T>))
     }
 
     /// Validate that value is greater than a minimum
@@ -147,14 +794,9 @@ impl<T> RuleBuilder<T> {
         T: Numeric,
     {
         let min_val = min.into();
-        let msg = message.map(|m| m.into());
-        self.rule(move |value| {
-            if value.to_f64() <= min_val {
-                Some(msg.clone().unwrap_or_else(|| format!("must be greater than {}", min_val)))
-            } else {
-                None
-            }
-        })
+        self.native("greater_than", vec![("min", min_val.to_string())], message.map(|m| m.into()),
+            RuleKind::Range { number: T::to_f64, min: Bound::Excluded(min_val), max: Bound::Unbounded },
+            Some(numeric_value::<T>))
     }
 
     /// Validate that value is greater than or equal to a minimum
@@ -167,14 +809,9 @@ impl<T> RuleBuilder<T> {
         T: Numeric,
     {
         let min_val = min.into();
-        let msg = message.map(|m| m.into());
-        self.rule(move |value| {
-            if value.to_f64() < min_val {
-                Some(msg.clone().unwrap_or_else(|| format!("must be greater than or equal to {}", min_val)))
-            } else {
-                None
-            }
-        })
+        self.native("greater_than_or_equal", vec![("min", min_val.to_string())], message.map(|m| m.into()),
+            RuleKind::Range { number: T::to_f64, min: Bound::Included(min_val), max: Bound::Unbounded },
+            Some(numeric_value::<T>))
     }
 
     /// Validate that value is less than a maximum
@@ -187,14 +824,9 @@ impl<T> RuleBuilder<T> {
         T: Numeric,
     {
         let max_val = max.into();
-        let msg = message.map(|m| m.into());
-        self.rule(move |value| {
-            if value.to_f64() >= max_val {
-                Some(msg.clone().unwrap_or_else(|| format!("must be less than {}", max_val)))
-            } else {
-                None
-            }
-        })
+        self.native("less_than", vec![("max", max_val.to_string())], message.map(|m| m.into()),
+            RuleKind::Range { number: T::to_f64, min: Bound::Unbounded, max: Bound::Excluded(max_val) },
+            Some(numeric_value::<T>))
     }
 
     /// Validate that value is less than or equal to a maximum
@@ -207,14 +839,9 @@ impl<T> RuleBuilder<T> {
         T: Numeric,
     {
         let max_val = max.into();
-        let msg = message.map(|m| m.into());
-        self.rule(move |value| {
-            if value.to_f64() > max_val {
-                Some(msg.clone().unwrap_or_else(|| format!("must be less than or equal to {}", max_val)))
-            } else {
-                None
-            }
-        })
+        self.native("less_than_or_equal", vec![("max", max_val.to_string())], message.map(|m| m.into()),
+            RuleKind::Range { number: T::to_f64, min: Bound::Unbounded, max: Bound::Included(max_val) },
+            Some(numeric_value::<T>))
     }
 
     /// Validate that value is within a range (inclusive)
@@ -229,38 +856,250 @@ impl<T> RuleBuilder<T> {
     {
         let min_val = min.into();
         let max_val = max.into();
-        let msg = message.map(|m| m.into());
-        self.rule(move |value| {
-            let val = value.to_f64();
-            if val < min_val || val > max_val {
-                Some(msg.clone().unwrap_or_else(|| format!("must be between {} and {}", min_val, max_val)))
-            } else {
-                None
-            }
-        })
+        self.native("inclusive_between", vec![("min", min_val.to_string()), ("max", max_val.to_string())], message.map(|m| m.into()),
+            RuleKind::Range { number: T::to_f64, min: Bound::Included(min_val), max: Bound::Included(max_val) },
+            Some(numeric_value::<T>))
+    }
+
+    /// Validate that value is within `epsilon` of an expected value
+    ///
+    /// Prefer this over exact equality for floating-point values, which rarely survive
+    /// arithmetic unchanged (`0.1 + 0.2 != 0.3`).
+    ///
+    /// # Arguments
+    /// * `expected` - Expected value
+    /// * `epsilon` - Largest accepted absolute difference
+    /// * `message` - Optional custom error message. If not provided, uses default message with the expected value and epsilon.
+    pub fn approx_equal(self, expected: impl Into<f64> + Copy + 'static, epsilon: impl Into<f64> + Copy + 'static, message: Option<impl Into<String> + Clone + 'static>) -> Self
+    where
+        T: Numeric,
+    {
+        let expected_val = expected.into();
+        let epsilon_val = epsilon.into();
+        self.built_in("approx_equal", vec![("expected", expected_val.to_string()), ("epsilon", epsilon_val.to_string())], message.map(|m| m.into()),
+            move |value| (value.to_f64() - expected_val).abs() <= epsilon_val,
+            Some(numeric_value::<T>))
+    }
+
+    /// Validate that value converts losslessly to a narrower numeric type `U`
+    ///
+    /// Use this for values that will be downcast before storage, e.g. `fits_in::<i32>()` for
+    /// an `i64` stored in a 32-bit column.
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message. `{type}` is replaced with the target type name.
+    pub fn fits_in<U>(self, message: Option<impl Into<String>>) -> Self
+    where
+        T: Copy + Display,
+        U: TryFrom<T>,
+    {
+        self.built_in("fits_in", vec![("type", std::any::type_name::<U>().to_string())], message.map(|m| m.into()),
+            |value| U::try_from(*value).is_ok(),
+            Some(display_value::<T>))
+    }
+
+    /// Validate that value is non-negative and fits in a `usize`, e.g. before using it as a length or index
+    ///
+    /// # Arguments
+    /// * `message` - Optional custom error message
+    pub fn non_negative_fits_usize(self, message: Option<impl Into<String>>) -> Self
+    where
+        T: Copy + Display,
+        usize: TryFrom<T>,
+    {
+        self.fits_in::<usize>(message)
+    }
+
+    /// Validate that a numeric code is one of a known set
+    ///
+    /// For legacy APIs where enums arrive as magic integers. The default message lists the
+    /// allowed codes, e.g. "must be one of 1, 2, 5, 9".
+    ///
+    /// # Arguments
+    /// * `codes` - The allowed codes
+    /// * `message` - Optional custom error message. `{codes}` is replaced with the allowed codes.
+    pub fn known_code(self, codes: &[T], message: Option<impl Into<String>>) -> Self
+    where
+        T: Copy + PartialEq + Display + Send + Sync + 'static,
+    {
+        let codes = codes.to_vec();
+        let listed = codes.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(", ");
+        self.built_in("known_code", vec![("codes", listed)], message.map(|m| m.into()),
+            move |value| codes.contains(value),
+            Some(display_value::<T>))
+    }
+
+    /// Validate that an integer bitset only has bits within `mask` set
+    ///
+    /// Use this for client-provided flag masks, so unknown bits are rejected rather than stored.
+    /// The unknown bits are reported as `{unknown}` in the message and in the error state.
+    ///
+    /// # Arguments
+    /// * `mask` - All known flags combined
+    /// * `message` - Optional custom error message. `{mask}` and `{unknown}` are replaced, in hex.
+    pub fn only_flags(self, mask: T, message: Option<impl Into<String>>) -> Self
+    where
+        T: Flags,
+    {
+        self.built_in_detailed("only_flags", vec![("mask", format!("{:#x}", mask))], message.map(|m| m.into()),
+            move |value, _| {
+                let unknown = *value & !mask;
+                (unknown != T::default()).then(|| vec![("unknown", format!("{:#x}", unknown))])
+            },
+            Some(hex_value::<T>))
+    }
+
+    /// Validate that an integer bitset has every bit of `flag` set
+    ///
+    /// # Arguments
+    /// * `flag` - Required flag (or flags)
+    /// * `message` - Optional custom error message. `{flag}` is replaced, in hex.
+    pub fn has_flag(self, flag: T, message: Option<impl Into<String>>) -> Self
+    where
+        T: Flags,
+    {
+        self.built_in("has_flag", vec![("flag", format!("{:#x}", flag))], message.map(|m| m.into()),
+            move |value| *value & flag == flag,
+            Some(hex_value::<T>))
+    }
+
+    /// Validate that value is at most a limit taken from the validation context
+    ///
+    /// Use this for limits that depend on the caller, such as their subscription plan, and
+    /// pass the context with `Validator::validate_with_context`.
+    ///
+    /// # Arguments
+    /// * `limit` - Function reading the maximum (inclusive) from the context
+    /// * `message` - Optional custom error message. `{max}` is replaced with the limit.
+    ///
+    /// A validation without a context of type `C` fails with a `CONTEXT_MISSING` error,
+    /// since skipping the rule would lift the limit.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// RuleBuilder::for_property("items").at_most_ctx(|ctx: &Ctx| ctx.plan.max_items, None::<String>)
+    /// ```
+    pub fn at_most_ctx<C, N>(self, limit: impl Fn(&C) -> N + Send + Sync + 'static, message: Option<impl Into<String>>) -> Self
+    where
+        T: Numeric,
+        C: 'static,
+        N: Numeric,
+    {
+        self.built_in_detailed("at_most", Vec::new(), message.map(|m| m.into()),
+            move |value, messages| {
+                let max = limit(context::<C>(messages)).to_f64();
+                (value.to_f64() > max).then(|| vec![("max", max.to_string())])
+            },
+            Some(numeric_value::<T>))
+            .requires_context::<C>()
+    }
+
+    /// Validate that value is at least a limit taken from the validation context
+    ///
+    /// # Arguments
+    /// * `limit` - Function reading the minimum (inclusive) from the context
+    /// * `message` - Optional custom error message. `{min}` is replaced with the limit.
+    ///
+    /// A validation without a context of type `C` fails with a `CONTEXT_MISSING` error.
+    pub fn at_least_ctx<C, N>(self, limit: impl Fn(&C) -> N + Send + Sync + 'static, message: Option<impl Into<String>>) -> Self
+    where
+        T: Numeric,
+        C: 'static,
+        N: Numeric,
+    {
+        self.built_in_detailed("at_least", Vec::new(), message.map(|m| m.into()),
+            move |value, messages| {
+                let min = limit(context::<C>(messages)).to_f64();
+                (value.to_f64() < min).then(|| vec![("min", min.to_string())])
+            },
+            Some(numeric_value::<T>))
+            .requires_context::<C>()
     }
 
     /// Validate with a custom predicate
-    pub fn must(self, predicate: impl Fn(&T) -> bool + 'static, message: impl Into<String> + Clone + 'static) -> Self {
-        let msg = message.into();
-        self.rule(move |value| {
-            if !predicate(value) {
-                Some(msg.clone())
-            } else {
-                None
-            }
-        })
+    pub fn must(self, predicate: impl Fn(&T) -> bool + Send + Sync + 'static, message: impl Into<String> + Clone + Send + Sync + 'static) -> Self {
+        let mut builder = self.built_in("must", Vec::new(), Some(message.into()), predicate, None);
+        if let Some(entry) = builder.rules.last_mut() {
+            entry.code = None;
+        }
+        builder
     }
 
     /// Build the rule and return a function that can be used in a validator
     pub fn build(self) -> impl Fn(&T) -> Vec<ValidationError> {
-        let property_name = self.property_name.clone();
+        let rule_fn = self.build_with_messages();
+        move |value: &T| rule_fn(value, &MessageContext::default())
+    }
+
+    /// Build the rule into a function that resolves default messages from a message context
+    pub(crate) fn build_with_messages(self) -> impl Fn(&T, &MessageContext) -> Vec<ValidationError> {
+        let property_name: Arc<str> = self.property_name.into();
         let rules = self.rules;
-        move |value: &T| {
+        let provider = self.message_provider;
+        let skip_when = self.skip_when;
+        let enforced_after = self.enforced_after;
+        let is_empty = self.is_empty;
+        let size = self.size;
+        let sensitive = self.sensitive;
+        move |value: &T, messages: &MessageContext| {
             let mut errors = Vec::new();
-            for rule in &rules {
-                if let Some(message) = rule(value) {
-                    errors.push(ValidationError::new(property_name.clone(), message));
+            if skip_when.iter().any(|condition| condition(ValidationContext::current(messages))) {
+                messages.skip(&property_name, rules.iter().flat_map(RuleEntry::kinds), SkipReason::Condition);
+                return errors;
+            }
+            if enforced_after.is_some_and(|start| messages.now() < start) {
+                messages.skip(&property_name, rules.iter().flat_map(RuleEntry::kinds), SkipReason::NotYetEnforced);
+                return errors;
+            }
+            if let Some(exceeded) = size.and_then(|size| messages.limits.exceeded(size(value))) {
+                errors.push(limits::resource_limit_error(property_name.clone(), exceeded, messages, provider.as_deref()));
+                messages.skip(&property_name, rules.iter().flat_map(RuleEntry::kinds), SkipReason::ResourceLimit);
+                return errors;
+            }
+            let skip_empty = messages.empty_values == EmptyValues::Skip && is_empty.is_some_and(|is_empty| is_empty(value));
+            for entry in &rules {
+                if skip_empty && !entry.even_if_empty {
+                    messages.skip(&property_name, entry.kinds(), SkipReason::EmptyValue);
+                    continue;
+                }
+                if let Some((_, context)) = entry.context.filter(|(present, _)| !present(messages)) {
+                    errors.push(context_missing_error(property_name.clone(), context, messages, provider.as_deref()));
+                    continue;
+                }
+                let (message, details) = match &entry.check {
+                    Check::Nested { rules, .. } => {
+                        errors.extend(rules(value, messages).into_iter().map(|error| ValidationError {
+                            property: property_name.clone(),
+                            attempted_value: error.attempted_value.filter(|_| !sensitive),
+                            ..error
+                        }));
+                        continue;
+                    }
+                    Check::Custom(_) | Check::BuiltIn { .. } if messages.isolate_panics => {
+                        match panic::catch_unwind(AssertUnwindSafe(|| evaluate(entry, value, messages, provider.as_deref()))) {
+                            Ok(outcome) => outcome,
+                            Err(_) => {
+                                let message = messages.message(provider.as_deref(), "rule_panicked", &[]);
+                                errors.push(ValidationError::new(property_name.clone(), message).with_code("RULE_PANICKED"));
+                                continue;
+                            }
+                        }
+                    }
+                    _ => evaluate(entry, value, messages, provider.as_deref()),
+                };
+                if let Some(message) = message {
+                    let mut error = ValidationError::new(property_name.clone(), message).with_severity(entry.severity);
+                    if let Some(code) = &entry.code {
+                        error = error.with_code(code.clone());
+                    }
+                    if let Some(format_value) = entry.attempted_value.as_ref().filter(|_| !sensitive) {
+                        error = error.with_attempted_value(format_value(value));
+                    }
+                    error.state.extend(details);
+                    if let Some(state) = &entry.state {
+                        error.state.extend(state(value));
+                    }
+                    errors.push(error);
                 }
             }
             errors
@@ -268,3 +1107,111 @@ impl<T> RuleBuilder<T> {
     }
 }
 
+/// The message and details of a rule's failure, `None` when it passes
+#[inline]
+fn evaluate<T>(entry: &RuleEntry<T>, value: &T, messages: &MessageContext, provider: Option<&dyn MessageProvider>) -> (Option<String>, Vec<(String, String)>) {
+    match &entry.check {
+        Check::Custom(rule) => (rule(value), Vec::new()),
+        Check::BuiltIn { check, message } => match check(value, messages) {
+            None => (None, Vec::new()),
+            Some(details) => built_in_failure(entry, message.as_deref(), details, messages, provider),
+        },
+        Check::Native { rule, message } if !rule.passes(value) => built_in_failure(entry, message.as_deref(), Vec::new(), messages, provider),
+        Check::Native { .. } | Check::Nested { .. } => (None, Vec::new()),
+    }
+}
+
+/// The message and details of a failed built-in rule, with the custom message rendered or
+/// the default one looked up by rule kind
+#[cold]
+fn built_in_failure<T>(entry: &RuleEntry<T>, message: Option<&str>, details: FailureDetails, messages: &MessageContext, provider: Option<&dyn MessageProvider>) -> (Option<String>, Vec<(String, String)>) {
+    let details: Vec<(String, String)> = details.into_iter().map(|(k, v)| (k.to_string(), v)).collect();
+    let message = match message {
+        Some(message) => render(message, &details),
+        None => {
+            let params = [entry.params.as_slice(), details.as_slice()].concat();
+            messages.message(provider, &entry.kind, &params)
+        }
+    };
+    (Some(message), details)
+}
+
+/// Whether the value is a syntactically valid email address
+pub(crate) fn is_email(value: &str) -> bool {
+    static EMAIL: OnceLock<Regex> = OnceLock::new();
+    EMAIL
+        .get_or_init(|| Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap())
+        .is_match(value)
+}
+
+/// Whether a string is empty or whitespace, like `not_empty` sees it
+pub(crate) fn str_is_empty<T: AsRef<str>>(value: &T) -> bool {
+    value.as_ref().trim().is_empty()
+}
+
+/// Measure a string in bytes
+pub(crate) fn str_size<T: AsRef<str>>(value: &T) -> InputSize {
+    InputSize::Bytes(value.as_ref().len())
+}
+
+/// Measure a collection by counting its elements
+pub(crate) fn element_count<T, E>(value: &T) -> InputSize
+where
+    for<'a> &'a T: IntoIterator<Item = &'a E>,
+{
+    InputSize::Elements(value.into_iter().count())
+}
+
+/// Whether a string or collection has no elements
+fn length_is_empty<T: HasLength>(value: &T) -> bool {
+    value.length() == 0
+}
+
+/// Whether an option is `None`
+fn option_is_none<T: OptionLike>(value: &T) -> bool {
+    value.is_none()
+}
+
+/// Report a string value as-is
+pub(crate) fn str_value<T: AsRef<str>>(value: &T) -> String {
+    value.as_ref().to_string()
+}
+
+/// Report a bitset in hex
+fn hex_value<T: LowerHex>(value: &T) -> String {
+    format!("{:#x}", value)
+}
+
+/// Report a value as displayed
+fn display_value<T: Display>(value: &T) -> String {
+    value.to_string()
+}
+
+/// Report a numeric value in its `f64` form
+fn numeric_value<T: Numeric>(value: &T) -> String {
+    value.to_f64().to_string()
+}
+
+/// The validation context a context-aware rule reads its limit from
+///
+/// Rules reading a context declare it with `requires_context`, so they only run when it
+/// was passed.
+pub(crate) fn context<'a, C: 'static>(messages: &'a MessageContext) -> &'a C {
+    messages.data::<C>().expect("rules reading a context only run when it was passed")
+}
+
+/// Whether the validation was passed a context of type `C`
+fn has_context<C: 'static>(messages: &MessageContext) -> bool {
+    messages.data::<C>().is_some()
+}
+
+/// The error of a rule reading a context of the named type when none was passed
+///
+/// Reported instead of skipping the rule, which would lift its limit.
+#[cold]
+fn context_missing_error(property: Arc<str>, context: &str, messages: &MessageContext, provider: Option<&dyn MessageProvider>) -> ValidationError {
+    let message = messages.message(provider, "context_missing", &[]);
+    let mut error = ValidationError::new(property, message).with_code("CONTEXT_MISSING");
+    error.state.insert("context".to_string(), context.to_string());
+    error
+}
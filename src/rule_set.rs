@@ -0,0 +1,65 @@
+//! Rules defined once for a kind of value and bound to properties of several validators
+
+use std::sync::Arc;
+
+use crate::builder::ValidatorBuilder;
+use crate::rule::RuleBuilder;
+
+/// A property's rules without a property name or accessor, so shared field semantics such as
+/// "an email address" live in one place
+///
+/// Bind the set to a property with `ValidatorBuilder::bind`, or start a property's chain
+/// from it with [`for_property`](Self::for_property). Clones share the definition.
+///
+/// # Example
+/// ```rust,ignore
+/// fn email_rules() -> RuleSet<String> {
+///     RuleSet::new(|rules| rules.not_empty(None::<String>).max_length(254, None::<String>).email(None::<String>))
+/// }
+///
+/// let validator = ValidatorBuilder::<Employee>::new()
+///     .bind("workEmail", |e| &e.work_email, &email_rules())
+///     .bind("privateEmail", |e| &e.private_email, &email_rules())
+///     .build();
+/// ```
+pub struct RuleSet<V> {
+    define: Arc<dyn Fn(RuleBuilder<V>) -> RuleBuilder<V> + Send + Sync>,
+}
+
+impl<V: 'static> RuleSet<V> {
+    /// Create a rule set from a function adding its rules to a property's builder
+    pub fn new(define: impl Fn(RuleBuilder<V>) -> RuleBuilder<V> + Send + Sync + 'static) -> Self {
+        Self { define: Arc::new(define) }
+    }
+
+    /// The set's rules for `property_name`, to be extended with rules of that property only
+    pub fn for_property(&self, property_name: impl Into<String>) -> RuleBuilder<V> {
+        (self.define)(RuleBuilder::for_property(property_name))
+    }
+}
+
+impl<V> Clone for RuleSet<V> {
+    fn clone(&self) -> Self {
+        Self { define: Arc::clone(&self.define) }
+    }
+}
+
+impl<T> ValidatorBuilder<T> {
+    /// Add the rules of a [`RuleSet`] for the property `accessor` reads, reported as `property_name`
+    pub fn bind<F, V>(self, property_name: impl Into<String>, accessor: F, rules: &RuleSet<V>) -> Self
+    where
+        F: Fn(&T) -> &V + Send + Sync + 'static,
+        V: 'static,
+    {
+        let property_name = property_name.into();
+        let rules = rules.for_property(property_name.clone());
+        self.rule_for(property_name, accessor, rules)
+    }
+}
+
+impl<V> RuleBuilder<V> {
+    /// Add the rules of a [`RuleSet`] to this property
+    pub fn include(self, rules: &RuleSet<V>) -> Self {
+        (rules.define)(self)
+    }
+}
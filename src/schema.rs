@@ -0,0 +1,191 @@
+//! Schema export of rule descriptors: JSON Schema and Zod (and OpenAPI, in `openapi`)
+
+use crate::format::json_string;
+use crate::rule::RuleDescriptor;
+
+/// Constraints collected for one property, or for the elements of a collection property
+#[derive(Default)]
+pub(crate) struct Constraints {
+    /// `string`, `number` or `array`
+    pub(crate) ty: Option<&'static str>,
+    /// `email`
+    pub(crate) format: Option<&'static str>,
+    /// Regular expression from `matches`
+    pub(crate) pattern: Option<String>,
+    /// Numeric keywords in declaration order, e.g. `("minLength", "2")`
+    pub(crate) bounds: Vec<(&'static str, String)>,
+    pub(crate) items: Option<Box<Constraints>>,
+}
+
+impl Constraints {
+    /// Set a bound, keeping an earlier bound when it is stricter
+    fn bound(&mut self, keyword: &'static str, value: Option<String>, lower: bool) {
+        let Some(value) = value else { return };
+        match self.bounds.iter_mut().find(|(k, _)| *k == keyword) {
+            Some((_, existing)) => {
+                let stricter = match (existing.parse::<f64>(), value.parse::<f64>()) {
+                    (Ok(existing), Ok(new)) => if lower { new > existing } else { new < existing },
+                    _ => true,
+                };
+                if stricter {
+                    *existing = value;
+                }
+            }
+            None => self.bounds.push((keyword, value)),
+        }
+    }
+
+    /// Apply a rule's schema equivalent; returns whether the property becomes required
+    fn apply(&mut self, rule: &RuleDescriptor) -> bool {
+        let param = |name: &str| rule.params.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone());
+        match rule.kind.as_str() {
+            "not_empty" | "not_blank" => {
+                self.ty = Some("string");
+                self.bound("minLength", Some("1".to_string()), true);
+                return true;
+            }
+            "not_null" => return true,
+            "min_length" => {
+                self.ty = Some("string");
+                self.bound("minLength", param("min"), true);
+            }
+            "max_length" => {
+                self.ty = Some("string");
+                self.bound("maxLength", param("max"), false);
+            }
+            "email" => {
+                self.ty = Some("string");
+                self.format = Some("email");
+            }
+            "matches" => {
+                self.ty = Some("string");
+                self.pattern = param("pattern");
+            }
+            "greater_than" => {
+                self.ty = Some("number");
+                self.bound("exclusiveMinimum", param("min"), true);
+            }
+            "greater_than_or_equal" => {
+                self.ty = Some("number");
+                self.bound("minimum", param("min"), true);
+            }
+            "less_than" => {
+                self.ty = Some("number");
+                self.bound("exclusiveMaximum", param("max"), false);
+            }
+            "less_than_or_equal" => {
+                self.ty = Some("number");
+                self.bound("maximum", param("max"), false);
+            }
+            "inclusive_between" => {
+                self.ty = Some("number");
+                self.bound("minimum", param("min"), true);
+                self.bound("maximum", param("max"), false);
+            }
+            _ => {}
+        }
+        false
+    }
+
+    fn json_schema(&self) -> String {
+        let mut fields = Vec::new();
+        if let Some(ty) = self.ty {
+            fields.push(format!("\"type\":{}", json_string(ty)));
+        }
+        if let Some(format) = self.format {
+            fields.push(format!("\"format\":{}", json_string(format)));
+        }
+        if let Some(pattern) = &self.pattern {
+            fields.push(format!("\"pattern\":{}", json_string(pattern)));
+        }
+        for (keyword, value) in &self.bounds {
+            fields.push(format!("{}:{}", json_string(keyword), value));
+        }
+        if let Some(items) = &self.items {
+            fields.push(format!("\"items\":{}", items.json_schema()));
+        }
+        format!("{{{}}}", fields.join(","))
+    }
+
+    fn zod(&self) -> String {
+        let mut schema = match (self.ty, &self.items) {
+            (Some("array"), Some(items)) => format!("z.array({})", items.zod()),
+            (Some("string"), _) => "z.string()".to_string(),
+            (Some("number"), _) => "z.number()".to_string(),
+            _ => "z.unknown()".to_string(),
+        };
+        if let Some(format) = self.format {
+            schema.push_str(&format!(".{}()", format));
+        }
+        if let Some(pattern) = &self.pattern {
+            schema.push_str(&format!(".regex(new RegExp({}))", json_string(pattern)));
+        }
+        for (keyword, value) in &self.bounds {
+            let method = match *keyword {
+                "minLength" | "minimum" => "min",
+                "maxLength" | "maximum" => "max",
+                "exclusiveMinimum" => "gt",
+                "exclusiveMaximum" => "lt",
+                _ => continue,
+            };
+            schema.push_str(&format!(".{}({})", method, value));
+        }
+        schema
+    }
+}
+
+/// Constraints per property in declaration order, and the required properties
+pub(crate) fn analyze(rules: &[RuleDescriptor]) -> (Vec<(&str, Constraints)>, Vec<&str>) {
+    let mut properties: Vec<(&str, Constraints)> = Vec::new();
+    let mut required: Vec<&str> = Vec::new();
+    for rule in rules {
+        let (name, each) = match rule.property.strip_suffix("[]") {
+            Some(name) => (name, true),
+            None => (rule.property.as_str(), false),
+        };
+        let index = match properties.iter().position(|(p, _)| *p == name) {
+            Some(index) => index,
+            None => {
+                properties.push((name, Constraints::default()));
+                properties.len() - 1
+            }
+        };
+        let constraints = &mut properties[index].1;
+        if each {
+            constraints.ty = Some("array");
+            constraints.items.get_or_insert_with(Default::default).apply(rule);
+        } else if constraints.apply(rule) && !required.contains(&name) {
+            required.push(name);
+        }
+    }
+    (properties, required)
+}
+
+/// Render rule descriptors as a JSON Schema (draft 2020-12) object schema
+pub(crate) fn json_schema(rules: &[RuleDescriptor]) -> String {
+    let (properties, required) = analyze(rules);
+    let properties: Vec<String> = properties
+        .iter()
+        .map(|(name, constraints)| format!("{}:{}", json_string(name), constraints.json_schema()))
+        .collect();
+    let required: Vec<String> = required.iter().map(|name| json_string(name)).collect();
+    format!(
+        "{{\"$schema\":\"https://json-schema.org/draft/2020-12/schema\",\"type\":\"object\",\"properties\":{{{}}},\"required\":[{}]}}",
+        properties.join(","),
+        required.join(","),
+    )
+}
+
+/// Render rule descriptors as a TypeScript module exporting a Zod schema and its inferred type
+pub(crate) fn zod_schema(rules: &[RuleDescriptor], name: &str) -> String {
+    let (properties, required) = analyze(rules);
+    let mut module = String::from("import { z } from \"zod\";\n\n");
+    module.push_str(&format!("export const {}Schema = z.object({{\n", name));
+    for (property, constraints) in &properties {
+        let optional = if required.contains(property) { "" } else { ".optional()" };
+        module.push_str(&format!("  {}: {}{},\n", json_string(property), constraints.zod(), optional));
+    }
+    module.push_str("});\n\n");
+    module.push_str(&format!("export type {} = z.infer<typeof {}Schema>;\n", name, name));
+    module
+}
@@ -0,0 +1,44 @@
+//! Validating the items of an async stream, such as records consumed from Kafka or read
+//! from S3, with a bound on how many validations run at once
+//!
+//! [`validate_stream`] runs `Validator::validate_async` for up to `concurrency` items at a
+//! time and yields each item with its result in the order the items arrived. It only pulls
+//! the next item from the source when a slot is free and the consumer has taken the
+//! results ahead of it, so a slow consumer or slow async rules slow down reading instead of
+//! buffering the whole source.
+//!
+//! # Example
+//! ```rust,ignore
+//! let mut results = validate_stream(records, &record_validator, 16);
+//! while let Some((record, result)) = results.next().await {
+//!     if result.is_valid() {
+//!         sink.send(record).await?;
+//!     } else {
+//!         dead_letters.send((record, result)).await?;
+//!     }
+//! }
+//! ```
+
+use futures_util::stream::{Stream, StreamExt};
+
+use crate::error::ValidationResult;
+use crate::traits::Validator;
+
+/// Validate the items of `stream` with at most `concurrency` validations in flight,
+/// yielding each item with its result in the order of the stream
+///
+/// A `concurrency` of 0 is treated as 1. Validations are driven by polling the returned
+/// stream, so they run on the consumer's task without spawning.
+pub fn validate_stream<'a, S, V>(stream: S, validator: &'a V, concurrency: usize) -> impl Stream<Item = (S::Item, ValidationResult)> + 'a
+where
+    S: Stream + 'a,
+    S::Item: 'a,
+    V: Validator<S::Item> + ?Sized,
+{
+    stream
+        .map(move |item| async move {
+            let result = validator.validate_async(&item).await;
+            (item, result)
+        })
+        .buffered(concurrency.max(1))
+}
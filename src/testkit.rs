@@ -0,0 +1,351 @@
+//! Fluent helpers for asserting on validator behaviour in tests
+//!
+//! # Example
+//! ```rust,ignore
+//! use fluentval::testkit::{check, mutations};
+//!
+//! check(&validator)
+//!     .given(User { name: "Jo".into(), email: "invalid".into(), age: 30 })
+//!     .expect_error("email", "EMAIL")
+//!     .expect_no_error("name");
+//!
+//! // Negative testing: every mutation of a valid instance must be rejected
+//! mutations(&validator, valid_user)
+//!     .boundaries("name", |u| &mut u.name)
+//!     .boundaries("age", |u| &mut u.age)
+//!     .empty_string("name", |u| &mut u.name)
+//!     .extremes("age", |u| &mut u.age)
+//!     .drop_option("nickname", |u| &mut u.nickname)
+//!     .assert_all_rejected();
+//! ```
+
+use std::borrow::Borrow;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::error::ValidationResult;
+use crate::rule::RuleDescriptor;
+use crate::traits::Validator;
+
+/// Start a test scenario against a validator
+pub fn check<T, V>(validator: &V) -> Check<'_, T, V>
+where
+    V: Validator<T> + ?Sized,
+{
+    Check {
+        validator,
+        _marker: PhantomData,
+    }
+}
+
+/// A validator under test, waiting for an instance to validate
+pub struct Check<'a, T, V: ?Sized> {
+    validator: &'a V,
+    _marker: PhantomData<fn(&T)>,
+}
+
+impl<'a, T, V> Check<'a, T, V>
+where
+    V: Validator<T> + ?Sized,
+{
+    /// Validate the given instance and return its outcome for assertions
+    pub fn given(&self, instance: impl Borrow<T>) -> Outcome {
+        Outcome {
+            result: self.validator.validate(instance.borrow()),
+        }
+    }
+}
+
+/// The result of validating one instance, with chainable assertions
+///
+/// Every assertion panics with the full validation result when it does not hold.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    result: ValidationResult,
+}
+
+impl Outcome {
+    /// Assert that validation passed
+    #[track_caller]
+    pub fn expect_valid(self) -> Self {
+        if !self.result.is_valid() {
+            self.fail("expected validation to pass");
+        }
+        self
+    }
+
+    /// Assert that validation failed
+    #[track_caller]
+    pub fn expect_invalid(self) -> Self {
+        if self.result.is_valid() {
+            self.fail("expected validation to fail");
+        }
+        self
+    }
+
+    /// Assert that the property has an error with the given code
+    #[track_caller]
+    pub fn expect_error(self, property: &str, code: &str) -> Self {
+        let found = self
+            .result
+            .errors()
+            .iter()
+            .any(|e| &*e.property == property && e.code.as_deref() == Some(code));
+        if !found {
+            self.fail(&format!("expected an error on `{}` with code `{}`", property, code));
+        }
+        self
+    }
+
+    /// Assert that the property has at least one error, whatever its code
+    #[track_caller]
+    pub fn expect_error_for(self, property: &str) -> Self {
+        if !self.result.errors().iter().any(|e| &*e.property == property) {
+            self.fail(&format!("expected an error on `{}`", property));
+        }
+        self
+    }
+
+    /// Assert that the property has an error with exactly this message
+    #[track_caller]
+    pub fn expect_message(self, property: &str, message: &str) -> Self {
+        let found = self
+            .result
+            .errors()
+            .iter()
+            .any(|e| &*e.property == property && e.message == message);
+        if !found {
+            self.fail(&format!("expected an error on `{}` with message \"{}\"", property, message));
+        }
+        self
+    }
+
+    /// Assert that the property has no errors
+    #[track_caller]
+    pub fn expect_no_error(self, property: &str) -> Self {
+        if self.result.errors().iter().any(|e| &*e.property == property) {
+            self.fail(&format!("expected no error on `{}`", property));
+        }
+        self
+    }
+
+    /// Assert the total number of errors
+    #[track_caller]
+    pub fn expect_error_count(self, count: usize) -> Self {
+        if self.result.errors().len() != count {
+            self.fail(&format!("expected {} error(s)", count));
+        }
+        self
+    }
+
+    /// The underlying validation result, for assertions the DSL doesn't cover
+    pub fn result(&self) -> &ValidationResult {
+        &self.result
+    }
+
+    #[track_caller]
+    fn fail(&self, expectation: &str) -> ! {
+        let mut report = format!("{}, but validation produced ", expectation);
+        if self.result.is_valid() {
+            report.push_str("no errors");
+        } else {
+            report.push_str(&format!("{} error(s):", self.result.errors().len()));
+            for error in self.result.errors() {
+                report.push_str(&format!("\n  - {}", error));
+                if let Some(code) = &error.code {
+                    report.push_str(&format!(" [{}]", code));
+                }
+            }
+        }
+        panic!("{}", report);
+    }
+}
+
+/// Numeric types with extreme values used to probe range rules
+pub trait Extremes: Sized {
+    /// The smallest and largest representable values
+    fn extremes() -> [Self; 2];
+}
+
+macro_rules! impl_extremes {
+    ($($t:ty),*) => {
+        $(impl Extremes for $t { fn extremes() -> [Self; 2] { [<$t>::MIN, <$t>::MAX] } })*
+    };
+}
+
+impl_extremes!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+/// Field types with values just outside what a described rule accepts
+pub trait Boundary: Sized {
+    /// Values `rule` rejects next to its limits, or none for kinds without known limits
+    fn outside(rule: &RuleDescriptor) -> Vec<Self>;
+}
+
+/// The value of a rule parameter
+fn param<'r>(rule: &'r RuleDescriptor, name: &str) -> Option<&'r str> {
+    rule.params.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+}
+
+impl Boundary for String {
+    fn outside(rule: &RuleDescriptor) -> Vec<Self> {
+        let length = |name: &str| param(rule, name).and_then(|value| value.parse::<usize>().ok());
+        let shorter = || length("min").and_then(|min| min.checked_sub(1)).map(|n| "a".repeat(n));
+        let longer = || length("max").map(|max| "a".repeat(max + 1));
+        match rule.kind.as_str() {
+            "not_empty" | "not_blank" => vec![String::new()],
+            "min_length" => shorter().into_iter().collect(),
+            "max_length" => longer().into_iter().collect(),
+            "exact_length" => match length("length") {
+                Some(length) => length.checked_sub(1).into_iter().chain([length + 1]).map(|n| "a".repeat(n)).collect(),
+                None => Vec::new(),
+            },
+            "email" => vec!["ada@".to_string()],
+            _ => Vec::new(),
+        }
+    }
+}
+
+macro_rules! impl_boundary {
+    ($($t:ty),*) => {
+        $(impl Boundary for $t {
+            fn outside(rule: &RuleDescriptor) -> Vec<Self> {
+                let bound = |name: &str| param(rule, name).and_then(|value| value.parse::<f64>().ok());
+                // Whole numbers on the rejected side, so fractional bounds apply to integer fields
+                let below = |min: f64| min.ceil() - 1.0;
+                let above = |max: f64| max.floor() + 1.0;
+                let values = match rule.kind.as_str() {
+                    "greater_than" => vec![bound("min").map(f64::floor)],
+                    "greater_than_or_equal" => vec![bound("min").map(below)],
+                    "less_than" => vec![bound("max").map(f64::ceil)],
+                    "less_than_or_equal" => vec![bound("max").map(above)],
+                    "inclusive_between" => vec![bound("min").map(below), bound("max").map(above)],
+                    _ => Vec::new(),
+                };
+                // Values the type can't hold, such as -1 for an unsigned field, are left out
+                values.into_iter().flatten().filter_map(|n| n.to_string().parse().ok()).collect()
+            }
+        })*
+    };
+}
+
+impl_boundary!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+type Mutator<T> = Box<dyn Fn(&mut T)>;
+
+struct Mutation<T> {
+    label: String,
+    property: String,
+    apply: Mutator<T>,
+}
+
+/// Start a mutation test from an instance the validator accepts
+pub fn mutations<T, V>(validator: &V, valid: T) -> Mutations<'_, T, V>
+where
+    T: Clone,
+    V: Validator<T> + ?Sized,
+{
+    Mutations {
+        validator,
+        valid,
+        mutations: Vec::new(),
+    }
+}
+
+/// A set of mutations of a valid instance, each expected to fail on a given property
+///
+/// [`boundaries`](Self::boundaries) derives its mutations from the validator's rule
+/// descriptors. The other helpers are unconditional expectations: they add the mutations they
+/// name regardless of the rules, so a rule that was never configured fails the test too.
+pub struct Mutations<'a, T, V: ?Sized> {
+    validator: &'a V,
+    valid: T,
+    mutations: Vec<Mutation<T>>,
+}
+
+impl<'a, T, V> Mutations<'a, T, V>
+where
+    T: Clone,
+    V: Validator<T> + ?Sized,
+{
+    /// Add a custom mutation expected to produce an error on `property`
+    pub fn mutation(mut self, label: impl Into<String>, property: impl Into<String>, apply: impl Fn(&mut T) + 'static) -> Self {
+        self.mutations.push(Mutation {
+            label: label.into(),
+            property: property.into(),
+            apply: Box::new(apply),
+        });
+        self
+    }
+
+    /// Expect the property to be rejected just outside the limits of its rules
+    ///
+    /// The values are derived from the validator's [`rules`](Validator::rules) for
+    /// `property`: an empty string for `not_empty`, lengths one past `min_length` and
+    /// `max_length`, the nearest whole numbers past inclusive bounds and on or past exclusive
+    /// ones, and so on.
+    pub fn boundaries<F>(mut self, property: &str, field: impl Fn(&mut T) -> &mut F + Clone + 'static) -> Self
+    where
+        F: Boundary + Clone + Debug + 'static,
+    {
+        let values: Vec<F> = self.validator.rules().iter().filter(|rule| rule.property == property).flat_map(F::outside).collect();
+        for value in values {
+            let field = field.clone();
+            self = self.mutation(format!("`{}` = {:?}", property, value), property, move |instance| *field(instance) = value.clone());
+        }
+        self
+    }
+
+    /// Expect the property to be rejected when the string is emptied, whatever rules it has
+    pub fn empty_string(self, property: &str, field: impl Fn(&mut T) -> &mut String + 'static) -> Self {
+        self.mutation(format!("empty `{}`", property), property, move |instance| field(instance).clear())
+    }
+
+    /// Expect the property to be rejected at both the minimum and maximum of its type,
+    /// whatever rules it has
+    pub fn extremes<N>(self, property: &str, field: impl Fn(&mut T) -> &mut N + Clone + 'static) -> Self
+    where
+        N: Extremes + std::fmt::Display + 'static,
+    {
+        let [min, max] = N::extremes();
+        let min_field = field.clone();
+        self.mutation(format!("`{}` = {}", property, min), property, move |instance| {
+            let [min, _] = N::extremes();
+            *min_field(instance) = min;
+        })
+        .mutation(format!("`{}` = {}", property, max), property, move |instance| {
+            let [_, max] = N::extremes();
+            *field(instance) = max;
+        })
+    }
+
+    /// Expect the property to be rejected when the option is set to `None`, whatever rules it
+    /// has
+    pub fn drop_option<O: 'static>(self, property: &str, field: impl Fn(&mut T) -> &mut Option<O> + 'static) -> Self {
+        self.mutation(format!("drop `{}`", property), property, move |instance| *field(instance) = None)
+    }
+
+    /// Assert the original instance is valid and every mutation is rejected on its property
+    ///
+    /// Panics with a list of every mutation the validator failed to catch.
+    #[track_caller]
+    pub fn assert_all_rejected(self) {
+        let baseline = self.validator.validate(&self.valid);
+        if !baseline.is_valid() {
+            Outcome { result: baseline }.fail("expected the unmutated instance to be valid");
+        }
+
+        let mut escaped = Vec::new();
+        for mutation in &self.mutations {
+            let mut instance = self.valid.clone();
+            (mutation.apply)(&mut instance);
+            let result = self.validator.validate(&instance);
+            if !result.errors().iter().any(|e| *e.property == *mutation.property) {
+                escaped.push(format!("  - {} was not rejected on `{}`", mutation.label, mutation.property));
+            }
+        }
+
+        if !escaped.is_empty() {
+            panic!("{} of {} mutation(s) escaped validation:\n{}", escaped.len(), self.mutations.len(), escaped.join("\n"));
+        }
+    }
+}
@@ -1,10 +1,362 @@
+use std::any::{Any, TypeId};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::LowerHex;
+use std::ops::{BitAnd, Not};
+use std::sync::{OnceLock, RwLock};
+use std::time::UNIX_EPOCH;
+
+#[cfg(feature = "async")]
+use crate::async_rule::BoxFuture;
 use crate::error::ValidationResult;
+use crate::format::is_within;
+use crate::messages;
+use crate::rule::RuleDescriptor;
+use crate::schema;
 
 /// Trait for defining validators
-pub trait Validator<T> {
+///
+/// Validators are `Send + Sync`, so one built at startup can be shared across threads,
+/// e.g. in a `static` `LazyLock` or an `Arc` in web framework state.
+pub trait Validator<T>: Send + Sync {
     fn validate(&self, instance: &T) -> ValidationResult;
+
+    /// Validate with default messages in the given locale (e.g. `"de-DE"`)
+    ///
+    /// Validators without localized messages ignore the locale.
+    fn validate_with_locale(&self, instance: &T, locale: &str) -> ValidationResult {
+        let _ = locale;
+        self.validate(instance)
+    }
+
+    /// Validate with a caller-supplied context, such as the caller's subscription plan
+    ///
+    /// Context-aware rules like `RuleBuilder::at_most_ctx` read their limits from it.
+    /// Validators without such rules ignore the context.
+    fn validate_with_context(&self, instance: &T, context: &dyn Any) -> ValidationResult {
+        let _ = context;
+        self.validate(instance)
+    }
+
+    /// Validate with a caller-supplied context and default messages in the given locale,
+    /// e.g. in a web handler that negotiated the request's language
+    fn validate_with_locale_and_context(&self, instance: &T, locale: &str, context: &dyn Any) -> ValidationResult {
+        let _ = locale;
+        self.validate_with_context(instance, context)
+    }
+
+    /// Validate a single property, e.g. one form field per keystroke
+    ///
+    /// Returns only the errors for `property` and its nested and indexed properties
+    /// (`address` includes `address.city`). Validators built with `ValidatorBuilder` only run
+    /// the rules that can report for the property; other validators run in full by default.
+    fn validate_property(&self, instance: &T, property: &str) -> ValidationResult {
+        let mut result = self.validate(instance);
+        result.retain_errors(|error| is_within(&error.property, property));
+        result
+    }
+
+    /// Validate a single property with a caller-supplied context, as in
+    /// [`validate_property`](Self::validate_property)
+    fn validate_property_with_context(&self, instance: &T, property: &str, context: &dyn Any) -> ValidationResult {
+        let mut result = self.validate_with_context(instance, context);
+        result.retain_errors(|error| is_within(&error.property, property));
+        result
+    }
+
+    /// Validate, running async rules (such as DNS lookups) after the synchronous ones
+    ///
+    /// Default messages use the global locale. Validators without async rules resolve to
+    /// the same result as `validate`.
+    #[cfg(feature = "async")]
+    fn validate_async<'a>(&'a self, instance: &'a T) -> BoxFuture<'a, ValidationResult> {
+        Box::pin(std::future::ready(self.validate(instance)))
+    }
+
+    /// Validate with a caller-supplied context, running async rules after the synchronous ones
+    #[cfg(feature = "async")]
+    fn validate_async_with_context<'a>(&'a self, instance: &'a T, context: &'a dyn Any) -> BoxFuture<'a, ValidationResult> {
+        Box::pin(std::future::ready(self.validate_with_context(instance, context)))
+    }
+
+    /// Pair a value with this validator, so it can be passed where `T: Validate` is expected
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// fn store(payload: impl Validate) -> Result<(), ValidationResult> { ... }
+    ///
+    /// store(validator.bind(&user))?;
+    /// ```
+    fn bind<'a>(&'a self, instance: &'a T) -> WithValidator<'a, T, Self>
+    where
+        Self: Sized,
+    {
+        WithValidator::new(instance, self)
+    }
+
+    /// The locales this validator's default messages can be served in, for negotiating a
+    /// request's `Accept-Language`
+    ///
+    /// Defaults to the bundled catalogs and `DEFAULT_LOCALE`; validators built with
+    /// `ValidatorBuilder` add the locales of their message provider.
+    fn locales(&self) -> Vec<String> {
+        messages::available_locales(None)
+    }
+
+    /// Describe the rules this validator runs, in evaluation order
+    ///
+    /// Hand-written validators return no descriptors unless they override this.
+    fn rules(&self) -> Vec<RuleDescriptor> {
+        Vec::new()
+    }
+
+    /// Stable fingerprint of the configured rule set
+    ///
+    /// The fingerprint is a 64-bit FNV-1a hash of the rule descriptors, rendered as
+    /// 16 hex digits. It covers everything a descriptor records, including custom messages,
+    /// codes, severities and when rules are skipped, and is stable across builds and
+    /// platforms, so it can be stored in a golden file and compared in a test to detect
+    /// unintended validation changes.
+    fn fingerprint(&self) -> String {
+        fingerprint(&self.rules(), &[])
+    }
+
+    /// Render the rule set as a JSON Schema (draft 2020-12) document
+    ///
+    /// Lengths, numeric ranges, the `email` format and required properties (`not_empty`,
+    /// `not_null`) are mapped to their JSON Schema keywords, and `rule_for_each` rules to
+    /// `items`. Rules without a JSON Schema equivalent, such as custom predicates, are omitted,
+    /// so the schema is a published subset of what `validate` enforces.
+    fn to_json_schema(&self) -> String {
+        schema::json_schema(&self.rules())
+    }
+
+    /// Render the rule set as a TypeScript module exporting a Zod schema named `{name}Schema`
+    /// and its inferred type `{name}`
+    ///
+    /// Constraints map as in [`to_json_schema`](Self::to_json_schema): properties without
+    /// `not_empty` or `not_null` are `.optional()`, and rules without a Zod equivalent are omitted.
+    fn to_zod(&self, name: &str) -> String {
+        schema::zod_schema(&self.rules(), name)
+    }
+
+    /// Render the rule set as a Markdown validation specification
+    ///
+    /// Rules are grouped per property in declaration order, one table row per rule with
+    /// its parameters and any documentation attached with `RuleBuilder::describe`.
+    fn to_markdown(&self) -> String {
+        let rules = self.rules();
+        let mut markdown = String::from("# Validation specification\n");
+        for property in properties(&rules) {
+            markdown.push_str(&format!("\n## `{}`\n\n", property));
+            markdown.push_str("| Rule | Parameters | Description |\n");
+            markdown.push_str("|------|------------|-------------|\n");
+            for rule in rules.iter().filter(|r| r.property == property) {
+                let params: Vec<String> = rule.params.iter().map(|(k, v)| format!("{} = {}", k, v)).collect();
+                markdown.push_str(&format!(
+                    "| `{}` | {} | {} |\n",
+                    rule.kind,
+                    params.join(", "),
+                    rule.description.as_deref().unwrap_or("").replace('|', "\\|"),
+                ));
+            }
+        }
+        markdown
+    }
+
+    /// Render the rule set as a human-readable requirements list, one line per property
+    ///
+    /// Each line lists what the property's rules require, e.g.
+    /// `password: must not be empty, must be at least 8 characters long`, so a UI can show
+    /// the constraints before submission. Rules use their custom message when configured
+    /// and otherwise the English default; custom rules without a message are left out.
+    fn describe(&self) -> String {
+        let rules = self.rules();
+        properties(&rules)
+            .into_iter()
+            .filter_map(|property| {
+                let requirements: Vec<String> = rules
+                    .iter()
+                    .filter(|r| r.property == property)
+                    .filter_map(messages::requirement)
+                    .collect();
+                (!requirements.is_empty()).then(|| format!("{}: {}", property, requirements.join(", ")))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The properties that rules apply to, in declaration order
+fn properties(rules: &[RuleDescriptor]) -> Vec<&str> {
+    let mut properties: Vec<&str> = Vec::new();
+    for rule in rules {
+        if !properties.contains(&rule.property.as_str()) {
+            properties.push(&rule.property);
+        }
+    }
+    properties
+}
+
+/// Types that validate themselves, used by the web framework extractors
+///
+/// Generic code can bound on `T: Validate` instead of passing validators around. Implement
+/// it by delegating to the type's validator:
+///
+/// ```rust,ignore
+/// impl Validate for CreateUser {
+///     fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+///         create_user_validator().validate_with_locale(self, locale)
+///     }
+/// }
+/// ```
+///
+/// or pair a value with a validator using [`Validator::bind`].
+pub trait Validate {
+    /// Validate with default messages in the given locale (e.g. `"de-DE"`)
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult;
+
+    /// Validate with a caller-supplied context and default messages in the given locale
+    ///
+    /// The web framework extractors pass the request's `ValidationContext` through this.
+    /// Types whose validator reads no context can keep the default, which ignores it.
+    fn validate_with_locale_and_context(&self, locale: &str, context: &dyn Any) -> ValidationResult {
+        let _ = context;
+        self.validate_with_locale(locale)
+    }
+
+    /// Validate with default messages in the global locale
+    fn validate(&self) -> ValidationResult {
+        messages::with_locale(|locale| self.validate_with_locale(locale))
+    }
+
+    /// The locales the web framework extractors negotiate from `Accept-Language`
+    ///
+    /// Defaults to the bundled catalogs and `DEFAULT_LOCALE`. Types whose validator has its
+    /// own message provider return the validator's [`Validator::locales`].
+    fn locales(&self) -> Vec<String> {
+        messages::available_locales(None)
+    }
 }
 
+impl<T: Validate + ?Sized> Validate for &T {
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+        (**self).validate_with_locale(locale)
+    }
+
+    fn validate_with_locale_and_context(&self, locale: &str, context: &dyn Any) -> ValidationResult {
+        (**self).validate_with_locale_and_context(locale, context)
+    }
+
+    fn locales(&self) -> Vec<String> {
+        (**self).locales()
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+        (**self).validate_with_locale(locale)
+    }
+
+    fn validate_with_locale_and_context(&self, locale: &str, context: &dyn Any) -> ValidationResult {
+        (**self).validate_with_locale_and_context(locale, context)
+    }
+
+    fn locales(&self) -> Vec<String> {
+        (**self).locales()
+    }
+}
+
+/// Types with a canonical validator, built once and cached for the life of the process
+///
+/// # Example
+/// ```rust,ignore
+/// impl HasValidator for User {
+///     fn validator() -> impl Validator<Self> + 'static {
+///         ValidatorBuilder::<User>::new()
+///             .rule_for("email", |u| &u.email, RuleBuilder::for_property("email").email(None::<String>))
+///             .build()
+///     }
+/// }
+///
+/// let result = user.validate_default();
+/// ```
+pub trait HasValidator: Sized + 'static {
+    /// Build the type's validator; called once, by the first [`default_validator`](Self::default_validator)
+    fn validator() -> impl Validator<Self> + 'static;
+
+    /// The cached validator
+    fn default_validator() -> &'static dyn Validator<Self> {
+        cached_validator::<Self>()
+    }
+
+    /// Validate with the cached validator, with default messages in the global locale
+    fn validate_default(&self) -> ValidationResult {
+        Self::default_validator().validate(self)
+    }
+
+    /// Validate with the cached validator and a caller-supplied context
+    fn validate_default_with_context(&self, context: &dyn Any) -> ValidationResult {
+        Self::default_validator().validate_with_context(self, context)
+    }
+}
+
+type ValidatorCache = RwLock<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>;
+
+/// The validator of `T`, building and caching it on first use
+fn cached_validator<T: HasValidator>() -> &'static dyn Validator<T> {
+    static CACHE: OnceLock<ValidatorCache> = OnceLock::new();
+    let cache = CACHE.get_or_init(Default::default);
+    let lookup = |entry: &'static (dyn Any + Send + Sync)| entry.downcast_ref::<Box<dyn Validator<T>>>().map(|validator| validator.as_ref());
+    let cached = cache.read().unwrap_or_else(|e| e.into_inner()).get(&TypeId::of::<T>()).copied();
+    if let Some(validator) = cached.and_then(lookup) {
+        return validator;
+    }
+    // Built outside the lock, since the validator may look up the validators of nested types
+    let validator: Box<dyn Validator<T>> = Box::new(T::validator());
+    let mut cache = cache.write().unwrap_or_else(|e| e.into_inner());
+    let entry = *cache.entry(TypeId::of::<T>()).or_insert_with(|| Box::leak(Box::new(validator)));
+    lookup(entry).expect("validator cache entries match their type")
+}
+
+/// A value paired with a validator, validating itself through [`Validate`]
+///
+/// Created with [`Validator::bind`].
+pub struct WithValidator<'a, T, V: ?Sized> {
+    value: &'a T,
+    validator: &'a V,
+}
+
+impl<'a, T, V: Validator<T> + ?Sized> WithValidator<'a, T, V> {
+    /// Pair a value with a validator
+    pub fn new(value: &'a T, validator: &'a V) -> Self {
+        Self { value, validator }
+    }
+
+    /// The validated value
+    pub fn value(&self) -> &'a T {
+        self.value
+    }
+}
+
+impl<T, V: Validator<T> + ?Sized> Validate for WithValidator<'_, T, V> {
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+        self.validator.validate_with_locale(self.value, locale)
+    }
+
+    fn validate_with_locale_and_context(&self, locale: &str, context: &dyn Any) -> ValidationResult {
+        self.validator.validate_with_locale_and_context(self.value, locale, context)
+    }
+
+    fn locales(&self) -> Vec<String> {
+        self.validator.locales()
+    }
+}
+
+/// Integer types usable as flag bitsets
+pub trait Flags: Copy + Default + PartialEq + BitAnd<Output = Self> + Not<Output = Self> + LowerHex + Send + Sync + 'static {}
+
+impl<T> Flags for T where T: Copy + Default + PartialEq + BitAnd<Output = T> + Not<Output = T> + LowerHex + Send + Sync + 'static {}
+
 /// Trait for types that can be treated as numeric values
 pub trait Numeric {
     fn to_f64(&self) -> f64;
@@ -32,3 +384,66 @@ impl<T> OptionLike for Option<T> {
     }
 }
 
+
+/// Trait for strings and collections with a length (bytes for strings, elements otherwise)
+pub trait HasLength {
+    fn length(&self) -> usize;
+}
+
+impl<T: HasLength + ?Sized> HasLength for &T { fn length(&self) -> usize { (**self).length() } }
+impl HasLength for str { fn length(&self) -> usize { self.len() } }
+impl HasLength for String { fn length(&self) -> usize { self.len() } }
+impl<E> HasLength for [E] { fn length(&self) -> usize { self.len() } }
+impl<E, const N: usize> HasLength for [E; N] { fn length(&self) -> usize { N } }
+impl<E> HasLength for Vec<E> { fn length(&self) -> usize { self.len() } }
+impl<E> HasLength for VecDeque<E> { fn length(&self) -> usize { self.len() } }
+impl<E, S> HasLength for HashSet<E, S> { fn length(&self) -> usize { self.len() } }
+impl<E> HasLength for BTreeSet<E> { fn length(&self) -> usize { self.len() } }
+impl<K, V, S> HasLength for HashMap<K, V, S> { fn length(&self) -> usize { self.len() } }
+impl<K, V> HasLength for BTreeMap<K, V> { fn length(&self) -> usize { self.len() } }
+
+/// FNV-1a fingerprint of `descriptors` and the validator-wide `options` that change what
+/// they check
+///
+/// Every value is written with its length in front, so no message or parameter can pass for
+/// the fields around it and make two rule sets hash alike.
+pub(crate) fn fingerprint(descriptors: &[RuleDescriptor], options: &[(&str, String)]) -> String {
+    fn field(canonical: &mut String, value: &str) {
+        canonical.push_str(&format!("{}:{}", value.len(), value));
+    }
+    fn optional(canonical: &mut String, value: Option<&str>) {
+        match value {
+            Some(value) => field(canonical, value),
+            None => canonical.push('-'),
+        }
+    }
+
+    let mut canonical = String::new();
+    field(&mut canonical, &options.len().to_string());
+    for (name, value) in options {
+        field(&mut canonical, name);
+        field(&mut canonical, value);
+    }
+    for descriptor in descriptors {
+        field(&mut canonical, &descriptor.property);
+        field(&mut canonical, &descriptor.kind);
+        field(&mut canonical, &descriptor.params.len().to_string());
+        for (name, value) in &descriptor.params {
+            field(&mut canonical, name);
+            field(&mut canonical, value);
+        }
+        optional(&mut canonical, descriptor.message.as_deref());
+        optional(&mut canonical, descriptor.code.as_deref());
+        field(&mut canonical, &format!("{:?}", descriptor.severity));
+        field(&mut canonical, &descriptor.even_if_empty.to_string());
+        field(&mut canonical, &descriptor.conditional.to_string());
+        let enforced_after = descriptor.enforced_after.map(|start| start.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis().to_string());
+        optional(&mut canonical, enforced_after.as_deref());
+    }
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in canonical.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
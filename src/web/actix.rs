@@ -0,0 +1,113 @@
+//! Actix-web extractor that validates the request payload
+//!
+//! `Validated<E>` wraps any extractor that dereferences to the payload, such as
+//! `Json<T>`, `Query<T>` or `Form<T>`, and runs `T`'s [`Validate`] implementation after
+//! extraction. Messages use the request's preferred `Accept-Language`, and context-aware
+//! rules read the `Arc<ValidationContext>` request extension. Invalid payloads
+//! are rejected with `422 Unprocessable Entity` and a `ValidationProblemDetails` body,
+//! unless a [`ValidationConfig`] registered as app data builds a different response.
+//!
+//! # Example
+//! ```rust,ignore
+//! use actix_web::web::Json;
+//! use fluentval::web::actix::{Validated, ValidationConfig};
+//!
+//! async fn create_user(user: Validated<Json<CreateUser>>) -> HttpResponse {
+//!     let user = user.into_inner().into_inner();
+//!     HttpResponse::Created().finish()
+//! }
+//!
+//! App::new()
+//!     .app_data(ValidationConfig::default()
+//!         .error_handler(|result, _req| HttpResponse::BadRequest().body(result.to_json(ErrorFormat::Rails))))
+//!     .route("/users", web::post().to(create_user))
+//! ```
+
+use std::future::Future;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use actix_web::dev::Payload;
+use actix_web::error::InternalError;
+use actix_web::http::header::ACCEPT_LANGUAGE;
+use actix_web::http::StatusCode;
+use actix_web::{Error, FromRequest, HttpMessage, HttpRequest, HttpResponse};
+
+use super::{rejection_body, validate_for_request, PROBLEM_JSON};
+use crate::context::ValidationContext;
+use crate::error::ValidationResult;
+use crate::traits::Validate;
+
+type ErrorHandler = Arc<dyn Fn(&ValidationResult, &HttpRequest) -> HttpResponse + Send + Sync>;
+
+/// Extractor wrapper that validates the payload of the inner extractor
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Validated<E>(pub E);
+
+impl<E> Validated<E> {
+    /// Unwrap into the inner extractor
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E> Deref for Validated<E> {
+    type Target = E;
+
+    fn deref(&self) -> &E {
+        &self.0
+    }
+}
+
+/// Response for payloads that fail validation, registered with `App::app_data`
+#[derive(Clone, Default)]
+pub struct ValidationConfig {
+    error_handler: Option<ErrorHandler>,
+}
+
+impl ValidationConfig {
+    /// Build the response for a failed validation
+    pub fn error_handler(mut self, handler: impl Fn(&ValidationResult, &HttpRequest) -> HttpResponse + Send + Sync + 'static) -> Self {
+        self.error_handler = Some(Arc::new(handler));
+        self
+    }
+
+    fn response(&self, result: &ValidationResult, req: &HttpRequest) -> HttpResponse {
+        match &self.error_handler {
+            Some(handler) => handler(result, req),
+            None => HttpResponse::build(StatusCode::UNPROCESSABLE_ENTITY)
+                .content_type(PROBLEM_JSON)
+                .body(rejection_body(result)),
+        }
+    }
+}
+
+impl<E> FromRequest for Validated<E>
+where
+    E: FromRequest + Deref + 'static,
+    E::Target: Validate,
+    E::Error: Into<Error>,
+{
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        let extract = E::from_request(&req, payload);
+        Box::pin(async move {
+            let inner = extract.await.map_err(Into::into)?;
+            let accept_language = req.headers().get(ACCEPT_LANGUAGE).and_then(|header| header.to_str().ok());
+            let context = req.extensions().get::<Arc<ValidationContext>>().cloned();
+            let result = validate_for_request(&*inner, accept_language, context.as_deref());
+            if result.is_valid() {
+                return Ok(Validated(inner));
+            }
+            let response = match req.app_data::<ValidationConfig>() {
+                Some(config) => config.response(&result, &req),
+                None => ValidationConfig::default().response(&result, &req),
+            };
+            Err(InternalError::from_response("validation failed", response).into())
+        })
+    }
+}
@@ -0,0 +1,93 @@
+//! async-graphql errors with field-level validation details
+//!
+//! Failed validations become a GraphQL error whose `extensions.validation` map groups the
+//! messages by property, mirroring the Rails-style `{"field": ["message"]}` body:
+//!
+//! ```json
+//! {"message": "One or more validation errors occurred.", "extensions": {"validation": {"email": ["must be a valid email address"]}}}
+//! ```
+//!
+//! Input objects are validated automatically with the [`FluentValidator`] argument validator,
+//! or explicitly in a resolver with [`validate_input`]. Inputs with context-aware rules are
+//! validated with [`validate_input_with_context`], which reads the `Arc<ValidationContext>`
+//! the schema or request was given as data.
+//!
+//! # Example
+//! ```rust,ignore
+//! use fluentval::web::async_graphql::FluentValidator;
+//!
+//! #[Object]
+//! impl Mutation {
+//!     async fn create_user(&self, #[graphql(validator(custom = "FluentValidator"))] input: CreateUserInput) -> User {
+//!         // `input` has passed validation
+//!     }
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use async_graphql::indexmap::IndexMap;
+use async_graphql::{Context, CustomValidator, Error, ErrorExtensions, InputType, InputValueError, Name, Value};
+
+use super::validate_for_request;
+use crate::context::ValidationContext;
+use crate::error::ValidationResult;
+use crate::traits::Validate;
+
+const MESSAGE: &str = "One or more validation errors occurred.";
+
+/// The `extensions.validation` value: messages grouped by property, in first-error order
+pub fn validation_extension(result: &ValidationResult) -> Value {
+    let mut grouped: IndexMap<Name, Value> = IndexMap::new();
+    for error in result.errors() {
+        let messages = grouped.entry(Name::new(&error.property)).or_insert_with(|| Value::List(Vec::new()));
+        if let Value::List(messages) = messages {
+            messages.push(Value::from(error.message.as_str()));
+        }
+    }
+    Value::Object(grouped)
+}
+
+/// A GraphQL error carrying the validation messages in `extensions.validation`
+pub fn graphql_error(result: &ValidationResult) -> Error {
+    Error::new(MESSAGE).extend_with(|_, extensions| extensions.set("validation", validation_extension(result)))
+}
+
+/// Validate an input in the global locale, for use at the start of a resolver
+pub fn validate_input<T: Validate>(input: T) -> async_graphql::Result<T> {
+    let result = validate_for_request(&input, None, None);
+    if result.is_valid() {
+        Ok(input)
+    } else {
+        Err(graphql_error(&result))
+    }
+}
+
+/// Validate an input in the global locale with the `Arc<ValidationContext>` data of the
+/// query, for inputs with context-aware rules
+pub fn validate_input_with_context<T: Validate>(ctx: &Context<'_>, input: T) -> async_graphql::Result<T> {
+    let context = ctx.data_opt::<Arc<ValidationContext>>();
+    let result = validate_for_request(&input, None, context.map(Arc::as_ref));
+    if result.is_valid() {
+        Ok(input)
+    } else {
+        Err(graphql_error(&result))
+    }
+}
+
+/// Argument validator that runs the input type's [`Validate`] implementation
+///
+/// Use it as `#[graphql(validator(custom = "FluentValidator"))]` on a resolver argument.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FluentValidator;
+
+impl<T: InputType + Validate> CustomValidator<T> for FluentValidator {
+    fn check(&self, value: &T) -> Result<(), InputValueError<T>> {
+        let result = validate_for_request(value, None, None);
+        if result.is_valid() {
+            Ok(())
+        } else {
+            Err(InputValueError::custom(MESSAGE).with_extension("validation", validation_extension(&result)))
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Axum extractors that validate the request payload
+//!
+//! `ValidatedJson<T>`, `ValidatedQuery<T>` and `ValidatedForm<T>` deserialize like their
+//! axum counterparts, then run `T`'s [`Validate`] implementation. Invalid payloads are
+//! rejected with `422 Unprocessable Entity` and a `ValidationProblemDetails` body; messages
+//! use the request's preferred `Accept-Language`, and context-aware rules read the
+//! `Arc<ValidationContext>` request extension, e.g. added with `Extension` or a middleware.
+//!
+//! # Example
+//! ```rust,ignore
+//! use fluentval::web::axum::ValidatedJson;
+//!
+//! async fn create_user(ValidatedJson(user): ValidatedJson<CreateUser>) -> StatusCode {
+//!     // `user` has passed validation
+//!     StatusCode::CREATED
+//! }
+//! ```
+
+use ::axum::extract::{Form, FromRequest, FromRequestParts, Query, Request};
+use ::axum::http::header::{ACCEPT_LANGUAGE, CONTENT_TYPE};
+use ::axum::http::request::Parts;
+use ::axum::http::{Extensions, HeaderMap, StatusCode};
+use ::axum::response::{IntoResponse, Response};
+use ::axum::Json;
+use serde::de::DeserializeOwned;
+
+use std::sync::Arc;
+
+use super::{rejection_body, validate_for_request, PROBLEM_JSON};
+use crate::context::ValidationContext;
+use crate::error::ValidationResult;
+use crate::traits::Validate;
+
+/// Rejection of the validating extractors
+#[derive(Debug)]
+pub enum ValidationRejection<R> {
+    /// The payload could not be extracted; responds like the wrapped extractor
+    Extract(R),
+    /// The payload was extracted but failed validation; responds with 422
+    Invalid(Box<ValidationResult>),
+}
+
+impl<R: IntoResponse> IntoResponse for ValidationRejection<R> {
+    fn into_response(self) -> Response {
+        match self {
+            ValidationRejection::Extract(rejection) => rejection.into_response(),
+            ValidationRejection::Invalid(result) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, [(CONTENT_TYPE, PROBLEM_JSON)], rejection_body(&result)).into_response()
+            }
+        }
+    }
+}
+
+/// Validate the payload in the request's preferred locale, with its validation context
+fn check<T: Validate, R>(value: T, headers: &HeaderMap, extensions: &Extensions) -> Result<T, ValidationRejection<R>> {
+    let accept_language = headers.get(ACCEPT_LANGUAGE).and_then(|header| header.to_str().ok());
+    let context = extensions.get::<Arc<ValidationContext>>();
+    let result = validate_for_request(&value, accept_language, context.map(Arc::as_ref));
+    if result.is_valid() {
+        Ok(value)
+    } else {
+        Err(ValidationRejection::Invalid(Box::new(result)))
+    }
+}
+
+/// JSON body extractor that validates the deserialized value
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = ValidationRejection<<Json<T> as FromRequest<S>>::Rejection>;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let (headers, extensions) = (req.headers().clone(), req.extensions().clone());
+        let Json(value) = Json::<T>::from_request(req, state).await.map_err(ValidationRejection::Extract)?;
+        check(value, &headers, &extensions).map(ValidatedJson)
+    }
+}
+
+/// Query string extractor that validates the deserialized value
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ValidatedQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = ValidationRejection<<Query<T> as FromRequestParts<S>>::Rejection>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(value) = Query::<T>::from_request_parts(parts, state).await.map_err(ValidationRejection::Extract)?;
+        check(value, &parts.headers, &parts.extensions).map(ValidatedQuery)
+    }
+}
+
+/// URL-encoded form extractor (body, or query string for `GET`) that validates the deserialized value
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedForm<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedForm<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = ValidationRejection<<Form<T> as FromRequest<S>>::Rejection>;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let (headers, extensions) = (req.headers().clone(), req.extensions().clone());
+        let Form(value) = Form::<T>::from_request(req, state).await.map_err(ValidationRejection::Extract)?;
+        check(value, &headers, &extensions).map(ValidatedForm)
+    }
+}
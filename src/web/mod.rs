@@ -0,0 +1,61 @@
+//! Web framework integrations
+//!
+//! Each framework lives behind its own feature. The HTTP integrations provide extractors
+//! that deserialize a payload, validate it with its [`Validate`](crate::Validate)
+//! implementation and reject invalid input with `422 Unprocessable Entity` and a
+//! `ValidationProblemDetails` body; the gRPC integration maps failures to `INVALID_ARGUMENT`,
+//! and the GraphQL integration to errors with an `extensions.validation` map.
+//!
+//! Context-aware rules such as `RuleBuilder::at_most_ctx` read the request's
+//! `ValidationContext`, which a middleware that knows the caller (e.g. their subscription
+//! plan) stores as an `Arc<ValidationContext>` in the request extensions. Without one,
+//! those rules fail with `CONTEXT_MISSING`.
+
+#[cfg(feature = "actix")]
+pub mod actix;
+#[cfg(feature = "async-graphql")]
+pub mod async_graphql;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "rocket")]
+pub mod rocket;
+#[cfg(feature = "tonic")]
+pub mod tonic;
+
+use crate::context::ValidationContext;
+use crate::error::ValidationResult;
+#[cfg(any(feature = "actix", feature = "axum", feature = "rocket"))]
+use crate::format::ProblemDetails;
+use crate::messages;
+use crate::traits::Validate;
+
+/// Status code used for payloads that fail validation
+#[cfg(any(feature = "actix", feature = "axum", feature = "rocket"))]
+pub(crate) const UNPROCESSABLE_ENTITY: u16 = 422;
+
+/// Content type of the rejection body
+#[cfg(any(feature = "actix", feature = "axum", feature = "rocket"))]
+pub(crate) const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Validate a payload in the most preferred locale of an `Accept-Language` header that
+/// its messages are available in, falling back to the global locale, with the request's
+/// validation context if it has one
+pub(crate) fn validate_for_request<T: Validate + ?Sized>(value: &T, accept_language: Option<&str>, context: Option<&ValidationContext>) -> ValidationResult {
+    let locale = accept_language
+        .and_then(|header| {
+            let locales = value.locales();
+            let supported: Vec<&str> = locales.iter().map(String::as_str).collect();
+            messages::negotiate_locale(header, &supported)
+        })
+        .unwrap_or_else(messages::locale);
+    match context {
+        Some(context) => value.validate_with_locale_and_context(&locale, context),
+        None => value.validate_with_locale(&locale),
+    }
+}
+
+/// The 422 response body for a failed validation
+#[cfg(any(feature = "actix", feature = "axum", feature = "rocket"))]
+pub(crate) fn rejection_body(result: &ValidationResult) -> String {
+    ProblemDetails::new().status(UNPROCESSABLE_ENTITY).render(result)
+}
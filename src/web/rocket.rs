@@ -0,0 +1,116 @@
+//! Rocket data guard that validates the request payload
+//!
+//! `Validated<E>` wraps a data guard that dereferences to the payload, such as `Json<T>`
+//! or `Form<T>`, and runs `T`'s [`Validate`] implementation after parsing. Messages use
+//! the request's preferred `Accept-Language`, and context-aware rules read the
+//! `Option<Arc<ValidationContext>>` a fairing stored with `Request::local_cache`. Invalid payloads fail with
+//! `422 Unprocessable Entity`; the full [`ValidationResult`] stays available to catchers
+//! through [`validation_result`], and [`catcher`] renders it as a `ValidationProblemDetails` body.
+//!
+//! # Example
+//! ```rust,ignore
+//! use rocket::serde::json::Json;
+//! use fluentval::web::rocket::{catcher, Validated};
+//!
+//! #[post("/users", data = "<user>")]
+//! fn create_user(user: Validated<Json<CreateUser>>) -> Status {
+//!     Status::Created
+//! }
+//!
+//! rocket::build()
+//!     .mount("/", routes![create_user])
+//!     .register("/", vec![catcher()])
+//! ```
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use rocket::catcher::{BoxFuture, Catcher};
+use rocket::data::{Data, FromData, Outcome};
+use rocket::http::{ContentType, Status};
+use rocket::request::Request;
+use rocket::response::Responder;
+
+use super::{rejection_body, validate_for_request, PROBLEM_JSON, UNPROCESSABLE_ENTITY};
+use crate::context::ValidationContext;
+use crate::error::ValidationResult;
+use crate::traits::Validate;
+
+/// Data guard wrapper that validates the payload of the inner guard
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Validated<E>(pub E);
+
+impl<E> Validated<E> {
+    /// Unwrap into the inner guard
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E> Deref for Validated<E> {
+    type Target = E;
+
+    fn deref(&self) -> &E {
+        &self.0
+    }
+}
+
+/// Error of the [`Validated`] data guard
+#[derive(Debug)]
+pub enum ValidationRejection<E> {
+    /// The inner guard failed to parse the payload
+    Extract(E),
+    /// The payload was parsed but failed validation
+    Invalid(Box<ValidationResult>),
+}
+
+/// The failed validation of this request, cached for catchers
+struct FailedValidation(Option<ValidationResult>);
+
+/// The validation result that made a [`Validated`] guard fail on this request, if any
+pub fn validation_result<'r>(req: &'r Request<'_>) -> Option<&'r ValidationResult> {
+    req.local_cache(|| FailedValidation(None)).0.as_ref()
+}
+
+#[rocket::async_trait]
+impl<'r, E> FromData<'r> for Validated<E>
+where
+    E: FromData<'r> + Deref,
+    E::Target: Validate,
+{
+    type Error = ValidationRejection<E::Error>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        let inner = match E::from_data(req, data).await {
+            Outcome::Success(inner) => inner,
+            Outcome::Error((status, error)) => return Outcome::Error((status, ValidationRejection::Extract(error))),
+            Outcome::Forward(forward) => return Outcome::Forward(forward),
+        };
+        let context = req.local_cache(|| None::<Arc<ValidationContext>>);
+        let result = validate_for_request(&*inner, req.headers().get_one("Accept-Language"), context.as_deref());
+        if result.is_valid() {
+            return Outcome::Success(Validated(inner));
+        }
+        req.local_cache(|| FailedValidation(Some(result.clone())));
+        Outcome::Error((Status::UnprocessableEntity, ValidationRejection::Invalid(Box::new(result))))
+    }
+}
+
+/// Catcher for `422 Unprocessable Entity` that renders the failed validation as a
+/// `ValidationProblemDetails` body
+///
+/// Requests that failed for another reason get Rocket's default 422 response.
+pub fn catcher() -> Catcher {
+    Catcher::new(UNPROCESSABLE_ENTITY, problem_details)
+}
+
+fn problem_details<'r>(status: Status, req: &'r Request<'_>) -> BoxFuture<'r> {
+    let body = validation_result(req).map(rejection_body);
+    let content_type = ContentType::parse_flexible(PROBLEM_JSON).unwrap_or(ContentType::JSON);
+    Box::pin(async move {
+        match body {
+            Some(body) => (status, (content_type, body)).respond_to(req),
+            None => Err(status),
+        }
+    })
+}
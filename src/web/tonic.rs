@@ -0,0 +1,59 @@
+//! gRPC status mapping for tonic
+//!
+//! Failed validations become `Status::invalid_argument` with a `google.rpc.BadRequest`
+//! detail holding one field violation per error, which gRPC clients in any language can
+//! decode with their rich error model support.
+//!
+//! Tonic interceptors only see request metadata, not the decoded message, so request
+//! messages are validated with [`validate_request`] at the start of the handler.
+//!
+//! # Example
+//! ```rust,ignore
+//! use fluentval::web::tonic::validate_request;
+//!
+//! async fn create_user(&self, request: Request<CreateUserRequest>) -> Result<Response<User>, Status> {
+//!     let user = validate_request(request)?.into_inner();
+//!     // ...
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use tonic::{Request, Status};
+use tonic_types::{ErrorDetails, FieldViolation, StatusExt};
+
+use super::validate_for_request;
+use crate::context::ValidationContext;
+use crate::error::ValidationResult;
+use crate::traits::Validate;
+
+/// `INVALID_ARGUMENT` status with a `google.rpc.BadRequest` field violation per error
+pub fn invalid_argument(result: &ValidationResult) -> Status {
+    let violations: Vec<FieldViolation> = result
+        .errors()
+        .iter()
+        .map(|error| FieldViolation::new(&*error.property, &error.message))
+        .collect();
+    Status::with_error_details(tonic::Code::InvalidArgument, "One or more validation errors occurred.", ErrorDetails::with_bad_request(violations))
+}
+
+impl From<ValidationResult> for Status {
+    fn from(result: ValidationResult) -> Self {
+        invalid_argument(&result)
+    }
+}
+
+/// Validate a request message, in the locale of its `accept-language` metadata and with
+/// the `Arc<ValidationContext>` request extension, if an interceptor added one
+///
+/// Returns the request unchanged when valid, or an [`invalid_argument`] status.
+pub fn validate_request<T: Validate>(request: Request<T>) -> Result<Request<T>, Status> {
+    let accept_language = request.metadata().get("accept-language").and_then(|value| value.to_str().ok());
+    let context = request.extensions().get::<Arc<ValidationContext>>();
+    let result = validate_for_request(request.get_ref(), accept_language, context.map(Arc::as_ref));
+    if result.is_valid() {
+        Ok(request)
+    } else {
+        Err(invalid_argument(&result))
+    }
+}
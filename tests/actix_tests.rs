@@ -0,0 +1,126 @@
+#![cfg(feature = "actix")]
+
+use std::sync::Arc;
+
+use actix_web::http::StatusCode;
+use actix_web::web::{self, Json, Query};
+use actix_web::{test, App, HttpMessage, HttpResponse};
+use fluentval::web::actix::{Validated, ValidationConfig};
+use fluentval::*;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct CreateUser {
+    name: String,
+    age: i32,
+}
+
+fn user_validator() -> impl Validator<CreateUser> {
+    ValidatorBuilder::<CreateUser>::new()
+        .with_message_provider(messages::MessageCatalog::new().with("nl", "not_empty", "mag niet leeg zijn"))
+        .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").not_empty(None::<String>))
+        .rule_for("age", |u| &u.age, RuleBuilder::for_property("age").greater_than_or_equal(18, None::<String>))
+        .build()
+}
+
+impl Validate for CreateUser {
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+        user_validator().validate_with_locale(self, locale)
+    }
+
+    fn locales(&self) -> Vec<String> {
+        user_validator().locales()
+    }
+}
+
+async fn create(user: Validated<Json<CreateUser>>) -> String {
+    user.into_inner().into_inner().name
+}
+
+async fn search(user: Validated<Query<CreateUser>>) -> String {
+    user.name.clone()
+}
+
+#[actix_web::test]
+async fn test_json_is_validated() {
+    let app = test::init_service(App::new().route("/users", web::post().to(create))).await;
+
+    let request = test::TestRequest::post().uri("/users").set_json(serde_json::json!({"name": "Ada", "age": 36})).to_request();
+    let body = test::call_and_read_body(&app, request).await;
+    assert_eq!(body, "Ada");
+
+    let request = test::TestRequest::post().uri("/users").set_json(serde_json::json!({"name": "", "age": 15})).to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = test::read_body_json(response).await;
+    assert_eq!(body["errors"]["name"][0], "must not be empty");
+    assert_eq!(body["errors"]["age"][0], "must be greater than or equal to 18");
+}
+
+#[actix_web::test]
+async fn test_query_messages_follow_accept_language() {
+    let app = test::init_service(App::new().route("/users", web::get().to(search))).await;
+
+    let request = test::TestRequest::get().uri("/users?name=&age=20").insert_header(("accept-language", "nl-BE")).to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = test::read_body(response).await;
+    assert!(String::from_utf8_lossy(&body).contains("mag niet leeg zijn"));
+}
+
+#[actix_web::test]
+async fn test_configured_error_response() {
+    let config = ValidationConfig::default()
+        .error_handler(|result, _| HttpResponse::BadRequest().body(result.to_json(ErrorFormat::Rails)));
+    let app = test::init_service(App::new().app_data(config).route("/users", web::post().to(create))).await;
+
+    let request = test::TestRequest::post().uri("/users").set_json(serde_json::json!({"name": "Ada", "age": 15})).to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(response).await;
+    assert_eq!(body, r#"{"errors":{"age":["must be greater than or equal to 18"]}}"#);
+}
+
+#[derive(Deserialize)]
+struct Workspace {
+    projects: Vec<String>,
+}
+
+impl Validate for Workspace {
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+        self.validate_with_locale_and_context(locale, &())
+    }
+
+    fn validate_with_locale_and_context(&self, locale: &str, context: &dyn std::any::Any) -> ValidationResult {
+        ValidatorBuilder::<Workspace>::new()
+            .rule_for("projects", |w| &w.projects,
+                RuleBuilder::for_property("projects").within_quota(|plan: &Quota| plan.clone(), None::<String>))
+            .build()
+            .validate_with_locale_and_context(self, locale, context)
+    }
+}
+
+async fn create_workspace(workspace: Validated<Json<Workspace>>) -> String {
+    workspace.projects.len().to_string()
+}
+
+#[actix_web::test]
+async fn test_quota_reads_the_request_context() {
+    let app = test::init_service(App::new().route("/workspaces", web::post().to(create_workspace))).await;
+    let workspace = serde_json::json!({"projects": ["a", "b", "c"]});
+
+    let request = test::TestRequest::post().uri("/workspaces").set_json(&workspace).to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = test::read_body(response).await;
+    assert!(String::from_utf8_lossy(&body).contains("without its context"));
+
+    let request = test::TestRequest::post().uri("/workspaces").set_json(&workspace).to_request();
+    request.extensions_mut().insert(Arc::new(ValidationContext::new().with_extension(Quota::new(5, "team"))));
+    assert_eq!(test::call_and_read_body(&app, request).await, "3");
+
+    let request = test::TestRequest::post().uri("/workspaces").set_json(&workspace).to_request();
+    request.extensions_mut().insert(Arc::new(ValidationContext::new().with_extension(Quota::new(2, "free"))));
+    let body: serde_json::Value = test::read_body_json(test::call_service(&app, request).await).await;
+    assert_eq!(body["errors"]["projects"][0], "must contain at most 2 items on the free plan");
+}
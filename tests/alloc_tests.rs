@@ -0,0 +1,60 @@
+//! Allocation counts of the success path; kept in its own binary for the counting allocator
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use fluentval::*;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+struct Signup {
+    name: String,
+    email: String,
+    age: i32,
+    score: f64,
+}
+
+#[test]
+fn test_valid_input_does_not_allocate() {
+    let validator = ValidatorBuilder::<Signup>::new()
+        .schema_id("signup")
+        .schema_version("3")
+        .rule_for("name", |s| &s.name, RuleBuilder::for_property("name").not_empty(None::<String>).length(2, 50, None::<String>, None::<String>))
+        .rule_for("email", |s| &s.email, RuleBuilder::for_property("email").not_empty(None::<String>).email(None::<String>))
+        .rule_for("age", |s| &s.age, RuleBuilder::for_property("age").inclusive_between(18, 130, None::<String>))
+        .rule_for("score", |s| &s.score, RuleBuilder::for_property("score").greater_than_or_equal(0.0, None::<String>))
+        .build();
+    let signup = Signup { name: "Ada".to_string(), email: "ada@example.com".to_string(), age: 36, score: 9.5 };
+    // Compiles the email regex and warms up its per-thread cache
+    assert!(validator.validate(&signup).is_valid());
+
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let result = validator.validate(&signup);
+    assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), before);
+    assert!(result.is_valid());
+    assert_eq!(result.schema_id(), Some("signup"));
+
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("age", "too young"));
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let result = result.clone();
+    // The error list and message are copied, but not the shared property name
+    assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), before + 2);
+    assert_eq!(result.errors().len(), 1);
+}
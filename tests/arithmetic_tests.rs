@@ -0,0 +1,70 @@
+use fluentval::*;
+
+struct Invoice {
+    subtotal: f64,
+    tax: f64,
+    total: f64,
+}
+
+struct Line {
+    unit_price: f64,
+    qty: u32,
+    line_total: f64,
+}
+
+#[test]
+fn test_sum_equals_names_all_properties() {
+    let validator = ValidatorBuilder::<Invoice>::new()
+        .sum_equals("total", |i| i.total, &[("subtotal", |i| i.subtotal), ("tax", |i| i.tax)], 0.005, None::<String>)
+        .build();
+
+    assert!(validate(&Invoice { subtotal: 100.0, tax: 20.0, total: 120.001 }, &validator).is_valid());
+
+    let result = validate(&Invoice { subtotal: 100.0, tax: 20.0, total: 110.0 }, &validator);
+    let error = &result.errors()[0];
+    assert_eq!(error.to_string(), "total: must equal subtotal + tax (120) (got 110)");
+    assert_eq!(error.code.as_deref(), Some("SUM_EQUALS"));
+    assert_eq!(error.state.get("properties").map(String::as_str), Some("total,subtotal,tax"));
+}
+
+#[test]
+fn test_product_equals_with_custom_message() {
+    let validator = ValidatorBuilder::<Line>::new()
+        .product_equals("line_total", |l| l.line_total, &[("unit_price", |l| l.unit_price), ("qty", |l| l.qty as f64)], 0.0,
+            Some("line total should be {expression} = {expected}"))
+        .build();
+
+    assert!(validate(&Line { unit_price: 2.5, qty: 4, line_total: 10.0 }, &validator).is_valid());
+
+    let result = validate(&Line { unit_price: 2.5, qty: 4, line_total: 12.0 }, &validator);
+    assert_eq!(result.errors()[0].message, "line total should be unit_price * qty = 10");
+    assert_eq!(validator.rules()[0].params[0], ("expression".to_string(), "unit_price * qty".to_string()));
+}
+
+#[test]
+fn test_approx_equal() {
+    let rule_fn = RuleBuilder::<f64>::for_property("ratio").approx_equal(0.3, 1e-9, None::<String>).build();
+
+    assert!(rule_fn(&(0.1 + 0.2)).is_empty());
+    let errors = rule_fn(&0.31);
+    assert_eq!(errors[0].message, "must be within 0.000000001 of 0.3");
+    assert_eq!(errors[0].code.as_deref(), Some("APPROX_EQUAL"));
+}
+
+struct Payment {
+    paid: f64,
+    due: f32,
+}
+
+#[test]
+fn test_approx_equal_to_other_property() {
+    let validator = ValidatorBuilder::<Payment>::new()
+        .approx_equal_to("paid", |p| &p.paid, "due", |p| &p.due, 0.01, Some("must match {other} ({expected})"))
+        .build();
+
+    assert!(validate(&Payment { paid: 10.004, due: 10.0 }, &validator).is_valid());
+
+    let result = validate(&Payment { paid: 9.5, due: 10.0 }, &validator);
+    assert_eq!(result.errors()[0].to_string(), "paid: must match due (10) (got 9.5)");
+    assert_eq!(result.errors()[0].state.get("other").map(String::as_str), Some("due"));
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "async-graphql")]
+
+use async_graphql::{EmptySubscription, InputObject, Object, Schema};
+use fluentval::web::async_graphql::{graphql_error, validate_input, FluentValidator};
+use fluentval::*;
+
+#[derive(InputObject)]
+struct CreateUserInput {
+    name: String,
+    age: i32,
+}
+
+impl Validate for CreateUserInput {
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+        ValidatorBuilder::<CreateUserInput>::new()
+            .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").not_empty(None::<String>).min_length(2, None::<String>))
+            .rule_for("age", |u| &u.age, RuleBuilder::for_property("age").greater_than_or_equal(18, None::<String>))
+            .build()
+            .validate_with_locale(self, locale)
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn ping(&self) -> bool {
+        true
+    }
+}
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn create_user(&self, #[graphql(validator(custom = "FluentValidator"))] input: CreateUserInput) -> String {
+        input.name
+    }
+
+    async fn rename(&self, input: CreateUserInput) -> async_graphql::Result<String> {
+        Ok(validate_input(input)?.name)
+    }
+}
+
+fn schema() -> Schema<Query, Mutation, EmptySubscription> {
+    Schema::new(Query, Mutation, EmptySubscription)
+}
+
+#[tokio::test]
+async fn test_argument_validator() {
+    let response = schema().execute(r#"mutation { createUser(input: {name: "Ada", age: 36}) }"#).await;
+    assert!(response.errors.is_empty());
+
+    let response = schema().execute(r#"mutation { createUser(input: {name: "", age: 15}) }"#).await;
+    let json = serde_json::to_value(&response.errors[0]).unwrap();
+    assert_eq!(json["extensions"]["validation"]["name"], serde_json::json!(["must not be empty", "must be at least 2 characters long"]));
+    assert_eq!(json["extensions"]["validation"]["age"], serde_json::json!(["must be greater than or equal to 18"]));
+}
+
+#[tokio::test]
+async fn test_validate_input_in_resolver() {
+    let response = schema().execute(r#"mutation { rename(input: {name: "A", age: 20}) }"#).await;
+    let json = serde_json::to_value(&response.errors[0]).unwrap();
+    assert_eq!(json["message"], "One or more validation errors occurred.");
+    assert_eq!(json["extensions"]["validation"]["name"], serde_json::json!(["must be at least 2 characters long"]));
+}
+
+#[test]
+fn test_graphql_error_groups_by_property() {
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("email", "must be a valid email address"));
+    result.add_error(ValidationError::new("items[0].qty", "must be greater than 0"));
+
+    let error = graphql_error(&result);
+    let json = serde_json::to_value(error.extensions.unwrap()).unwrap();
+    assert_eq!(json, serde_json::json!({"validation": {"email": ["must be a valid email address"], "items[0].qty": ["must be greater than 0"]}}));
+}
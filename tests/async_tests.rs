@@ -0,0 +1,96 @@
+#![cfg(feature = "async")]
+
+use fluentval::*;
+
+struct Signup {
+    username: String,
+    #[cfg_attr(not(feature = "dns"), allow(dead_code))]
+    host: String,
+}
+
+fn signup(username: &str, host: &str) -> Signup {
+    Signup { username: username.to_string(), host: host.to_string() }
+}
+
+fn taken(name: &str) -> bool {
+    name == "admin"
+}
+
+#[tokio::test]
+async fn test_async_rules_run_after_sync_rules() {
+    let validator = ValidatorBuilder::<Signup>::new()
+        .record_skipped_rules()
+        .rule_for("username", |s| &s.username,
+            RuleBuilder::for_property("username").min_length(4, None::<String>))
+        .rule_for_async("username", |s| &s.username,
+            AsyncRuleBuilder::for_property("username")
+                .must(|name: &String| Box::pin(async move { !taken(name) }), "is already taken")
+                .rule(|name: &String| Box::pin(async move { name.contains(' ').then(|| "must not contain spaces".to_string()) })))
+        .build();
+
+    assert!(validator.validate_async(&signup("alice", "")).await.is_valid());
+
+    let result = validator.validate_async(&signup("admin", "")).await;
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(result.errors()[0].message, "is already taken");
+    assert_eq!(result.errors()[0].code, None);
+
+    let result = validator.validate_async(&signup("bo b", "")).await;
+    assert_eq!(result.first_error_for("username"), Some("must not contain spaces"));
+
+    // The sync failure short-circuits the async rules of the same property
+    let result = validator.validate_async(&signup("a b", "")).await;
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(result.errors()[0].code.as_deref(), Some("MIN_LENGTH"));
+    let skipped: Vec<(&str, SkipReason)> = result.skipped_rules().iter().map(|s| (s.rule_kind.as_str(), s.reason)).collect();
+    assert_eq!(skipped, [("must", SkipReason::PropertyFailed), ("custom", SkipReason::PropertyFailed)]);
+
+    // validate skips async rules entirely
+    assert!(validator.validate(&signup("admin", "")).is_valid());
+
+    let kinds: Vec<String> = validator.rules().into_iter().map(|r| r.kind).collect();
+    assert_eq!(kinds, ["min_length", "must", "custom"]);
+}
+
+#[cfg(feature = "dns")]
+#[tokio::test]
+async fn test_resolvable_host() {
+    use std::time::Duration;
+
+    let validator = ValidatorBuilder::<Signup>::new()
+        .rule_for_async("host", |s| &s.host,
+            AsyncRuleBuilder::for_property("host").resolvable_host(Duration::from_secs(5), None::<String>))
+        .build();
+
+    assert!(validator.validate_async(&signup("", "localhost")).await.is_valid());
+
+    let result = validator.validate_async(&signup("", "no-such-host.invalid")).await;
+    assert_eq!(result.errors()[0].message, "must be a resolvable host name");
+    assert_eq!(result.errors()[0].code.as_deref(), Some("RESOLVABLE_HOST"));
+    assert_eq!(validator.rules()[0].params, vec![("timeout_ms".to_string(), "5000".to_string())]);
+}
+
+#[cfg(feature = "mx")]
+#[tokio::test]
+async fn test_email_has_mx() {
+    use std::time::Duration;
+
+    struct Contact {
+        email: String,
+    }
+
+    let validator = ValidatorBuilder::<Contact>::new()
+        .rule_for("email", |c| &c.email, RuleBuilder::for_property("email").email(None::<String>))
+        .rule_for_async("email", |c| &c.email,
+            AsyncRuleBuilder::for_property("email").email_has_mx(Duration::from_secs(3), None::<String>))
+        .build();
+
+    // Malformed addresses are reported by email() alone
+    let result = validator.validate_async(&Contact { email: "nope".to_string() }).await;
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(result.errors()[0].code.as_deref(), Some("EMAIL"));
+
+    let result = validator.validate_async(&Contact { email: "someone@no-such-domain.invalid".to_string() }).await;
+    assert_eq!(result.errors()[0].message, "must use a domain that accepts email");
+    assert_eq!(result.errors()[0].code.as_deref(), Some("EMAIL_HAS_MX"));
+}
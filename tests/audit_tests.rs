@@ -0,0 +1,89 @@
+#![cfg(feature = "audit")]
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, UNIX_EPOCH};
+
+use fluentval::*;
+
+struct Applicant {
+    tax_id: String,
+    email: String,
+}
+
+fn applicant_validator(sink: impl AuditSink + 'static) -> impl Validator<Applicant> {
+    ValidatorBuilder::<Applicant>::new()
+        .schema_id("kyc-applicant")
+        .schema_version("2024-06")
+        .with_clock(FixedClock::new(UNIX_EPOCH + Duration::from_secs(1_718_000_000)))
+        .record_skipped_rules()
+        .audit(sink)
+        .rule_for("tax_id", |a| &a.tax_id, RuleBuilder::for_property("tax_id").not_empty(None::<String>))
+        .rule_for("email", |a| &a.email, RuleBuilder::for_property("email")
+            .email(None::<String>)
+            .skip_when_context(|ctx| ctx.flag("branch_onboarding")))
+        .build()
+}
+
+#[test]
+fn test_audit_records_each_validation() {
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&records);
+    let validator = applicant_validator(move |record: &AuditRecord| recorded.lock().unwrap().push(record.clone()));
+
+    let applicant = Applicant { tax_id: String::new(), email: "walk-in".to_string() };
+    let context = ValidationContext::new().with_value(ACTOR_KEY, "agent-17").with_flag("branch_onboarding");
+    validator.validate_with_context(&applicant, &context);
+    validator.validate(&Applicant { tax_id: "DE123".to_string(), email: "ada@example.com".to_string() });
+
+    let records = records.lock().unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].timestamp_ms, 1_718_000_000_000);
+    assert_eq!(records[0].actor.as_deref(), Some("agent-17"));
+    assert!(records[0].subject.ends_with("Applicant"));
+    assert_eq!(records[0].property, None);
+    assert_eq!(records[0].schema_version.as_deref(), Some("2024-06"));
+    assert!(!records[0].valid);
+    assert_eq!(records[0].failures, [AuditedFailure { property: "tax_id".into(), code: Some("NOT_EMPTY".to_string()) }]);
+    assert_eq!(records[0].skipped_rules, [SkippedRule { property: "email".into(), rule_kind: "email".to_string(), reason: SkipReason::Condition }]);
+
+    assert_eq!(records[1].actor, None);
+    assert!(records[1].valid);
+    assert!(records[1].failures.is_empty());
+}
+
+#[test]
+fn test_audit_records_the_property_of_a_partial_validation() {
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&records);
+    let validator = applicant_validator(move |record: &AuditRecord| recorded.lock().unwrap().push(record.clone()));
+
+    validator.validate_property(&Applicant { tax_id: "DE123".to_string(), email: "walk-in".to_string() }, "tax_id");
+
+    let records = records.lock().unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].property.as_deref(), Some("tax_id"));
+    assert!(records[0].valid);
+}
+
+#[test]
+fn test_json_lines_audit_sink() {
+    let path = std::env::temp_dir().join(format!("fluentval-audit-{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let sink = Arc::new(JsonLinesAuditSink::open(&path).unwrap());
+    let shared = Arc::clone(&sink);
+    let validator = applicant_validator(move |record: &AuditRecord| shared.record(record));
+
+    validator.validate(&Applicant { tax_id: String::new(), email: "ada@example.com".to_string() });
+    validator.validate(&Applicant { tax_id: "DE123".to_string(), email: "ada@example.com".to_string() });
+
+    let log = std::fs::read_to_string(&path).unwrap();
+    let lines: Vec<serde_json::Value> = log.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["schema_id"], "kyc-applicant");
+    assert_eq!(lines[0]["property"], serde_json::Value::Null);
+    assert_eq!(lines[0]["valid"], false);
+    assert_eq!(lines[0]["failures"], serde_json::json!([{"property": "tax_id", "code": "NOT_EMPTY"}]));
+    assert_eq!(lines[1]["valid"], true);
+    assert!(sink.take_error().is_none());
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "axum")]
+
+use std::sync::Arc;
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::routing::{get, post};
+use axum::{Extension, Router};
+use fluentval::web::axum::{ValidatedForm, ValidatedJson, ValidatedQuery};
+use fluentval::*;
+use serde::Deserialize;
+use tower::ServiceExt;
+
+#[derive(Deserialize)]
+struct CreateUser {
+    name: String,
+    age: i32,
+}
+
+fn user_validator() -> impl Validator<CreateUser> {
+    ValidatorBuilder::<CreateUser>::new()
+        .with_message_provider(messages::MessageCatalog::new().with("nl", "not_empty", "mag niet leeg zijn"))
+        .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").not_empty(None::<String>))
+        .rule_for("age", |u| &u.age, RuleBuilder::for_property("age").greater_than_or_equal(18, None::<String>))
+        .build()
+}
+
+impl Validate for CreateUser {
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+        user_validator().validate_with_locale(self, locale)
+    }
+
+    fn locales(&self) -> Vec<String> {
+        user_validator().locales()
+    }
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/json", post(|ValidatedJson(user): ValidatedJson<CreateUser>| async move { user.name }))
+        .route("/query", get(|ValidatedQuery(user): ValidatedQuery<CreateUser>| async move { user.name }))
+        .route("/form", post(|ValidatedForm(user): ValidatedForm<CreateUser>| async move { user.name }))
+}
+
+async fn send(request: Request<Body>) -> (StatusCode, String) {
+    let response = app().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn json(body: &str) -> Request<Body> {
+    Request::post("/json").header("content-type", "application/json").body(Body::from(body.to_string())).unwrap()
+}
+
+#[tokio::test]
+async fn test_valid_json_reaches_handler() {
+    let (status, body) = send(json(r#"{"name":"Ada","age":36}"#)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "Ada");
+}
+
+#[tokio::test]
+async fn test_invalid_json_is_rejected_with_422() {
+    let (status, body) = send(json(r#"{"name":"","age":15}"#)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["status"], 422);
+    assert_eq!(body["errors"]["name"][0], "must not be empty");
+    assert_eq!(body["errors"]["age"][0], "must be greater than or equal to 18");
+}
+
+#[tokio::test]
+async fn test_malformed_json_keeps_axum_rejection() {
+    let (status, _) = send(json(r#"{"name":"#)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_messages_follow_accept_language() {
+    let request = Request::get("/query?name=&age=20").header("accept-language", "nl-BE, en;q=0.5").body(Body::empty()).unwrap();
+    let (status, body) = send(request).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(body.contains("mag niet leeg zijn"), "{}", body);
+}
+
+#[cfg(feature = "i18n")]
+#[tokio::test]
+async fn test_accept_language_negotiates_an_available_locale() {
+    let request = Request::get("/query?name=&age=20").header("accept-language", "it, de;q=0.9").body(Body::empty()).unwrap();
+    let (_, body) = send(request).await;
+    assert!(body.contains("darf nicht leer sein"), "{}", body);
+
+    let request = Request::get("/query?name=&age=20").header("accept-language", "it").body(Body::empty()).unwrap();
+    let (_, body) = send(request).await;
+    assert!(body.contains("must not be empty"), "{}", body);
+}
+
+#[tokio::test]
+async fn test_form_is_validated() {
+    let request = Request::post("/form")
+        .header("content-type", "application/x-www-form-urlencoded")
+        .body(Body::from("name=Ada&age=12"))
+        .unwrap();
+    let (status, _) = send(request).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let request = Request::get("/query?name=Ada&age=40").body(Body::empty()).unwrap();
+    assert_eq!(send(request).await, (StatusCode::OK, "Ada".to_string()));
+}
+
+#[derive(Deserialize)]
+struct Order {
+    items: u32,
+}
+
+struct Plan {
+    max_items: u32,
+}
+
+fn order_validator() -> impl Validator<Order> {
+    ValidatorBuilder::<Order>::new()
+        .rule_for("items", |o| &o.items, RuleBuilder::for_property("items").at_most_ctx(|plan: &Plan| plan.max_items, None::<String>))
+        .build()
+}
+
+impl Validate for Order {
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+        order_validator().validate_with_locale(self, locale)
+    }
+
+    fn validate_with_locale_and_context(&self, locale: &str, context: &dyn std::any::Any) -> ValidationResult {
+        order_validator().validate_with_locale_and_context(self, locale, context)
+    }
+}
+
+fn order(items: u32) -> Request<Body> {
+    Request::post("/orders").header("content-type", "application/json").body(Body::from(format!(r#"{{"items":{}}}"#, items))).unwrap()
+}
+
+#[tokio::test]
+async fn test_context_rules_read_the_request_context() {
+    let orders = Router::new().route("/orders", post(|ValidatedJson(order): ValidatedJson<Order>| async move { order.items.to_string() }));
+    let plan = Arc::new(ValidationContext::new().with_extension(Plan { max_items: 10 }));
+    let with_plan = orders.clone().layer(Extension(plan));
+
+    let response = with_plan.clone().oneshot(order(3)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = with_plan.oneshot(order(30)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = orders.oneshot(order(3)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(String::from_utf8_lossy(&body).contains("without its context"));
+}
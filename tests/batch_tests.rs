@@ -0,0 +1,51 @@
+use fluentval::*;
+
+struct Row {
+    line: u32,
+    email: String,
+    quantity: i32,
+}
+
+fn row(line: u32, email: &str, quantity: i32) -> Row {
+    Row { line, email: email.to_string(), quantity }
+}
+
+fn row_validator() -> impl Validator<Row> {
+    ValidatorBuilder::<Row>::new()
+        .rule_for("email", |r| &r.email, RuleBuilder::for_property("email").email(None::<String>))
+        .rule_for("quantity", |r| &r.quantity, RuleBuilder::for_property("quantity").greater_than(0, None::<String>))
+        .must("quantity", |r| &r.quantity, |_, q| *q < 1000, "too many")
+        .build()
+}
+
+#[test]
+fn test_validate_all() {
+    let rows = vec![row(10, "a@example.com", 1), row(11, "nope", 0), row(12, "b@example.com", 5), row(13, "c@example.com", 5000)];
+    let report = validate_all(&rows, &row_validator());
+
+    assert_eq!((report.total(), report.passed(), report.failed()), (4, 2, 2));
+    assert!(!report.is_valid());
+    assert_eq!(report.error_count(), 3);
+    assert_eq!(report.errors_by_rule().get("EMAIL"), Some(&1));
+    assert_eq!(report.errors_by_rule().get("GREATER_THAN"), Some(&1));
+    assert_eq!(report.errors_by_rule().get(""), Some(&1));
+    let indices: Vec<usize> = report.failures().map(|(index, _)| *index).collect();
+    assert_eq!(indices, vec![1, 3]);
+    assert!(report.result_for(&0).is_none());
+    assert_eq!(report.result_for(&3).unwrap().first_error_for("quantity"), Some("too many"));
+}
+
+#[test]
+fn test_validate_all_by_key() {
+    let rows = vec![row(10, "a@example.com", 1), row(11, "nope", 1)];
+    let report = validate_all_by(&rows, &row_validator(), |r| r.line);
+
+    assert_eq!(report.total(), 2);
+    let failures = report.into_failures();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, 11);
+    assert!(failures[0].1.has_error("email", "EMAIL"));
+
+    let empty: Vec<Row> = Vec::new();
+    assert!(validate_all(&empty, &row_validator()).is_valid());
+}
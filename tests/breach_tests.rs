@@ -0,0 +1,37 @@
+#![cfg(feature = "breach")]
+
+use fluentval::*;
+
+/// Serves the range of the SHA-1 of `"password"` (`5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8`)
+struct FakeRange;
+
+impl BreachRangeProvider for FakeRange {
+    fn range<'a>(&'a self, prefix: &'a str) -> BoxFuture<'a, std::io::Result<String>> {
+        Box::pin(async move {
+            match prefix {
+                "5BAA6" => Ok("003D68EB55068C33ACE09247EE4C639306B:3\r\n1E4C9B93F3F0682250B6CF8331B7EE68FD8:9545824\r\n".to_string()),
+                _ => Err(std::io::Error::other("unavailable")),
+            }
+        })
+    }
+}
+
+struct Account {
+    password: String,
+}
+
+#[tokio::test]
+async fn test_not_in_breach_list() {
+    let validator = ValidatorBuilder::<Account>::new()
+        .rule_for_async("password", |a| &a.password,
+            AsyncRuleBuilder::for_property("password").not_in_breach_list(FakeRange, None::<String>))
+        .build();
+
+    let result = validator.validate_async(&Account { password: "password".to_string() }).await;
+    assert_eq!(result.errors()[0].message, "has appeared in a data breach and must not be used");
+    assert_eq!(result.errors()[0].code.as_deref(), Some("NOT_IN_BREACH_LIST"));
+    assert_eq!(result.errors()[0].state.get("count").map(String::as_str), Some("9545824"));
+
+    // A provider failure fails open
+    assert!(validator.validate_async(&Account { password: "correct horse battery staple".to_string() }).await.is_valid());
+}
@@ -0,0 +1,140 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use fluentval::*;
+
+struct Order {
+    key: String,
+    quantity: i32,
+}
+
+fn counting_validator(runs: Arc<AtomicUsize>) -> impl Validator<Order> {
+    ValidatorBuilder::<Order>::new()
+        .rule_for("quantity", |o| &o.quantity, RuleBuilder::for_property("quantity").rule(move |quantity: &i32| {
+            runs.fetch_add(1, Ordering::SeqCst);
+            (*quantity <= 0).then(|| "must be positive".to_string())
+        }))
+        .build()
+}
+
+#[test]
+fn test_reuses_results_by_key() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let validator = CachedValidator::new(counting_validator(Arc::clone(&runs)), MemoryCache::new(10, Duration::from_secs(60)), |o: &Order| o.key.clone());
+
+    let order = Order { key: "req-1".to_string(), quantity: 0 };
+    assert!(!validator.validate(&order).is_valid());
+    assert!(!validator.validate(&order).is_valid());
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+    assert!(validator.validate(&Order { key: "req-2".to_string(), quantity: 3 }).is_valid());
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+    assert_eq!(validator.cache().len(), 2);
+
+    validator.invalidate(&order);
+    validator.validate(&order);
+    assert_eq!(runs.load(Ordering::SeqCst), 3);
+
+    validator.invalidate_all();
+    assert!(validator.cache().is_empty());
+    validator.validate_with_context(&order, &ValidationContext::new());
+    assert_eq!(runs.load(Ordering::SeqCst), 4);
+    assert!(validator.cache().is_empty());
+}
+
+#[test]
+fn test_entries_expire() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let validator = CachedValidator::new(counting_validator(Arc::clone(&runs)), MemoryCache::new(10, Duration::ZERO), |o: &Order| o.key.clone());
+    let order = Order { key: "req-1".to_string(), quantity: 1 };
+    validator.validate(&order);
+    validator.validate(&order);
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_memory_cache_evicts_oldest_when_full() {
+    let cache = MemoryCache::new(2, Duration::from_secs(60));
+    let result = ValidationResult::new();
+    cache.put("a", &result);
+    cache.put("b", &result);
+    cache.put("c", &result);
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("a").is_none());
+    assert!(cache.get("b").is_some() && cache.get("c").is_some());
+
+    // Replacing a result doesn't let the stale entry evict the new one
+    cache.put("c", &result);
+    cache.put("d", &result);
+    assert!(cache.get("c").is_some() && cache.get("d").is_some());
+
+    let cache = MemoryCache::new(0, Duration::from_secs(60));
+    cache.put("a", &result);
+    assert!(cache.is_empty());
+}
+
+fn counting_rule(runs: Arc<AtomicUsize>, capacity: usize, ttl: Duration) -> impl Fn(&String) -> Vec<ValidationError> {
+    RuleBuilder::<String>::for_property("country")
+        .must(move |code: &String| {
+            runs.fetch_add(1, Ordering::SeqCst);
+            code != "XX"
+        }, "is not a supported country")
+        .cached(capacity, ttl)
+        .build()
+}
+
+#[test]
+fn test_cached_rule_memoizes_by_value() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let rule = counting_rule(Arc::clone(&runs), 10, Duration::from_secs(60));
+
+    for _ in 0..3 {
+        assert!(rule(&"DE".to_string()).is_empty());
+        assert_eq!(rule(&"XX".to_string()).len(), 1);
+    }
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_cached_rule_expires_and_evicts() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let rule = counting_rule(Arc::clone(&runs), 10, Duration::from_millis(20));
+    rule(&"DE".to_string());
+    std::thread::sleep(Duration::from_millis(40));
+    rule(&"DE".to_string());
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+    let runs = Arc::new(AtomicUsize::new(0));
+    let rule = counting_rule(Arc::clone(&runs), 1, Duration::from_secs(60));
+    rule(&"DE".to_string());
+    rule(&"FR".to_string());
+    rule(&"DE".to_string());
+    assert_eq!(runs.load(Ordering::SeqCst), 3);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_async_results_are_cached_apart() {
+    let lookups = Arc::new(AtomicUsize::new(0));
+    let counted = Arc::clone(&lookups);
+    let inner = ValidatorBuilder::<Order>::new()
+        .rule_for_async("key", |o| &o.key, AsyncRuleBuilder::for_property("key").must(move |key: &String| {
+            counted.fetch_add(1, Ordering::SeqCst);
+            let blocked = key == "blocked";
+            Box::pin(async move { !blocked })
+        }, "is blocked"))
+        .build();
+    let validator = CachedValidator::new(inner, MemoryCache::new(10, Duration::from_secs(60)), |o: &Order| o.key.clone());
+    let order = Order { key: "blocked".to_string(), quantity: 1 };
+
+    // A result of `validate` never ran the async rule, so `validate_async` doesn't reuse it
+    assert!(validator.validate(&order).is_valid());
+    assert!(!validator.validate_async(&order).await.is_valid());
+    assert!(!validator.validate_async(&order).await.is_valid());
+    assert_eq!(lookups.load(Ordering::SeqCst), 1);
+    assert!(validator.validate(&order).is_valid());
+
+    validator.invalidate(&order);
+    assert!(validator.cache().is_empty());
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "casemap")]
+
+use fluentval::*;
+
+#[test]
+fn test_title_case() {
+    let rule_fn = RuleBuilder::<String>::for_property("title").title_case(None, None::<String>).build();
+    assert!(rule_fn(&"The Lord Of The Rings".to_string()).is_empty());
+    assert!(rule_fn(&"NASA Reaches McMurdo".to_string()).is_empty());
+
+    let errors = rule_fn(&"The Lord of the Rings".to_string());
+    assert_eq!(errors[0].message, "must be in title case");
+    assert_eq!(errors[0].code.as_deref(), Some("TITLE_CASE"));
+    assert_eq!(errors[0].state.get("word").map(String::as_str), Some("of"));
+}
+
+#[test]
+fn test_sentence_case() {
+    let rule_fn = RuleBuilder::<String>::for_property("caption").sentence_case(None, None::<String>).build();
+    assert!(rule_fn(&"The quick brown fox. It jumps over NASA!".to_string()).is_empty());
+
+    let errors = rule_fn(&"The Quick brown fox".to_string());
+    assert_eq!(errors[0].message, "must be in sentence case");
+    assert_eq!(errors[0].state.get("word").map(String::as_str), Some("Quick"));
+    assert_eq!(rule_fn(&"the quick brown fox".to_string()).len(), 1);
+}
+
+#[test]
+fn test_casing_follows_the_content_language() {
+    // Dutch capitalizes the IJ digraph as a unit
+    let dutch = RuleBuilder::<String>::for_property("country").sentence_case(Some("nl"), None::<String>).build();
+    let english = RuleBuilder::<String>::for_property("country").sentence_case(Some("en"), None::<String>).build();
+    assert!(dutch(&"IJsland is mooi".to_string()).is_empty());
+    assert_eq!(english(&"IJsland is mooi".to_string()).len(), 1);
+
+    // Without an explicit language, the validation locale is used
+    struct Page {
+        heading: String,
+    }
+    let validator = ValidatorBuilder::<Page>::new()
+        .rule_for("heading", |p| &p.heading,
+            RuleBuilder::for_property("heading").sentence_case(None, Some("invalid")))
+        .build();
+    let page = Page { heading: "IJsland is mooi".to_string() };
+    assert!(validator.validate_with_locale(&page, "nl-NL").is_valid());
+    assert!(!validator.validate_with_locale(&page, "en").is_valid());
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "cli")]
+
+use std::path::PathBuf;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn write(name: &str, contents: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("fluentval-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+fn fluentval(args: &[&std::ffi::OsStr]) -> (Option<i32>, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_fluentval")).args(args).output().unwrap();
+    (output.status.code(), String::from_utf8(output.stdout).unwrap(), String::from_utf8(output.stderr).unwrap())
+}
+
+#[test]
+fn test_check_reports_invalid_records() {
+    let rules = write("rules.yaml", "rules:\n  - property: name\n    rule: min_length\n    params: { min: 2 }\n");
+    let data = write("data.ndjson", "{\"name\": \"Ada\"}\n\n{\"name\": \"A\"}\nnot json\n");
+
+    let (code, stdout, stderr) = fluentval(&["check".as_ref(), "--rules".as_ref(), rules.as_os_str(), data.as_os_str()]);
+    assert_eq!(code, Some(1));
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].ends_with("data.ndjson:3: name: must be at least 2 characters long"));
+    assert!(lines[1].contains("data.ndjson:4: invalid JSON"));
+    assert_eq!(stderr, "checked 3 records, 2 invalid\n");
+}
+
+#[test]
+fn test_check_json_array_with_toml_rules() {
+    let rules = write("rules.toml", "[[rules]]\nproperty = \"age\"\nrule = \"greater_than_or_equal\"\nparams = { min = 18 }\n");
+    let data = write("people.json", "[{\"age\": 30}, {\"age\": 40}]");
+
+    let (code, stdout, _) = fluentval(&["check".as_ref(), "--rules".as_ref(), rules.as_os_str(), data.as_os_str()]);
+    assert_eq!(code, Some(0));
+    assert!(stdout.is_empty());
+}
+
+#[test]
+fn test_check_usage_and_load_errors() {
+    let (code, _, stderr) = fluentval(&["check".as_ref()]);
+    assert_eq!(code, Some(2));
+    assert!(stderr.starts_with("fluentval: usage:"));
+
+    let rules = write("bad.yaml", "rules:\n  - { property: name, rule: shiny }\n");
+    let (code, _, stderr) = fluentval(&["check".as_ref(), "--rules".as_ref(), rules.as_os_str(), "-".as_ref()]);
+    assert_eq!(code, Some(2));
+    assert!(stderr.contains("unknown rule `shiny` on `name`"));
+}
+
+#[test]
+fn test_repl_explains_payloads() {
+    let rules = write("repl.yaml", "rules:\n  - property: name\n    rule: min_length\n    params: { min: 2 }\n  - property: age\n    rule: greater_than_or_equal\n    params: { min: 18 }\n");
+    let mut child = Command::new(env!("CARGO_BIN_EXE_fluentval"))
+        .args(["repl".as_ref(), "--rules".as_ref(), rules.as_os_str()])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"{\"name\": \"A\", \"age\": 30}\n{\"name\": \"Ada\",\n \"age\": 40}\n{oops}\n:rules\n:quit\n{}\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[..6], [
+        "  FAIL  name: min_length(min = 2): must be at least 2 characters long",
+        "  ok    age: greater_than_or_equal(min = 18)",
+        "invalid: 1 error",
+        "  ok    name: min_length(min = 2)",
+        "  ok    age: greater_than_or_equal(min = 18)",
+        "valid",
+    ]);
+    assert!(lines[6].starts_with("invalid JSON:"));
+    assert_eq!(lines[7..], ["  name: min_length(min = 2)", "  age: greater_than_or_equal(min = 18)"]);
+}
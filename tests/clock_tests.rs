@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use fluentval::*;
+
+const DAY: u64 = 86_400;
+
+/// 2000-03-01T00:00:00Z
+fn march_2000() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(951_868_800)
+}
+
+struct Signup {
+    birth_date: SystemTime,
+    starts_at: SystemTime,
+}
+
+#[test]
+fn test_in_past_and_in_future_use_the_validator_clock() {
+    let clock = Arc::new(FixedClock::new(march_2000()));
+    let validator = ValidatorBuilder::<Signup>::new()
+        .with_clock(Arc::clone(&clock))
+        .rule_for("birth_date", |s| &s.birth_date, RuleBuilder::for_property("birth_date").in_past(None::<String>))
+        .rule_for("starts_at", |s| &s.starts_at, RuleBuilder::for_property("starts_at").in_future(None::<String>))
+        .build();
+    let signup = Signup { birth_date: march_2000() - Duration::from_secs(1), starts_at: march_2000() + Duration::from_secs(1) };
+    assert!(validator.validate(&signup).is_valid());
+
+    clock.advance(Duration::from_secs(1));
+    let result = validator.validate(&signup);
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(result.first_error_for("starts_at"), Some("must be in the future"));
+    assert!(result.has_error("starts_at", "IN_FUTURE"));
+
+    clock.set(march_2000() - Duration::from_secs(2));
+    assert_eq!(validator.validate(&signup).first_error_for("birth_date"), Some("must be in the past"));
+}
+
+#[test]
+fn test_age_at_least_counts_calendar_years() {
+    let clock = Arc::new(FixedClock::new(march_2000() - Duration::from_secs(DAY)));
+    let validator = ValidatorBuilder::<Signup>::new()
+        .with_clock(Arc::clone(&clock))
+        .rule_for("birth_date", |s| &s.birth_date, RuleBuilder::for_property("birth_date").age_at_least(50, None::<String>))
+        .build();
+    // Born 1950-03-01, before the epoch
+    let signup = Signup { birth_date: UNIX_EPOCH - Duration::from_secs(7_246 * DAY), starts_at: march_2000() };
+
+    let result = validator.validate(&signup);
+    assert_eq!(result.first_error_for("birth_date"), Some("must be at least 50 years old"));
+
+    clock.advance(Duration::from_secs(DAY));
+    assert!(validator.validate(&signup).is_valid());
+}
+
+#[test]
+fn test_enforced_after() {
+    let clock = Arc::new(FixedClock::new(march_2000()));
+    let validator = ValidatorBuilder::<String>::new()
+        .with_clock(Arc::clone(&clock))
+        .record_skipped_rules()
+        .rule_for("password", |p| p,
+            RuleBuilder::for_property("password")
+                .min_length(12, None::<String>)
+                .enforced_after(march_2000() + Duration::from_secs(DAY)))
+        .build();
+
+    let result = validator.validate(&"short".to_string());
+    assert!(result.is_valid());
+    assert_eq!(result.skipped_rules(), [SkippedRule { property: "password".into(), rule_kind: "min_length".to_string(), reason: SkipReason::NotYetEnforced }]);
+    clock.advance(Duration::from_secs(DAY));
+    let result = validator.validate(&"short".to_string());
+    assert!(!result.is_valid());
+    assert!(result.skipped_rules().is_empty());
+}
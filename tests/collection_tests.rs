@@ -0,0 +1,135 @@
+use fluentval::*;
+
+struct Chart {
+    labels: Vec<String>,
+    values: Vec<i32>,
+}
+
+#[test]
+fn test_same_length() {
+    let validator = ValidatorBuilder::<Chart>::new()
+        .same_length("values", |c| &c.labels, |c| &c.values, None::<String>)
+        .build();
+
+    let chart = Chart { labels: vec!["a".into(), "b".into()], values: vec![1, 2, 3] };
+    let result = validate(&chart, &validator);
+    let error = &result.errors()[0];
+    assert_eq!(&*error.property, "values");
+    assert_eq!(error.message, "must have the same number of elements (2 vs 3)");
+    assert_eq!(error.code.as_deref(), Some("SAME_LENGTH"));
+    assert_eq!(error.state.get("right_len").map(String::as_str), Some("3"));
+
+    let chart = Chart { labels: vec!["a".into()], values: vec![1] };
+    assert!(validate(&chart, &validator).is_valid());
+}
+
+#[test]
+fn test_same_length_custom_message_placeholders() {
+    let validator = ValidatorBuilder::<Chart>::new()
+        .same_length("values", |c| &c.labels, |c| &c.values, Some("{left_len} labels for {right_len} values"))
+        .build();
+
+    let chart = Chart { labels: vec!["a".into()], values: vec![1, 2] };
+    assert_eq!(validate(&chart, &validator).errors()[0].message, "1 labels for 2 values");
+}
+
+#[test]
+fn test_each_pair_reports_mismatching_indices() {
+    let validator = ValidatorBuilder::<Chart>::new()
+        .each_pair("values", |c| &c.labels, |c| &c.values,
+            |label, value| !label.is_empty() || *value == 0,
+            "value {CollectionIndex} needs a label")
+        .build();
+
+    let chart = Chart {
+        labels: vec!["a".into(), "".into(), "".into(), "".into()],
+        values: vec![1, 2, 0, 4],
+    };
+    let result = validate(&chart, &validator);
+    let errors: Vec<String> = result.errors().iter().map(|e| e.to_string()).collect();
+    assert_eq!(errors, vec!["values[1]: value 1 needs a label", "values[3]: value 3 needs a label"]);
+}
+
+struct Series {
+    points: Vec<(u64, f64)>,
+}
+
+#[test]
+fn test_sorted_ascending_reports_first_out_of_order_index() {
+    let rules = RuleBuilder::<Vec<i32>>::for_property("tiers").sorted_ascending(None::<String>);
+    let validator = ValidatorBuilder::<Vec<i32>>::new().rule_for("tiers", |v| v, rules).build();
+
+    assert!(validate(&vec![1, 2, 2, 5], &validator).is_valid());
+    assert!(validate(&Vec::new(), &validator).is_valid());
+
+    let result = validate(&vec![1, 3, 2, 0], &validator);
+    assert_eq!(result.errors().len(), 1);
+    let error = &result.errors()[0];
+    assert_eq!(error.message, "must be sorted in ascending order (element 2 is out of order)");
+    assert_eq!(error.code.as_deref(), Some("SORTED_ASCENDING"));
+    assert_eq!(error.state.get("index").map(String::as_str), Some("2"));
+}
+
+#[test]
+fn test_strictly_increasing_by_key() {
+    let rules = RuleBuilder::<Vec<(u64, f64)>>::for_property("points")
+        .strictly_increasing(|point: &(u64, f64)| point.0, Some("timestamps must increase (see point {index})"));
+    let validator = ValidatorBuilder::<Series>::new().rule_for("points", |s| &s.points, rules).build();
+
+    let series = Series { points: vec![(1, 0.5), (2, 0.1), (3, 0.9)] };
+    assert!(validate(&series, &validator).is_valid());
+
+    let series = Series { points: vec![(1, 0.5), (2, 0.1), (2, 0.9)] };
+    let result = validate(&series, &validator);
+    assert_eq!(result.errors()[0].message, "timestamps must increase (see point 2)");
+    assert_eq!(result.errors()[0].code.as_deref(), Some("STRICTLY_INCREASING"));
+}
+
+struct Booking {
+    slots: Vec<(u32, u32)>,
+}
+
+#[test]
+fn test_non_overlapping_reports_conflicting_pairs() {
+    let validator = ValidatorBuilder::<Booking>::new()
+        .non_overlapping("slots", |b| &b.slots, |slot| *slot, None::<String>)
+        .build();
+
+    let booking = Booking { slots: vec![(9, 10), (10, 11), (13, 14)] };
+    assert!(validate(&booking, &validator).is_valid());
+
+    let booking = Booking { slots: vec![(9, 12), (10, 11), (13, 14), (11, 13)] };
+    let result = validate(&booking, &validator);
+    let errors: Vec<String> = result.errors().iter().map(|e| e.to_string()).collect();
+    assert_eq!(errors, vec!["slots[1]: overlaps element 0", "slots[3]: overlaps element 0"]);
+    assert_eq!(result.errors()[1].code.as_deref(), Some("NON_OVERLAPPING"));
+    assert_eq!(result.errors()[1].state.get("second").map(String::as_str), Some("3"));
+}
+
+#[test]
+fn test_non_overlapping_matches_pairwise_comparison() {
+    let validator = ValidatorBuilder::<Booking>::new()
+        .non_overlapping("slots", |b| &b.slots, |slot| *slot, None::<String>)
+        .build();
+
+    // Unsorted, nested, empty and inverted intervals from a fixed pseudo-random sequence
+    let mut seed = 7u32;
+    let mut next = || {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        (seed >> 16) % 40
+    };
+    let slots: Vec<(u32, u32)> = (0..60).map(|_| { let start = next(); (start, start + next() % 6) }).chain([(20, 18), (5, 5)]).collect();
+
+    let mut expected = Vec::new();
+    for second in 0..slots.len() {
+        for first in 0..second {
+            if slots[second].0 < slots[first].1 && slots[first].0 < slots[second].1 {
+                expected.push(format!("{}-{}", first, second));
+            }
+        }
+    }
+    let result = validate(&Booking { slots }, &validator);
+    let pairs: Vec<String> = result.errors().iter().map(|e| format!("{}-{}", e.state["first"], e.state["second"])).collect();
+    assert!(!expected.is_empty());
+    assert_eq!(pairs, expected);
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "config")]
+
+use fluentval::config::{self, ConfigError, DynamicValidator, ValidatorDefinition};
+use fluentval::*;
+use serde_json::json;
+
+const YAML: &str = r#"
+rules:
+  - property: name
+    rule: length
+    params: { min: 2, max: 50 }
+  - property: address.country
+    rule: not_empty
+    message: Country is required
+  - property: age
+    rule: inclusive_between
+    params: { min: 18, max: 120 }
+  - property: email
+    rule: email
+"#;
+
+#[test]
+fn test_yaml_definition() {
+    let validator = DynamicValidator::from_yaml(YAML).unwrap();
+
+    assert!(validator.validate(&json!({"name": "Ada", "address": {"country": "UK"}, "age": 36})).is_valid());
+
+    let result = validator.validate(&json!({"name": "A", "address": {}, "age": 15, "email": "nope"}));
+    assert_eq!(result.first_error_for("name"), Some("must be at least 2 characters long"));
+    assert_eq!(result.first_error_for("address.country"), Some("Country is required"));
+    assert_eq!(result.first_error_for("age"), Some("must be between 18 and 120"));
+    assert_eq!(result.errors()[3].code.as_deref(), Some("EMAIL"));
+    assert_eq!(result.errors()[3].attempted_value.as_deref(), Some("nope"));
+
+    // Wrong JSON types fail the rules (both length bounds for `name`)
+    let result = validator.validate(&json!({"name": 7, "address": {"country": "UK"}, "age": "old"}));
+    assert_eq!(result.errors().len(), 3);
+
+    let kinds: Vec<String> = validator.rules().into_iter().map(|r| r.kind).collect();
+    assert_eq!(kinds, ["min_length", "max_length", "not_empty", "inclusive_between", "email"]);
+}
+
+#[test]
+fn test_toml_definition() {
+    let validator = DynamicValidator::from_toml(r#"
+        [[rules]]
+        property = "callback"
+        rule = "safe_outbound_url"
+        params = { allowed_hosts = ["hooks.internal"] }
+
+        [[rules]]
+        property = "retries"
+        rule = "less_than_or_equal"
+        params = { max = 5 }
+        message = "at most 5 retries"
+    "#).unwrap();
+
+    assert!(validator.validate(&json!({"callback": "http://hooks.internal/x", "retries": 5})).is_valid());
+    let result = validator.validate(&json!({"callback": "http://127.0.0.1/", "retries": 6}));
+    assert_eq!(result.errors().len(), 2);
+    assert_eq!(result.first_error_for("retries"), Some("at most 5 retries"));
+}
+
+#[test]
+fn test_definition_errors() {
+    let unknown = DynamicValidator::from_yaml("rules:\n  - { property: name, rule: shiny }").err().unwrap();
+    assert_eq!(unknown, ConfigError::UnknownRule { property: "name".into(), rule: "shiny".into() });
+    assert_eq!(unknown.to_string(), "unknown rule `shiny` on `name`");
+
+    let missing = DynamicValidator::from_yaml("rules:\n  - { property: name, rule: min_length }").err().unwrap();
+    assert_eq!(missing.to_string(), "`min_length` on `name` needs a valid `min` parameter");
+
+    assert!(matches!(DynamicValidator::from_toml("rules = 3"), Err(ConfigError::Parse(_))));
+}
+
+#[test]
+fn test_expression_rule() {
+    let validator = DynamicValidator::from_yaml("rules:\n  - { property: code, rule: expression, params: { expression: \"len == 6 && contains_digit\" } }").unwrap();
+    assert!(validator.validate(&json!({ "code": "AB12CD" })).is_valid());
+    assert_eq!(validator.validate(&json!({ "code": "ABCDEF" })).first_error_for("code"), Some("must satisfy len == 6 && contains_digit"));
+
+    let invalid = DynamicValidator::from_yaml("rules:\n  - { property: code, rule: expression, params: { expression: \"len >=\" } }").err().unwrap();
+    assert_eq!(invalid, ConfigError::InvalidParam { property: "code".into(), rule: "expression".into(), param: "expression".into() });
+}
+
+#[test]
+fn test_validate_json_bytes() {
+    let validator = DynamicValidator::from_yaml(YAML).unwrap();
+
+    let result = config::validate_json_bytes(br#"{"name": "A", "address": {"country": "UK"}}"#, &validator).unwrap();
+    assert_eq!(result.first_error_for("name"), Some("must be at least 2 characters long"));
+    assert!(config::validate_json_bytes(b"{\"name\": ", &validator).is_none());
+    assert!(config::validate_json_bytes(&[0xff, 0xfe], &validator).is_none());
+    assert!(config::validate_json_bytes(&[b'['; 10_000], &validator).is_none());
+}
+
+#[test]
+fn test_corpus_seeds() {
+    let validator = DynamicValidator::from_yaml(YAML).unwrap();
+    let seeds = config::corpus_seeds(&validator.rules());
+
+    assert_eq!(seeds[0], b"{}");
+    let documents: Vec<serde_json::Value> = seeds.iter().map(|seed| serde_json::from_slice(seed).unwrap()).collect();
+    assert!(documents.contains(&json!({"name": "a"})));
+    assert!(documents.contains(&json!({"name": "aa"})));
+    assert!(documents.contains(&json!({"name": "a".repeat(51)})));
+    assert!(documents.contains(&json!({"address": {"country": null}})));
+    assert!(documents.contains(&json!({"age": 17.0})));
+    assert!(documents.contains(&json!({"age": 121.0})));
+    assert!(documents.contains(&json!({"email": "ada@"})));
+
+    let mut unique = seeds.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), seeds.len());
+    for seed in &seeds {
+        config::validate_json_bytes(seed, &validator).unwrap();
+    }
+}
+
+#[test]
+fn test_definition_round_trip() {
+    let definition = DynamicValidator::from_yaml(YAML).unwrap().definition().clone();
+    assert_eq!(definition.version, config::FORMAT_VERSION);
+
+    let json = definition.to_json();
+    let loaded = DynamicValidator::from_json(&json).unwrap();
+    assert_eq!(loaded.definition(), &definition);
+    assert_eq!(ValidatorDefinition::from_yaml(&definition.to_yaml()).unwrap(), definition);
+
+    let result = loaded.validate(&json!({"name": "A", "address": {}, "age": 15}));
+    assert_eq!(result.first_error_for("address.country"), Some("Country is required"));
+
+    let newer = json.replacen(&format!("\"version\": {}", config::FORMAT_VERSION), "\"version\": 99", 1);
+    assert_eq!(DynamicValidator::from_json(&newer).err(), Some(ConfigError::UnsupportedVersion(99)));
+    assert_eq!(ValidatorDefinition::from_json(r#"{"rules": []}"#).unwrap().version, config::FORMAT_VERSION);
+}
+
+#[test]
+fn test_definition_from_rules_built_in_code() {
+    struct Signup {
+        name: String,
+        age: i32,
+        website: String,
+    }
+
+    let typed = ValidatorBuilder::<Signup>::new()
+        .rule_for("name", |s| &s.name, RuleBuilder::for_property("name").not_empty(None::<String>).max_length(50, Some("Name is too long")))
+        .rule_for("age", |s| &s.age, RuleBuilder::for_property("age").inclusive_between(18, 120, None::<String>).with_code("AGE"))
+        .rule_for("website", |s| &s.website, RuleBuilder::for_property("website").safe_outbound_url(&["example.com", "example.org"], None::<String>))
+        .build();
+
+    let definition = ValidatorDefinition::from_rules(&typed.rules()).unwrap();
+    let loaded = DynamicValidator::from_json(&definition.to_json()).unwrap();
+    assert_eq!(loaded.rules(), typed.rules());
+
+    let result = loaded.validate(&json!({"name": "x".repeat(51), "age": 15, "website": "http://127.0.0.1/admin"}));
+    let errors: Vec<(&str, &str, Option<&str>)> = result.errors().iter().map(|e| (&*e.property, e.message.as_str(), e.code.as_deref())).collect();
+    assert_eq!(errors, vec![
+        ("name", "Name is too long", Some("MAX_LENGTH")),
+        ("age", "must be between 18 and 120", Some("AGE")),
+        ("website", "must be a public http or https URL", Some("SAFE_OUTBOUND_URL")),
+    ]);
+
+    let custom = ValidatorBuilder::<Signup>::new()
+        .must("name", |s| &s.name, |_, name: &String| name != "admin", "is reserved")
+        .build();
+    assert!(matches!(ValidatorDefinition::from_rules(&custom.rules()), Err(ConfigError::UnknownRule { .. })));
+}
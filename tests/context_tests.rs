@@ -0,0 +1,203 @@
+use fluentval::*;
+
+struct Plan {
+    max_items: u32,
+    min_seats: u32,
+}
+
+struct Order {
+    items: u32,
+    seats: u32,
+}
+
+fn order_validator() -> impl Validator<Order> {
+    ValidatorBuilder::<Order>::new()
+        .rule_for("items", |o| &o.items,
+            RuleBuilder::for_property("items").at_most_ctx(|plan: &Plan| plan.max_items, None::<String>))
+        .rule_for("seats", |o| &o.seats,
+            RuleBuilder::for_property("seats").at_least_ctx(|plan: &Plan| plan.min_seats, Some("your plan needs at least {min} seats")))
+        .build()
+}
+
+#[test]
+fn test_limits_come_from_context() {
+    let validator = order_validator();
+    let order = Order { items: 20, seats: 1 };
+
+    let pro = Plan { max_items: 50, min_seats: 1 };
+    assert!(validator.validate_with_context(&order, &pro).is_valid());
+
+    let free = Plan { max_items: 10, min_seats: 2 };
+    let result = validator.validate_with_context(&order, &free);
+    let errors: Vec<String> = result.errors().iter().map(|e| e.to_string()).collect();
+    assert_eq!(errors, vec!["items: must be at most 10 (got 20)", "seats: your plan needs at least 2 seats (got 1)"]);
+    assert_eq!(result.errors()[0].code.as_deref(), Some("AT_MOST"));
+    assert_eq!(result.errors()[0].state.get("max").map(String::as_str), Some("10"));
+}
+
+#[test]
+fn test_missing_context_fails_closed() {
+    let validator = order_validator();
+    let order = Order { items: 1, seats: 1 };
+
+    let result = validator.validate(&order);
+    let codes: Vec<Option<&str>> = result.errors().iter().map(|e| e.code.as_deref()).collect();
+    assert_eq!(codes, vec![Some("CONTEXT_MISSING"); 2]);
+    assert_eq!(result.errors()[0].message, "could not be validated without its context");
+    assert!(result.errors()[0].state["context"].ends_with("Plan"));
+    assert!(!validator.validate_property(&order, "items").is_valid());
+
+    let free = Plan { max_items: 10, min_seats: 2 };
+    assert!(validator.validate_property_with_context(&order, "items", &free).is_valid());
+    let result = validator.validate_with_locale_and_context(&order, "en", &ValidationContext::new().with_extension(free));
+    assert_eq!(result.first_error_for("seats"), Some("your plan needs at least 2 seats"));
+}
+
+struct Workspace {
+    projects: Vec<String>,
+}
+
+#[test]
+fn test_quota_attaches_upgrade_metadata() {
+    let validator = ValidatorBuilder::<Workspace>::new()
+        .rule_for("projects", |w| &w.projects,
+            RuleBuilder::for_property("projects")
+                .within_quota(|plan: &Plan| Quota::new(plan.max_items as usize, "free").upgrade_url("https://example.com/billing"), None::<String>))
+        .build();
+    let plan = Plan { max_items: 2, min_seats: 0 };
+
+    let workspace = Workspace { projects: vec!["a".into(), "b".into()] };
+    assert!(validator.validate_with_context(&workspace, &plan).is_valid());
+
+    let workspace = Workspace { projects: vec!["a".into(), "b".into(), "c".into()] };
+    assert_eq!(validator.validate(&workspace).errors()[0].code.as_deref(), Some("CONTEXT_MISSING"));
+    let result = validator.validate_with_context(&workspace, &plan);
+    let error = &result.errors()[0];
+    assert_eq!(error.message, "must contain at most 2 items on the free plan");
+    assert_eq!(error.code.as_deref(), Some("QUOTA"));
+    assert_eq!(error.state.get("limit").map(String::as_str), Some("2"));
+    assert_eq!(error.state.get("plan").map(String::as_str), Some("free"));
+    assert_eq!(error.state.get("count").map(String::as_str), Some("3"));
+    assert_eq!(error.state.get("upgrade_url").map(String::as_str), Some("https://example.com/billing"));
+}
+
+#[test]
+fn test_validation_context() {
+    struct Tenant {
+        currencies: Vec<&'static str>,
+    }
+    struct Payment {
+        currency: String,
+        amount: u32,
+        items: u32,
+    }
+
+    let validator = ValidatorBuilder::<Payment>::new()
+        .must_with_context("currency", |p| &p.currency,
+            |currency, ctx| ctx.context().extension::<Tenant>().is_none_or(|t| t.currencies.contains(&currency.as_str())),
+            "currency is not enabled for this tenant")
+        .must_with_context("amount", |p| &p.amount,
+            |amount, ctx| ctx.property() == "amount" && (*amount <= 100 || ctx.context().value("tier") == Some("gold")),
+            "amount exceeds the limit")
+        .rule_for("items", |p| &p.items, RuleBuilder::for_property("items").at_most_ctx(|plan: &Plan| plan.max_items, None::<String>))
+        .build();
+
+    let payment = Payment { currency: "EUR".to_string(), amount: 500, items: 20 };
+    let context = ValidationContext::new()
+        .with_value("tier", "gold")
+        .with_extension(Tenant { currencies: vec!["USD"] })
+        .with_extension(Plan { max_items: 10, min_seats: 0 });
+    let result = validator.validate_with_context(&payment, &context);
+    assert_eq!(result.properties(), vec!["currency", "items"]);
+    assert_eq!(result.first_error_for("items"), Some("must be at most 10"));
+
+    let context = ValidationContext::new().with_extension(Plan { max_items: 50, min_seats: 0 });
+    let result = validator.validate_with_context(&payment, &context);
+    assert_eq!(result.properties(), vec!["amount"]);
+    assert!(context.extension::<Tenant>().is_none());
+    assert!(!context.flag("import_mode"));
+}
+
+#[test]
+fn test_skip_when_context() {
+    struct Contact {
+        email: String,
+        name: String,
+    }
+
+    let validator = ValidatorBuilder::<Contact>::new()
+        .rule_for("email", |c| &c.email, RuleBuilder::for_property("email")
+            .email(None::<String>)
+            .skip_when_context(|ctx| ctx.flag("import_mode")))
+        .rule_for("name", |c| &c.name, RuleBuilder::for_property("name").not_empty(None::<String>))
+        .build();
+
+    let contact = Contact { email: "legacy-id-42".to_string(), name: String::new() };
+    assert_eq!(validator.validate(&contact).properties(), vec!["email", "name"]);
+    let import = ValidationContext::new().with_flag("import_mode");
+    assert_eq!(validator.validate_with_context(&contact, &import).properties(), vec!["name"]);
+    assert_eq!(validator.validate_with_context(&contact, &ValidationContext::new()).properties(), vec!["email", "name"]);
+}
+
+#[test]
+fn test_record_skipped_rules() {
+    struct Signup {
+        email: String,
+        vat_id: String,
+        tags: Vec<String>,
+    }
+
+    let builder = || ValidatorBuilder::<Signup>::new()
+        .empty_values(EmptyValues::Skip)
+        .rule_for("email", |s| &s.email, RuleBuilder::for_property("email")
+            .email(None::<String>)
+            .skip_when_context(|ctx| ctx.flag("import_mode")))
+        .rule_for("vat_id", |s| &s.vat_id, RuleBuilder::for_property("vat_id").min_length(8, None::<String>))
+        .rule_for_each("tags", |s| &s.tags, RuleBuilder::for_property("tags").min_length(2, None::<String>));
+    let signup = Signup { email: "legacy-id-42".to_string(), vat_id: String::new(), tags: vec!["rust".to_string(), String::new()] };
+    let import = ValidationContext::new().with_flag("import_mode");
+
+    assert!(builder().build().validate_with_context(&signup, &import).skipped_rules().is_empty());
+
+    let validator = builder().record_skipped_rules().build();
+    let result = validator.validate_with_context(&signup, &import);
+    assert!(result.is_valid());
+    let skipped: Vec<(&str, &str, SkipReason)> = result.skipped_rules().iter().map(|s| (&*s.property, s.rule_kind.as_str(), s.reason)).collect();
+    assert_eq!(skipped, vec![
+        ("email", "email", SkipReason::Condition),
+        ("vat_id", "min_length", SkipReason::EmptyValue),
+        ("tags[1]", "min_length", SkipReason::EmptyValue),
+    ]);
+
+    let result = validator.validate_property(&signup, "vat_id");
+    assert_eq!(result.skipped_rules().len(), 1);
+    assert_eq!(result.skipped_rules()[0].reason, SkipReason::EmptyValue);
+}
+
+#[test]
+fn test_record_rules_skipped_by_limits() {
+    struct Upload {
+        name: String,
+        title: String,
+        tags: Vec<String>,
+    }
+
+    let validator = ValidatorBuilder::<Upload>::new()
+        .record_skipped_rules()
+        .max_errors(1)
+        .max_input_bytes(8)
+        .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").email(None::<String>).max_length(100, None::<String>))
+        .rule_for("title", |u| &u.title, RuleBuilder::for_property("title").not_empty(None::<String>))
+        .rule_for_each("tags", |u| &u.tags, RuleBuilder::for_property("tags").min_length(2, None::<String>))
+        .build();
+
+    let result = validator.validate(&Upload { name: "a".repeat(20), title: String::new(), tags: vec!["x".to_string()] });
+    assert!(result.is_truncated());
+    let skipped: Vec<(&str, &str, SkipReason)> = result.skipped_rules().iter().map(|s| (&*s.property, s.rule_kind.as_str(), s.reason)).collect();
+    assert_eq!(skipped, vec![
+        ("name", "email", SkipReason::ResourceLimit),
+        ("name", "max_length", SkipReason::ResourceLimit),
+        ("title", "not_empty", SkipReason::MaxErrors),
+        ("tags[]", "min_length", SkipReason::MaxErrors),
+    ]);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "chrono")]
+
+use fluentval::*;
+
+#[test]
+fn test_date_and_time_format() {
+    let rule_fn = RuleBuilder::<String>::for_property("birthday").date_format("%Y-%m-%d", None::<String>).build();
+    assert!(rule_fn(&"1815-12-10".to_string()).is_empty());
+    assert_eq!(rule_fn(&"2023-02-30".to_string()).len(), 1);
+    let errors = rule_fn(&"10/12/1815".to_string());
+    assert_eq!(errors[0].message, "must be a date in the format %Y-%m-%d");
+    assert_eq!(errors[0].code.as_deref(), Some("DATE_FORMAT"));
+
+    let rule_fn = RuleBuilder::<String>::for_property("opens_at").time_format("%H:%M", None::<String>).build();
+    assert!(rule_fn(&"09:30".to_string()).is_empty());
+    assert_eq!(rule_fn(&"25:00".to_string())[0].message, "must be a time in the format %H:%M");
+}
+
+#[test]
+fn test_any_date_format() {
+    let rule_fn = RuleBuilder::<String>::for_property("date")
+        .any_format([AcceptedFormat::date("%Y-%m-%d"), AcceptedFormat::date("%d/%m/%Y")], None::<String>)
+        .build();
+    assert!(rule_fn(&"2024-03-01".to_string()).is_empty());
+    assert!(rule_fn(&"01/03/2024".to_string()).is_empty());
+    assert_eq!(rule_fn(&"March 1st".to_string())[0].message, "must match one of the accepted formats: %Y-%m-%d, %d/%m/%Y");
+}
+
+#[test]
+fn test_chrono_timestamps() {
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    let today = Utc.with_ymd_and_hms(2024, 2, 28, 12, 0, 0).unwrap();
+    let validator = ValidatorBuilder::<NaiveDate>::new()
+        .with_clock(FixedClock::new(today.to_system_time()))
+        .rule_for("birth_date", |d| d, RuleBuilder::for_property("birth_date").age_at_least(18, None::<String>).in_past(None::<String>))
+        .build();
+
+    assert!(validator.validate(&NaiveDate::from_ymd_opt(2006, 2, 28).unwrap()).is_valid());
+    assert!(!validator.validate(&NaiveDate::from_ymd_opt(2006, 3, 1).unwrap()).is_valid());
+    assert!(validator.validate(&NaiveDate::from_ymd_opt(1950, 6, 1).unwrap()).is_valid());
+    assert_eq!(validator.validate(&NaiveDate::from_ymd_opt(2030, 1, 1).unwrap()).errors().len(), 2);
+}
@@ -0,0 +1,57 @@
+use fluentval::*;
+
+struct CreateUser {
+    email: String,
+}
+
+struct DeleteUser {
+    id: u64,
+}
+
+enum Command {
+    CreateUser(CreateUser),
+    DeleteUser(DeleteUser),
+    Ping,
+}
+
+fn dispatcher() -> CommandDispatcher<Command> {
+    CommandDispatcher::<Command>::new()
+        .route("CreateUser", |c| match c { Command::CreateUser(x) => Some(x), _ => None },
+            ValidatorBuilder::<CreateUser>::new()
+                .rule_for("email", |c| &c.email, RuleBuilder::for_property("email").email(None::<String>))
+                .build())
+        .route("DeleteUser", |c| match c { Command::DeleteUser(x) => Some(x), _ => None },
+            ValidatorBuilder::<DeleteUser>::new()
+                .rule_for("id", |c| &c.id, RuleBuilder::for_property("id").greater_than(0, None::<String>))
+                .build())
+}
+
+#[test]
+fn test_routes_each_variant() {
+    let dispatcher = dispatcher();
+    let result = dispatcher.validate(&Command::CreateUser(CreateUser { email: "nope".to_string() }));
+    assert_eq!(&*result.errors()[0].property, "email");
+    assert!(dispatcher.validate(&Command::DeleteUser(DeleteUser { id: 7 })).is_valid());
+    assert_eq!(dispatcher.variant_of(&Command::DeleteUser(DeleteUser { id: 7 })), Some("DeleteUser"));
+    assert_eq!(dispatcher.rules().len(), 2);
+}
+
+#[test]
+fn test_unrouted_commands_fail() {
+    let result = dispatcher().validate(&Command::Ping);
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(result.errors()[0].code.as_deref(), Some("UNROUTED"));
+}
+
+#[test]
+fn test_validate_batch() {
+    let commands = vec![
+        Command::CreateUser(CreateUser { email: "ada@example.com".to_string() }),
+        Command::DeleteUser(DeleteUser { id: 0 }),
+        Command::Ping,
+    ];
+    let results = dispatcher().validate_batch(&commands);
+    let summary: Vec<(usize, Option<&str>, bool)> = results.iter().map(|r| (r.index, r.variant.as_deref(), r.result.is_valid())).collect();
+    assert_eq!(summary, vec![(0, Some("CreateUser"), true), (1, Some("DeleteUser"), false), (2, None, false)]);
+    assert_eq!(results[1].result.first_error_for("id"), Some("must be greater than 0"));
+}
@@ -0,0 +1,55 @@
+use fluentval::*;
+
+#[test]
+fn test_password_policy() {
+    let policy = Expression::parse("len >= 8 && contains_upper && !contains(' ')").unwrap();
+    assert!(policy.evaluate("Secret123"));
+    assert!(!policy.evaluate("short1A"));
+    assert!(!policy.evaluate("secret123"));
+    assert!(!policy.evaluate("Secret 123"));
+}
+
+#[test]
+fn test_precedence_and_grouping() {
+    let either = Expression::parse("starts_with('+') || len == 10 && contains_digit").unwrap();
+    assert!(either.evaluate("+44"));
+    assert!(either.evaluate("0123456789"));
+    assert!(!either.evaluate("abcdefghij"));
+
+    let grouped = Expression::parse("!(is_empty || value == \"n/a\") && words <= 2").unwrap();
+    assert!(grouped.evaluate("Ada Lovelace"));
+    assert!(!grouped.evaluate("n/a"));
+    assert!(!grouped.evaluate("Augusta Ada King"));
+
+    let pattern = Expression::parse(r"matches('^[a-z]+\\d$') && ends_with('7')").unwrap();
+    assert!(pattern.evaluate("abc7"));
+    assert!(!pattern.evaluate("abc8"));
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = |source: &str| Expression::parse(source).unwrap_err();
+    assert_eq!(error("len >= 8 &&").to_string(), "expected a condition at position 11");
+    assert_eq!(error("len >= 'x'").message, "cannot compare a number with a string");
+    assert_eq!(error("size > 3").message, "unknown name `size`");
+    assert_eq!(error("contains(x)").position, 9);
+    assert_eq!(error("(len > 1").message, "expected `)`");
+    assert_eq!(error("contains('x").message, "unterminated string");
+    assert!(error("matches('[')").message.starts_with("invalid regex"));
+    assert_eq!(error("len > 1 len").message, "unexpected input");
+}
+
+#[test]
+fn test_satisfies_rule() {
+    let policy = Expression::parse("len >= 8").unwrap();
+    let rule_fn = RuleBuilder::<String>::for_property("password").satisfies(&policy, None::<String>).build();
+    assert!(rule_fn(&"long enough".to_string()).is_empty());
+
+    let errors = rule_fn(&"short".to_string());
+    assert_eq!(errors[0].message, "must satisfy len >= 8");
+    assert_eq!(errors[0].code.as_deref(), Some("EXPRESSION"));
+
+    let rule_fn: Rule<String> = policy.to_rule("too short");
+    assert_eq!(rule_fn(&"short".to_string()), Some("too short".to_string()));
+    assert_eq!(rule_fn(&"long enough".to_string()), None);
+}
@@ -0,0 +1,207 @@
+use fluentval::*;
+
+fn sample_result() -> ValidationResult {
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("email", "must be a valid email address").with_code("EMAIL"));
+    result.add_error(ValidationError::new("name", "must not be empty").with_code("NOT_EMPTY"));
+    result.add_error(ValidationError::new("email", "is \"taken\""));
+    result
+}
+
+#[test]
+fn test_to_json_rails() {
+    assert_eq!(
+        sample_result().to_json(ErrorFormat::Rails),
+        r#"{"errors":{"email":["must be a valid email address","is \"taken\""],"name":["must not be empty"]}}"#
+    );
+}
+
+#[test]
+fn test_to_json_problem_details() {
+    assert_eq!(
+        sample_result().to_json(ErrorFormat::ProblemDetails),
+        r#"{"type":"https://tools.ietf.org/html/rfc9110#section-15.5.1","title":"One or more validation errors occurred.","status":400,"errors":{"email":["must be a valid email address","is \"taken\""],"name":["must not be empty"]}}"#
+    );
+}
+
+#[test]
+fn test_to_json_json_api() {
+    assert_eq!(
+        sample_result().to_json(ErrorFormat::JsonApi),
+        concat!(
+            r#"{"errors":["#,
+            r#"{"status":"422","code":"EMAIL","title":"Invalid Attribute","detail":"must be a valid email address","source":{"pointer":"/data/attributes/email"}},"#,
+            r#"{"status":"422","code":"NOT_EMPTY","title":"Invalid Attribute","detail":"must not be empty","source":{"pointer":"/data/attributes/name"}},"#,
+            r#"{"status":"422","title":"Invalid Attribute","detail":"is \"taken\"","source":{"pointer":"/data/attributes/email"}}"#,
+            r#"]}"#
+        )
+    );
+    assert_eq!(ValidationResult::new().to_json(ErrorFormat::JsonApi), r#"{"errors":[]}"#);
+}
+
+#[test]
+fn test_path_segments() {
+    use PathSegment::{Index, Key};
+
+    assert_eq!(path_segments("items[2].name"), vec![Key("items".into()), Index(2), Key("name".into())]);
+    assert_eq!(path_segments("items.2.name"), vec![Key("items".into()), Index(2), Key("name".into())]);
+    assert_eq!(path_segments("matrix[0][1]"), vec![Key("matrix".into()), Index(0), Index(1)]);
+    assert_eq!(path_segments("email"), vec![Key("email".into())]);
+}
+
+#[test]
+fn test_to_json_graphql() {
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("items[2].name", "must not be empty").with_code("NOT_EMPTY"));
+    result.add_error(ValidationError::new("email", "is taken"));
+    assert_eq!(
+        result.to_json(ErrorFormat::Graphql),
+        r#"{"errors":[{"message":"must not be empty","path":["items",2,"name"],"extensions":{"code":"NOT_EMPTY"}},{"message":"is taken","path":["email"]}]}"#
+    );
+}
+
+#[test]
+fn test_problem_details_options() {
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("tax_number", "is invalid"));
+    result.add_error(ValidationError::new("lines[0].unit_price", "must be positive"));
+    result.add_error(ValidationError::new("email", "is required"));
+
+    assert_eq!(
+        ProblemDetails::new()
+            .pascal_case_properties(true)
+            .instance("/orders")
+            .trace_id("00-4bf92f3577b34da6-01")
+            .render(&result),
+        concat!(
+            r#"{"type":"https://tools.ietf.org/html/rfc9110#section-15.5.1","title":"One or more validation errors occurred.","status":400,"instance":"/orders","#,
+            r#""errors":{"TaxNumber":["is invalid"],"Lines[0].UnitPrice":["must be positive"],"Email":["is required"]},"traceId":"00-4bf92f3577b34da6-01"}"#
+        )
+    );
+
+    assert_eq!(
+        ProblemDetails::new().title("Invalid order").status(422).render(&ValidationResult::new()),
+        r#"{"type":"https://tools.ietf.org/html/rfc9110#section-15.5.1","title":"Invalid order","status":422,"errors":{}}"#
+    );
+}
+
+#[test]
+fn test_json_api_pointers_for_nested_paths() {
+    assert_eq!(json_pointer("email"), "/data/attributes/email");
+    assert_eq!(json_pointer("items[2].name"), "/data/attributes/items/2/name");
+    assert_eq!(json_pointer("address.post/code"), "/data/attributes/address/post~1code");
+
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("lines[0].qty", "must be positive").with_code("GREATER_THAN"));
+    let errors = result.json_api_errors();
+    assert_eq!(errors[0].pointer, "/data/attributes/lines/0/qty");
+    assert_eq!(errors[0].detail, "must be positive");
+    assert_eq!(
+        result.to_json(ErrorFormat::JsonApi),
+        r#"{"errors":[{"status":"422","code":"GREATER_THAN","title":"Invalid Attribute","detail":"must be positive","source":{"pointer":"/data/attributes/lines/0/qty"}}]}"#
+    );
+}
+
+#[test]
+fn test_schema_tagged_results() {
+    struct Order {
+        quantity: u32,
+    }
+    let validator = ValidatorBuilder::<Order>::new()
+        .schema_id("order.create")
+        .schema_version("2024-06")
+        .rule_for("quantity", |o| &o.quantity, RuleBuilder::for_property("quantity").greater_than(0, None::<String>))
+        .build();
+
+    let result = validator.validate(&Order { quantity: 0 });
+    assert_eq!(result.schema_id(), Some("order.create"));
+    assert_eq!(result.schema_version(), Some("2024-06"));
+    assert!(validator.validate(&Order { quantity: 1 }).schema_id().is_some());
+    assert!(ProblemDetails::new().render(&result).ends_with(r#","schemaId":"order.create","schemaVersion":"2024-06"}"#));
+
+    let untagged = ValidatorBuilder::<Order>::new().build().validate(&Order { quantity: 0 });
+    assert_eq!(untagged.schema_id(), None);
+}
+
+#[test]
+fn test_correlation_ids() {
+    use std::sync::{Arc, Mutex};
+
+    struct Order {
+        quantity: i32,
+    }
+
+    let logged = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&logged);
+    let validator = ValidatorBuilder::<Order>::new()
+        .correlation_ids()
+        .on_result(move |result| log.lock().unwrap().push(result.correlation_id().unwrap().to_string()))
+        .rule_for("quantity", |o| &o.quantity, RuleBuilder::for_property("quantity").greater_than(0, None::<String>))
+        .build();
+    let first = validator.validate(&Order { quantity: 0 });
+    let second = validator.validate(&Order { quantity: 1 });
+    let id = first.correlation_id().unwrap();
+    assert_eq!(id.len(), 16);
+    assert_ne!(Some(id), second.correlation_id());
+    assert_eq!(*logged.lock().unwrap(), vec![id.to_string(), second.correlation_id().unwrap().to_string()]);
+    assert!(ProblemDetails::new().render(&first).ends_with(&format!(r#","correlationId":"{}"}}"#, id)));
+
+    let validator = ValidatorBuilder::<Order>::new()
+        .correlation_id_generator(|| "req-42".to_string())
+        .build();
+    assert_eq!(validator.validate(&Order { quantity: 1 }).correlation_id(), Some("req-42"));
+    assert_eq!(ValidatorBuilder::<Order>::new().build().validate(&Order { quantity: 1 }).correlation_id(), None);
+
+    let seeded = || ValidatorBuilder::<Order>::new().correlation_id_generator(SeededIds::new(7)).build();
+    let (first, second) = (seeded(), seeded());
+    let ids: Vec<String> = (0..3).map(|_| first.validate(&Order { quantity: 1 }).correlation_id().unwrap().to_string()).collect();
+    assert_eq!(ids.len(), ids.iter().collect::<std::collections::HashSet<_>>().len());
+    for id in &ids {
+        assert_eq!(second.validate(&Order { quantity: 1 }).correlation_id(), Some(id.as_str()));
+    }
+}
+
+#[test]
+fn test_remap() {
+    let public = ErrorMap::new()
+        .property("customer.email", "buyerEmail")
+        .property("customer", "buyer")
+        .property("customer.address", "shippingAddress")
+        .code("EMAIL", "INVALID_EMAIL");
+
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("customer.email", "invalid").with_code("EMAIL"));
+    result.add_error(ValidationError::new("customer.address.zip", "required").with_code("NOT_EMPTY"));
+    result.add_error(ValidationError::new("customer.name", "required"));
+    result.add_error(ValidationError::new("customers[0]", "unknown"));
+    result.add_error(ValidationError::new("lines[2].qty", "too many"));
+    let result = result.remap(&public);
+
+    let errors: Vec<(&str, Option<&str>)> = result.errors().iter().map(|e| (&*e.property, e.code.as_deref())).collect();
+    assert_eq!(errors, vec![
+        ("buyerEmail", Some("INVALID_EMAIL")),
+        ("shippingAddress.zip", Some("NOT_EMPTY")),
+        ("buyer.name", None),
+        ("customers[0]", None),
+        ("lines[2].qty", None),
+    ]);
+    assert_eq!(ErrorMap::new().property("lines", "items").map_property("lines[2].qty"), "items[2].qty");
+}
+
+#[test]
+fn test_with_property_case() {
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("billing_address.postal_code", "required"));
+    result.add_error(ValidationError::new("line_items[2].unit_price", "too low"));
+    result.add_error(ValidationError::new("labels[build_id]", "unknown"));
+    result.add_error(ValidationError::new("", "inconsistent"));
+
+    let properties = |result: ValidationResult| -> Vec<String> { result.errors().iter().map(|e| e.property.to_string()).collect() };
+    assert_eq!(properties(result.clone().with_property_case(Case::Camel)), ["billingAddress.postalCode", "lineItems[2].unitPrice", "labels[build_id]", ""]);
+    assert_eq!(properties(result.with_property_case(Case::Pascal)), ["BillingAddress.PostalCode", "LineItems[2].UnitPrice", "Labels[build_id]", ""]);
+
+    assert_eq!(Case::Snake.convert("shippingAddress.postalCode"), "shipping_address.postal_code");
+    assert_eq!(Case::Snake.convert("HTTPServer.userID"), "http_server.user_id");
+    assert_eq!(Case::Camel.convert("user-id"), "userId");
+    assert_eq!(Case::Camel.convert("address_line2"), "addressLine2");
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "i18n")]
+
+use fluentval::messages::{self, BundledMessages, MessageCatalog, MessageProvider};
+use fluentval::*;
+
+struct Product {
+    name: String,
+    price: f64,
+}
+
+fn product_validator() -> impl Validator<Product> {
+    ValidatorBuilder::<Product>::new()
+        .with_message_provider(MessageCatalog::new().with("fr", "not_empty", "est obligatoire"))
+        .rule_for("name", |p| &p.name,
+            RuleBuilder::for_property("name")
+                .not_empty(None::<String>)
+                .min_length(3, None::<String>))
+        .rule_for("price", |p| &p.price,
+            RuleBuilder::for_property("price")
+                .inclusive_between(1, 100, None::<String>))
+        .build()
+}
+
+#[test]
+fn test_bundled_catalogs_cover_every_default_message() {
+    let kinds = ["not_empty", "not_null", "min_length", "max_length", "email", "greater_than",
+        "greater_than_or_equal", "less_than", "less_than_or_equal", "inclusive_between", "same_length", "sorted_ascending",
+        "strictly_increasing", "non_overlapping", "date_range",
+        "date_range_inclusive", "date_range_span", "at_most", "at_least", "quota",
+        "sum_equals", "product_equals", "approx_equal", "fits_in",
+        "only_flags", "has_flag", "known_code",
+        "http_header_name", "http_header_value", "http_method", "safe_outbound_url", "not_disposable_email", "title_case", "sentence_case", "expression", "not_similar_to", "no_repeated_chars", "no_keyboard_sequences", "not_in_breach_list", "not_blank", "blank", "exact_length", "length_one_of", "starts_with", "ends_with", "any_format", "date_format", "time_format", "matches", "resolvable_host", "email_has_mx", "in_past", "in_future", "age_at_least", "rule_panicked", "resource_limit", "context_missing"];
+    for locale in ["es", "fr", "de", "pt", "zh"] {
+        for kind in kinds {
+            assert!(BundledMessages.message(kind, locale).is_some(), "{} missing for {}", kind, locale);
+        }
+    }
+}
+
+// Kept in a single test because the global locale is process-wide
+#[test]
+fn test_locale_selection() {
+    let validator = product_validator();
+    let product = Product { name: "".to_string(), price: 500.0 };
+
+    // Per validation; the application's own catalog wins over the bundled one
+    let result = validator.validate_with_locale(&product, "fr-FR");
+    assert_eq!(result.errors()[0].message, "est obligatoire");
+    assert_eq!(result.errors()[1].message, "doit contenir au moins 3 caractères");
+    assert_eq!(result.errors()[2].message, "doit être compris entre 1 et 100");
+
+    // Globally
+    messages::set_locale("pt-BR");
+    let result = validator.validate(&product);
+    assert_eq!(result.first_error_for("name"), Some("não deve estar vazio"));
+    assert_eq!(result.first_error_for("price"), Some("deve estar entre 1 e 100"));
+
+    // Cached results are kept per global locale
+    let cached = CachedValidator::new(product_validator(), MemoryCache::new(10, std::time::Duration::from_secs(60)), |p: &Product| p.name.clone());
+    assert_eq!(cached.validate(&product).first_error_for("name"), Some("não deve estar vazio"));
+
+    messages::reset_locale();
+    assert_eq!(messages::locale(), "en");
+    let result = validator.validate(&product);
+    assert_eq!(result.first_error_for("name"), Some("must not be empty"));
+    assert_eq!(cached.validate(&product).first_error_for("name"), Some("must not be empty"));
+    assert_eq!(cached.cache().len(), 2);
+}
@@ -0,0 +1,15 @@
+#![cfg(feature = "indexmap")]
+
+use fluentval::*;
+
+#[test]
+fn test_into_index_map_keeps_first_error_order() {
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("zip", "required"));
+    result.add_error(ValidationError::new("address.city", "required"));
+    result.add_error(ValidationError::new("zip", "must be 5 digits"));
+
+    let map = result.into_index_map();
+    let entries: Vec<(&str, Vec<&str>)> = map.iter().map(|(k, v)| (k.as_str(), v.iter().map(String::as_str).collect())).collect();
+    assert_eq!(entries, vec![("zip", vec!["required", "must be 5 digits"]), ("address.city", vec!["required"])]);
+}
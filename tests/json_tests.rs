@@ -0,0 +1,38 @@
+#![cfg(feature = "json")]
+
+use fluentval::*;
+
+struct Signup {
+    email: String,
+    password: String,
+}
+
+#[test]
+fn test_from_json_round_trip() {
+    let validator = ValidatorBuilder::<Signup>::new()
+        .schema_id("signup")
+        .rule_for("email", |s| &s.email, RuleBuilder::for_property("email").email(None::<String>))
+        .rule_for("password", |s| &s.password, RuleBuilder::for_property("password").exact_length(8, None::<String>))
+        .build();
+    let result = validator.validate(&Signup { email: "nope".to_string(), password: "short".to_string() });
+
+    let replayed = ValidationResult::from_json(&serde_json::to_string(&result).unwrap()).unwrap();
+    assert_eq!(replayed, result);
+    assert_eq!(replayed.schema_id(), Some("signup"));
+    assert_eq!(replayed.properties(), vec!["email", "password"]);
+    assert!(replayed.has_error("email", "EMAIL"));
+    assert!(!replayed.has_error("email", "EXACT_LENGTH"));
+    let error = replayed.errors_for("password").next().unwrap();
+    assert_eq!(error.state_as::<usize>("actual_length"), Some(5));
+    assert_eq!(error.state_as::<usize>("missing"), None);
+}
+
+#[test]
+fn test_from_json_server_response() {
+    let body = r#"{"errors":[{"property":"age","message":"too young","code":"GREATER_THAN","severity":"error","state":{"limit":"18"}}],"correlation_id":"abc"}"#;
+    let result = ValidationResult::from_json(body).unwrap();
+    assert_eq!(result.first_error_for("age"), Some("too young"));
+    assert_eq!(result.errors()[0].state_as::<i32>("limit"), Some(18));
+    assert_eq!(result.correlation_id(), Some("abc"));
+    assert!(ValidationResult::from_json(r#"{"errors":{"age":["too young"]}}"#).is_err());
+}
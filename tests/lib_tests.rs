@@ -4,7 +4,7 @@ use fluentval::*;
 #[test]
 fn test_validation_error_new() {
     let error = ValidationError::new("email", "must be a valid email");
-    assert_eq!(error.property, "email");
+    assert_eq!(&*error.property, "email");
     assert_eq!(error.message, "must be a valid email");
 }
 
@@ -14,6 +14,48 @@ fn test_validation_error_display() {
     assert_eq!(format!("{}", error), "name: must not be empty");
 }
 
+#[test]
+fn test_validation_error_display_with_attempted_value() {
+    let error = ValidationError::new("age", "must be greater than or equal to 18").with_attempted_value(15);
+    assert_eq!(error.attempted_value.as_deref(), Some("15"));
+    assert_eq!(format!("{}", error), "age: must be greater than or equal to 18 (got 15)");
+}
+
+#[test]
+fn test_rule_builder_captures_attempted_value() {
+    let rule_fn = RuleBuilder::<i32>::for_property("age")
+        .greater_than_or_equal(18, None::<String>)
+        .build();
+    assert_eq!(rule_fn(&15)[0].attempted_value.as_deref(), Some("15"));
+
+    let rule_fn = RuleBuilder::<String>::for_property("email")
+        .email(None::<String>)
+        .build();
+    assert_eq!(rule_fn(&"invalid".to_string())[0].attempted_value.as_deref(), Some("invalid"));
+
+    // Custom rules don't know how to render the value
+    let rule_fn = RuleBuilder::<String>::for_property("name")
+        .must(|v| v == "ok", "must be ok")
+        .build();
+    assert_eq!(rule_fn(&"nope".to_string())[0].attempted_value, None);
+}
+
+#[test]
+fn test_sensitive_properties_keep_values_out_of_errors() {
+    let validator = ValidatorBuilder::<String>::new()
+        .rule_for("password", |p| p, RuleBuilder::for_property("password")
+            .with_prefix_stripped("pw:", RuleBuilder::for_property("password").min_length(8, None::<String>), None::<String>)
+            .max_length(4, None::<String>)
+            .sensitive())
+        .build();
+
+    let result = validator.validate(&"pw:hunter2".to_string());
+    assert_eq!(result.errors().len(), 2);
+    assert!(result.errors().iter().all(|e| e.attempted_value.is_none()));
+    let error = result.into_result().unwrap_err();
+    assert!(!error.to_string().contains("hunter2"), "{}", error);
+}
+
 // ValidationResult tests
 #[test]
 fn test_validation_result_new() {
@@ -48,10 +90,38 @@ fn test_validation_result_errors_by_property() {
     result.add_error(ValidationError::new("email", "must not be empty"));
     result.add_error(ValidationError::new("name", "too short"));
 
-    let grouped = result.errors_by_property();
-    assert_eq!(grouped.len(), 2);
-    assert_eq!(grouped.get("email").unwrap().len(), 2);
-    assert_eq!(grouped.get("name").unwrap().len(), 1);
+    let grouped: Vec<(String, usize)> = result.errors_by_property().into_iter().map(|(property, messages)| (property.to_string(), messages.len())).collect();
+    assert_eq!(grouped, vec![("email".to_string(), 2), ("name".to_string(), 1)]);
+}
+
+#[test]
+fn test_validation_result_ordering_and_dedup() {
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("zip", "required").with_code("NOT_EMPTY"));
+    result.add_error(ValidationError::new("address.city", "required").with_code("NOT_EMPTY"));
+    result.add_error(ValidationError::new("zip", "must be 5 digits"));
+    result.add_error(ValidationError::new("zip", "required").with_code("NOT_EMPTY"));
+    result.add_error(ValidationError::new("zip", "required"));
+
+    let keys: Vec<String> = result.errors_by_property().into_iter().map(|(k, _)| k.to_string()).collect();
+    assert_eq!(keys, vec!["zip", "address.city"]);
+    let sorted: Vec<(&str, &str)> = result.sorted().into_iter().map(|e| (&*e.property, e.message.as_str())).collect();
+    assert_eq!(sorted, vec![
+        ("address.city", "required"),
+        ("zip", "required"),
+        ("zip", "must be 5 digits"),
+        ("zip", "required"),
+        ("zip", "required"),
+    ]);
+
+    result.dedup();
+    let errors: Vec<(&str, &str, Option<&str>)> = result.errors().iter().map(|e| (&*e.property, e.message.as_str(), e.code.as_deref())).collect();
+    assert_eq!(errors, vec![
+        ("zip", "required", Some("NOT_EMPTY")),
+        ("address.city", "required", Some("NOT_EMPTY")),
+        ("zip", "must be 5 digits", None),
+        ("zip", "required", None),
+    ]);
 }
 
 #[test]
@@ -182,6 +252,29 @@ fn test_rule_builder_inclusive_between() {
     assert!(!rule_fn(&66).is_empty());
 }
 
+#[test]
+fn test_rule_builder_comparisons_with_infinity() {
+    let above = RuleBuilder::<f64>::for_property("x").greater_than(0.0, None::<String>).build();
+    let below = RuleBuilder::<f64>::for_property("x").less_than(0.0, None::<String>).build();
+
+    assert!(above(&f64::INFINITY).is_empty());
+    assert!(below(&f64::NEG_INFINITY).is_empty());
+    assert!(!above(&f64::NEG_INFINITY).is_empty());
+    assert!(!below(&f64::INFINITY).is_empty());
+}
+
+#[test]
+fn test_rule_builder_comparisons_let_nan_through() {
+    let above = RuleBuilder::<f64>::for_property("x").greater_than(0.0, None::<String>).build();
+    let at_most = RuleBuilder::<f64>::for_property("x").less_than_or_equal(0.0, None::<String>).build();
+    let between = RuleBuilder::<f64>::for_property("x").inclusive_between(0.0, 1.0, None::<String>).build();
+
+    // NaN is neither below nor above a bound, as with the original comparisons
+    assert!(above(&f64::NAN).is_empty());
+    assert!(at_most(&f64::NAN).is_empty());
+    assert!(between(&f64::NAN).is_empty());
+}
+
 #[test]
 fn test_rule_builder_must() {
     let rule_fn = RuleBuilder::<String>::for_property("password")
@@ -321,8 +414,8 @@ fn test_validator_builder_multiple_errors() {
     assert!(!result.is_valid());
     
     let errors_by_prop = result.errors_by_property();
-    assert!(errors_by_prop.contains_key("name"));
-    assert!(errors_by_prop.contains_key("age"));
+    assert!(errors_by_prop.iter().any(|(property, _)| &**property == "name"));
+    assert!(errors_by_prop.iter().any(|(property, _)| &**property == "age"));
 }
 
 #[test]
@@ -502,7 +595,7 @@ fn test_validator_builder_must_with_object() {
 
     let result = validate(&invalid_command, &validator);
     assert!(!result.is_valid());
-    assert!(result.errors().iter().any(|e| e.property == "phoneNumber"));
+    assert!(result.errors().iter().any(|e| &*e.property == "phoneNumber"));
 
     // Test invalid: alt phone same as primary
     let invalid_command2 = Command {
@@ -513,7 +606,7 @@ fn test_validator_builder_must_with_object() {
 
     let result = validate(&invalid_command2, &validator);
     assert!(!result.is_valid());
-    assert!(result.errors().iter().any(|e| e.property == "altPhoneNumber"));
+    assert!(result.errors().iter().any(|e| &*e.property == "altPhoneNumber"));
 
     // Test valid
     let valid_command = Command {
@@ -572,7 +665,7 @@ fn test_validator_builder_must_with_country_validation() {
 
     let result = validate(&invalid_command, &validator);
     assert!(!result.is_valid());
-    assert!(result.errors().iter().any(|e| e.property == "country"));
+    assert!(result.errors().iter().any(|e| &*e.property == "country"));
 
     // Test invalid: tax number doesn't match country
     let invalid_command2 = Command {
@@ -583,7 +676,7 @@ fn test_validator_builder_must_with_country_validation() {
 
     let result = validate(&invalid_command2, &validator);
     assert!(!result.is_valid());
-    assert!(result.errors().iter().any(|e| e.property == "taxNumber"));
+    assert!(result.errors().iter().any(|e| &*e.property == "taxNumber"));
 
     // Test valid
     let valid_command = Command {
@@ -596,3 +689,881 @@ fn test_validator_builder_must_with_country_validation() {
     assert!(result.is_valid());
 }
 
+
+#[test]
+fn test_rule_builder_with_state() {
+    let rule_fn = RuleBuilder::<Vec<u32>>::for_property("items")
+        .must(|items| items.len() <= 2, "too many items")
+        .with_state(|items| [("limit", 2), ("count", items.len())])
+        .build();
+
+    let errors = rule_fn(&vec![1, 2, 3]);
+    assert_eq!(errors[0].state.get("limit").map(String::as_str), Some("2"));
+    assert_eq!(errors[0].state.get("count").map(String::as_str), Some("3"));
+    assert!(rule_fn(&vec![1]).is_empty());
+
+    let error = ValidationError::new("age", "too young").with_state("minimum", 18);
+    assert_eq!(error.state.get("minimum").map(String::as_str), Some("18"));
+}
+
+#[test]
+fn test_validator_rules_and_fingerprint() {
+    struct User {
+        name: String,
+        age: i32,
+    }
+
+    let build = |min_age: i32, odd: &str, severity: Severity| {
+        ValidatorBuilder::<User>::new()
+            .rule_for("name", |u| &u.name,
+                RuleBuilder::for_property("name")
+                    .not_empty(None::<String>)
+                    .length(2, 50, None::<String>, None::<String>))
+            .rule_for("age", |u| &u.age,
+                RuleBuilder::for_property("age")
+                    .greater_than_or_equal(min_age, None::<String>)
+                    .with_severity(severity))
+            .must("name", |u| &u.name, |u, name| name.len() as i32 != u.age, odd)
+            .build()
+    };
+
+    let validator = build(18, "odd", Severity::Error);
+    let rules = validator.rules();
+    assert_eq!(rules.len(), 5);
+    assert_eq!(rules[1].kind, "min_length");
+    assert_eq!(rules[1].params, vec![("min".to_string(), "2".to_string())]);
+    assert_eq!(rules[3].property, "age");
+    assert_eq!(rules[4].kind, "must");
+    assert_eq!(rules[1].code.as_deref(), Some("MIN_LENGTH"));
+    assert_eq!(rules[1].message, None);
+    assert_eq!(rules[4].message.as_deref(), Some("odd"));
+    assert_eq!(rules[4].code, None);
+
+    // Same configuration, same fingerprint; a changed limit, message or severity changes it
+    assert_eq!(validator.fingerprint(), build(18, "odd", Severity::Error).fingerprint());
+    assert_ne!(validator.fingerprint(), build(21, "odd", Severity::Error).fingerprint());
+    assert_ne!(validator.fingerprint(), build(18, "strange", Severity::Error).fingerprint());
+    assert_ne!(validator.fingerprint(), build(18, "odd", Severity::Warning).fingerprint());
+    // The hash is stable across builds, so it can live in a golden file
+    assert_eq!(validator.fingerprint(), "57a445e933b167e4");
+
+    // A message can't pass for the code that follows it
+    let tagged = |message: &'static str, code: &str| {
+        ValidatorBuilder::<User>::new()
+            .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").must(|_| true, message).with_code(code))
+            .build()
+            .fingerprint()
+    };
+    assert_ne!(tagged("odd|code=X", "C"), tagged("odd", "X|code=C"));
+}
+
+#[test]
+fn test_validator_to_markdown() {
+    struct Account {
+        password: String,
+        age: i32,
+    }
+
+    let validator = ValidatorBuilder::<Account>::new()
+        .rule_for("password", |a| &a.password,
+            RuleBuilder::for_property("password")
+                .not_empty(None::<String>)
+                .min_length(12, None::<String>)
+                .describe("SEC-42: passwords need at least 12 characters"))
+        .rule_for("age", |a| &a.age,
+            RuleBuilder::for_property("age")
+                .inclusive_between(18, 120, None::<String>))
+        .build();
+
+    assert_eq!(validator.rules()[1].description.as_deref(), Some("SEC-42: passwords need at least 12 characters"));
+    assert_eq!(
+        validator.to_markdown(),
+        "# Validation specification\n\
+         \n## `password`\n\n\
+         | Rule | Parameters | Description |\n\
+         |------|------------|-------------|\n\
+         | `not_empty` |  |  |\n\
+         | `min_length` | min = 12 | SEC-42: passwords need at least 12 characters |\n\
+         \n## `age`\n\n\
+         | Rule | Parameters | Description |\n\
+         |------|------------|-------------|\n\
+         | `inclusive_between` | min = 18, max = 120 |  |\n"
+    );
+}
+
+#[test]
+fn test_validator_describe() {
+    struct Signup {
+        password: String,
+        age: i32,
+        tags: Vec<String>,
+    }
+
+    let validator = ValidatorBuilder::<Signup>::new()
+        .rule_for("password", |s| &s.password,
+            RuleBuilder::for_property("password")
+                .min_length(8, None::<String>)
+                .must(|p: &String| p.chars().any(|c| c.is_ascii_uppercase()), "must contain an uppercase letter")
+                .rule(|_| None))
+        .rule_for("age", |s| &s.age,
+            RuleBuilder::for_property("age")
+                .inclusive_between(18, 120, Some("must be an adult")))
+        .rule_for("tags", |s| &s.tags,
+            RuleBuilder::for_property("tags")
+                .sorted_ascending(None::<String>))
+        .build();
+
+    assert_eq!(
+        validator.describe(),
+        "password: must be at least 8 characters long, must contain an uppercase letter\n\
+         age: must be an adult\n\
+         tags: must be sorted in ascending order"
+    );
+}
+
+#[test]
+fn test_validator_builder_rule_for_each_collection_index() {
+    struct Order {
+        quantities: Vec<i32>,
+        tags: Vec<String>,
+    }
+
+    let validator = ValidatorBuilder::<Order>::new()
+        .rule_for_each("lines", |o| &o.quantities,
+            RuleBuilder::for_property("lines")
+                .greater_than(0, Some("line {CollectionIndex}: quantity must be positive")))
+        .rule_for_each("tags", |o| &o.tags,
+            RuleBuilder::for_property("tags")
+                .not_empty(None::<String>))
+        .build();
+
+    let order = Order {
+        quantities: vec![1, 0, 5, -2],
+        tags: vec!["a".to_string(), "".to_string()],
+    };
+    let result = validate(&order, &validator);
+    let errors: Vec<String> = result.errors().iter().map(|e| format!("{}: {}", e.property, e.message)).collect();
+    assert_eq!(errors, vec![
+        "lines[1]: line 1: quantity must be positive",
+        "lines[3]: line 3: quantity must be positive",
+        "tags[1]: must not be empty",
+    ]);
+    assert_eq!(validator.rules()[0].property, "lines[]");
+
+    let valid = Order { quantities: vec![1, 2], tags: Vec::new() };
+    assert!(validate(&valid, &validator).is_valid());
+}
+
+#[test]
+fn test_fits_in() {
+    let rule_fn = RuleBuilder::<i64>::for_property("port").fits_in::<u16>(None::<String>).build();
+
+    assert!(rule_fn(&8080).is_empty());
+    let errors = rule_fn(&70000);
+    assert_eq!(errors[0].to_string(), "port: must fit in u16 (got 70000)");
+    assert_eq!(errors[0].code.as_deref(), Some("FITS_IN"));
+    assert_eq!(rule_fn(&-1).len(), 1);
+
+    let rule_fn = RuleBuilder::<i64>::for_property("len").non_negative_fits_usize(None::<String>).build();
+    assert!(rule_fn(&0).is_empty());
+    assert_eq!(rule_fn(&-5)[0].message, "must fit in usize");
+}
+
+#[test]
+fn test_flag_rules() {
+    const READ: u8 = 0b001;
+    const WRITE: u8 = 0b010;
+    const ADMIN: u8 = 0b100;
+
+    let rule_fn = RuleBuilder::<u8>::for_property("permissions")
+        .only_flags(READ | WRITE | ADMIN, None::<String>)
+        .has_flag(READ, None::<String>)
+        .build();
+
+    assert!(rule_fn(&(READ | ADMIN)).is_empty());
+
+    let errors = rule_fn(&0b1001_0001);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "permissions: must only set flags within 0x7 (unknown bits 0x90) (got 0x91)");
+    assert_eq!(errors[0].code.as_deref(), Some("ONLY_FLAGS"));
+    assert_eq!(errors[0].state.get("unknown").map(String::as_str), Some("0x90"));
+
+    let errors = rule_fn(&WRITE);
+    assert_eq!(errors[0].message, "must have flag 0x1 set");
+}
+
+#[test]
+fn test_known_code() {
+    let rule_fn = RuleBuilder::<u16>::for_property("status").known_code(&[1, 2, 5, 9], None::<String>).build();
+
+    assert!(rule_fn(&5).is_empty());
+    let errors = rule_fn(&3);
+    assert_eq!(errors[0].to_string(), "status: must be one of 1, 2, 5, 9 (got 3)");
+    assert_eq!(errors[0].code.as_deref(), Some("KNOWN_CODE"));
+}
+
+#[test]
+fn test_http_rules() {
+    let name = RuleBuilder::<String>::for_property("header").http_header_name(None::<String>).build();
+    assert!(name(&"X-Request-Id".to_string()).is_empty());
+    assert_eq!(name(&"X Request".to_string())[0].message, "must be a valid HTTP header name");
+    assert_eq!(name(&"".to_string()).len(), 1);
+
+    let value = RuleBuilder::<String>::for_property("value").http_header_value(None::<String>).build();
+    assert!(value(&"Bearer abc.def".to_string()).is_empty());
+    assert!(value(&"".to_string()).is_empty());
+    assert_eq!(value(&"abc\r\nSet-Cookie: x=1".to_string())[0].code.as_deref(), Some("HTTP_HEADER_VALUE"));
+    assert_eq!(value(&" padded".to_string()).len(), 1);
+    assert_eq!(value(&"caf\u{e9}".to_string()).len(), 1);
+
+    let method = RuleBuilder::<String>::for_property("method").http_method(None::<String>).build();
+    assert!(method(&"PATCH".to_string()).is_empty());
+    assert_eq!(method(&"get".to_string())[0].message, "must be a valid HTTP method");
+}
+
+#[test]
+fn test_safe_outbound_url() {
+    let rule_fn = RuleBuilder::<String>::for_property("callback_url").safe_outbound_url(&["hooks.internal"], None::<String>).build();
+    let check = |url: &str| rule_fn(&url.to_string()).is_empty();
+
+    assert!(check("https://example.com/hooks?id=1"));
+    assert!(check("http://93.184.216.34:8080/"));
+    assert!(check("https://[2606:4700::1111]/"));
+    assert!(check("https://[64:ff9b::5db8:d822]/"));
+    assert!(check("https://[2002:5db8:d822::1]/"));
+    assert!(check("http://hooks.internal/relay"));
+
+    for url in [
+        "ftp://example.com/",
+        "file:///etc/passwd",
+        "example.com",
+        "http://localhost:8080/",
+        "http://api.localhost/",
+        "http://127.0.0.1/",
+        "http://10.0.0.5/",
+        "http://192.168.1.1/",
+        "http://169.254.169.254/latest/meta-data/",
+        "http://100.64.0.1/",
+        "http://0.0.0.0/",
+        "http://[::1]/",
+        "http://[fd00::1]/",
+        "http://[::ffff:127.0.0.1]/",
+        "http://[::7f00:1]/",
+        "http://[::a9fe:a9fe]/",
+        "http://[64:ff9b::a9fe:a9fe]/",
+        "http://[2002:a00:1::1]/",
+        "http://[fec0::1]/",
+        "http://2130706433/",
+        "http://0x7f.1/",
+        "http://user@127.0.0.1/",
+        "http://127.0.0.1\\@example.com/",
+    ] {
+        assert!(!check(url), "{} should be rejected", url);
+    }
+
+    let errors = rule_fn(&"http://10.0.0.5/".to_string());
+    assert_eq!(errors[0].message, "must be a public http or https URL");
+    assert_eq!(errors[0].code.as_deref(), Some("SAFE_OUTBOUND_URL"));
+}
+
+#[test]
+fn test_not_disposable_email() {
+    let rule_fn = RuleBuilder::<String>::for_property("email").not_disposable_email(BundledDisposableDomains, None::<String>).build();
+    assert!(rule_fn(&"ada@example.com".to_string()).is_empty());
+    assert!(rule_fn(&"not an email".to_string()).is_empty());
+
+    let errors = rule_fn(&"ada@EU.Mailinator.com".to_string());
+    assert_eq!(errors[0].message, "must not use a disposable email domain");
+    assert_eq!(errors[0].code.as_deref(), Some("NOT_DISPOSABLE_EMAIL"));
+    assert_eq!(errors[0].state.get("domain").map(String::as_str), Some("eu.mailinator.com"));
+
+    // A shared set can be refreshed after the rule is built
+    let domains = std::sync::Arc::new(DomainSet::parse("# blocked\nburner.test\n\n"));
+    let rule_fn = RuleBuilder::<String>::for_property("email").not_disposable_email(domains.clone(), None::<String>).build();
+    assert_eq!(rule_fn(&"a@burner.test".to_string()).len(), 1);
+    domains.replace(["other.test"]);
+    assert!(rule_fn(&"a@burner.test".to_string()).is_empty());
+    assert_eq!(rule_fn(&"a@other.test".to_string()).len(), 1);
+    assert_eq!(domains.len(), 1);
+}
+
+#[test]
+fn test_not_similar_to() {
+    struct Signup {
+        username: String,
+        password: String,
+    }
+    let validator = ValidatorBuilder::<Signup>::new()
+        .not_similar_to("password", |s| &s.password, "username", |s| &s.username, 0.6, None::<String>)
+        .build();
+    let signup = |username: &str, password: &str| Signup { username: username.to_string(), password: password.to_string() };
+
+    assert!(validator.validate(&signup("ada", "correct horse battery")).is_valid());
+    assert!(validator.validate(&signup("", "anything")).is_valid());
+
+    let result = validator.validate(&signup("lovelace", "Lovelace1"));
+    assert_eq!(result.errors()[0].message, "must not be too similar to username");
+    assert_eq!(result.errors()[0].code.as_deref(), Some("NOT_SIMILAR_TO"));
+    assert_eq!(result.errors()[0].state.get("similarity").map(String::as_str), Some("1.00"));
+
+    // One substitution in eight characters
+    let result = validator.validate(&signup("lovelace", "lovelice"));
+    assert_eq!(result.errors()[0].state.get("similarity").map(String::as_str), Some("0.88"));
+    assert!(validator.validate(&signup("lovelace", "l0v3l4c3!")).is_valid());
+}
+
+#[test]
+fn test_password_character_rules() {
+    let rule_fn = RuleBuilder::<String>::for_property("password").no_repeated_chars(2, None::<String>).build();
+    assert!(rule_fn(&"aabbcc".to_string()).is_empty());
+    let errors = rule_fn(&"paaassword".to_string());
+    assert_eq!(errors[0].message, "must not repeat a character more than 2 times in a row");
+    assert_eq!(errors[0].attempted_value, None);
+
+    let rule_fn = RuleBuilder::<String>::for_property("password").no_keyboard_sequences(None::<String>).build();
+    assert!(rule_fn(&"correct horse".to_string()).is_empty());
+    assert!(rule_fn(&"qwe-asd".to_string()).is_empty());
+    for weak in ["xQWERx", "pass4321", "lkjh!", "abcd", "zxcvbn"] {
+        assert_eq!(rule_fn(&weak.to_string())[0].code.as_deref(), Some("NO_KEYBOARD_SEQUENCES"), "{}", weak);
+    }
+}
+
+#[test]
+fn test_not_empty_trimming() {
+    let trimmed = RuleBuilder::<String>::for_property("bio").not_empty_opts(TrimMode::default(), None::<String>).build();
+    let untrimmed = RuleBuilder::<String>::for_property("bio").not_empty_opts(TrimMode::None, None::<String>).build();
+    assert_eq!(trimmed(&" ".to_string()).len(), 1);
+    assert!(untrimmed(&" ".to_string()).is_empty());
+    assert_eq!(untrimmed(&String::new())[0].code.as_deref(), Some("NOT_EMPTY"));
+
+    let not_blank = RuleBuilder::<String>::for_property("bio").not_blank(None::<String>).build();
+    let blank = RuleBuilder::<String>::for_property("honeypot").blank(None::<String>).build();
+    assert_eq!(not_blank(&" \t".to_string())[0].message, "must not be blank");
+    assert!(not_blank(&" x ".to_string()).is_empty());
+    assert!(blank(&"  ".to_string()).is_empty());
+    assert_eq!(blank(&"bot".to_string())[0].message, "must be blank");
+}
+
+#[test]
+fn test_validator_shared_across_threads() {
+    use std::sync::{Arc, LazyLock};
+
+    struct User {
+        name: String,
+        age: i32,
+    }
+
+    static VALIDATOR: LazyLock<Box<dyn Validator<User>>> = LazyLock::new(|| {
+        Box::new(ValidatorBuilder::<User>::new()
+            .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").not_empty(None::<String>))
+            .build())
+    });
+    let validator = Arc::new(ValidatorBuilder::<User>::new()
+        .rule_for("age", |u| &u.age, RuleBuilder::for_property("age").greater_than(0, None::<String>))
+        .build());
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let validator = Arc::clone(&validator);
+            std::thread::spawn(move || {
+                let user = User { name: if i % 2 == 0 { String::new() } else { "Ada".to_string() }, age: i };
+                (VALIDATOR.validate(&user).is_valid(), validator.validate(&user).is_valid())
+            })
+        })
+        .collect();
+    let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(results, [(false, false), (true, true), (false, true), (true, true)]);
+}
+
+#[test]
+fn test_exact_length_rules() {
+    let rule_fn = RuleBuilder::<String>::for_property("iban_check").exact_length(2, None::<String>).build();
+    assert!(rule_fn(&"29".to_string()).is_empty());
+    let errors = rule_fn(&"290".to_string());
+    assert_eq!(errors[0].message, "length must be exactly 2");
+    assert_eq!(errors[0].state.get("actual_length").map(String::as_str), Some("3"));
+
+    let rule_fn = RuleBuilder::<String>::for_property("pan").length_one_of(&[15, 16, 19], None::<String>).build();
+    assert!(rule_fn(&"4111111111111111".to_string()).is_empty());
+    let errors = rule_fn(&"411111111111".to_string());
+    assert_eq!(errors[0].message, "length must be one of 15, 16, 19");
+    assert_eq!(errors[0].code.as_deref(), Some("LENGTH_ONE_OF"));
+
+    let rule_fn = RuleBuilder::<Vec<u8>>::for_property("key").length_one_of(&[16, 32], Some("{actual_length} bytes is not a valid key size")).build();
+    assert!(rule_fn(&vec![0; 32]).is_empty());
+    assert_eq!(rule_fn(&vec![0; 20])[0].message, "20 bytes is not a valid key size");
+}
+
+#[test]
+fn test_validator_clone() {
+    struct Item {
+        name: String,
+    }
+    let validator = ValidatorBuilder::<Item>::new()
+        .rule_for("name", |i| &i.name, RuleBuilder::for_property("name").not_empty(None::<String>))
+        .build();
+    let copy = validator.clone();
+    drop(validator);
+    assert_eq!(copy.validate(&Item { name: String::new() }).first_error_for("name"), Some("must not be empty"));
+    assert_eq!(copy.rules().len(), 1);
+}
+
+#[test]
+fn test_prefix_and_suffix_stripped() {
+    let phone = RuleBuilder::<String>::for_property("phone").with_prefix_stripped("+",
+        RuleBuilder::for_property("digits")
+            .length(8, 15, None::<String>, None::<String>)
+            .must(|digits: &String| digits.bytes().all(|b| b.is_ascii_digit()), "must only contain digits after the +"),
+        None::<String>);
+    let validator = ValidatorBuilder::<String>::new().rule_for("phone", |p| p, phone).build();
+    let rules: Vec<_> = validator.rules().into_iter().map(|d| format!("{}.{}", d.property, d.kind)).collect();
+    assert_eq!(rules, ["phone.starts_with", "phone.min_length", "phone.max_length", "phone.must"]);
+
+    let rule_fn = |value: &String| validator.validate(value).errors().to_vec();
+    assert!(rule_fn(&"+4915112345678".to_string()).is_empty());
+
+    let errors = rule_fn(&"015112345678".to_string());
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "must start with +");
+
+    let errors = rule_fn(&"+49 1511".to_string());
+    assert_eq!(errors.len(), 2);
+    assert_eq!(&*errors[0].property, "phone");
+    assert_eq!(errors[0].attempted_value.as_deref(), Some("49 1511"));
+    assert_eq!(errors[1].message, "must only contain digits after the +");
+
+    let rule_fn = RuleBuilder::<String>::for_property("timeout")
+        .with_suffix_stripped("ms", RuleBuilder::for_property("timeout").max_length(4, None::<String>), None::<String>)
+        .build();
+    assert!(rule_fn(&"250ms".to_string()).is_empty());
+    assert_eq!(rule_fn(&"250s".to_string())[0].code.as_deref(), Some("ENDS_WITH"));
+    assert_eq!(rule_fn(&"250000ms".to_string())[0].code.as_deref(), Some("MAX_LENGTH"));
+}
+
+#[test]
+fn test_any_format() {
+    let rule_fn = RuleBuilder::<String>::for_property("reference")
+        .any_format([
+            AcceptedFormat::regex(r"^INV-\d{6}$").unwrap(),
+            AcceptedFormat::new("8 hex digits", |s| s.len() == 8 && s.chars().all(|c| c.is_ascii_hexdigit())),
+        ], None::<String>)
+        .build();
+    assert!(rule_fn(&"INV-000042".to_string()).is_empty());
+    assert!(rule_fn(&"deadbeef".to_string()).is_empty());
+
+    let errors = rule_fn(&"INV-42".to_string());
+    assert_eq!(errors[0].message, r"must match one of the accepted formats: ^INV-\d{6}$, 8 hex digits");
+    assert_eq!(errors[0].code.as_deref(), Some("ANY_FORMAT"));
+    assert!(AcceptedFormat::regex("(").is_err());
+}
+
+#[test]
+fn test_validate_adapter() {
+    struct Tag {
+        name: String,
+    }
+    fn check(payload: impl Validate) -> bool {
+        payload.validate().is_valid()
+    }
+
+    let validator = ValidatorBuilder::<Tag>::new()
+        .rule_for("name", |t| &t.name, RuleBuilder::for_property("name").not_empty(None::<String>))
+        .build();
+    let tag = Tag { name: String::new() };
+    let bound = validator.bind(&tag);
+    assert!(!check(&bound));
+    assert_eq!(bound.validate_with_locale("en").first_error_for("name"), Some("must not be empty"));
+    assert!(bound.value().name.is_empty());
+
+    let dynamic: Box<dyn Validator<Tag>> = Box::new(validator);
+    assert!(check(Box::new(WithValidator::new(&Tag { name: "rust".to_string() }, dynamic.as_ref()))));
+}
+
+#[test]
+fn test_has_validator() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Address {
+        city: String,
+    }
+    struct Customer {
+        name: String,
+        address: Address,
+    }
+
+    impl HasValidator for Address {
+        fn validator() -> impl Validator<Self> + 'static {
+            BUILDS.fetch_add(1, Ordering::SeqCst);
+            ValidatorBuilder::<Address>::new()
+                .rule_for("city", |a| &a.city, RuleBuilder::for_property("city").not_empty(None::<String>))
+                .build()
+        }
+    }
+
+    impl HasValidator for Customer {
+        fn validator() -> impl Validator<Self> + 'static {
+            // Looking up another type's validator while building is fine
+            let address = Address::default_validator();
+            ValidatorBuilder::<Customer>::new()
+                .rule_for("name", |c| &c.name, RuleBuilder::for_property("name").not_empty(None::<String>))
+                .rule_for("address", |c| &c.address, RuleBuilder::for_property("address")
+                    .rule(move |a: &Address| address.validate(a).errors().first().map(|e| format!("{}: {}", e.property, e.message))))
+                .build()
+        }
+    }
+
+    let customer = Customer { name: "Ada".to_string(), address: Address { city: String::new() } };
+    assert_eq!(customer.validate_default().first_error_for("address"), Some("city: must not be empty"));
+    assert!(!customer.address.validate_default().is_valid());
+    assert_eq!(customer.validate_default().errors().len(), 1);
+    assert_eq!(BUILDS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_include() {
+    struct Invoice {
+        created_by: String,
+        total: f64,
+    }
+
+    fn audited() -> ValidatorBuilder<Invoice> {
+        ValidatorBuilder::<Invoice>::new()
+            .rule_for("created_by", |i| &i.created_by, RuleBuilder::for_property("created_by").not_empty(None::<String>))
+    }
+
+    let validator = ValidatorBuilder::<Invoice>::new()
+        .include(audited())
+        .rule_for("total", |i| &i.total, RuleBuilder::for_property("total").greater_than(0.0, None::<String>))
+        .build();
+    let result = validator.validate(&Invoice { created_by: String::new(), total: 0.0 });
+    let properties: Vec<&str> = result.errors().iter().map(|e| &*e.property).collect();
+    assert_eq!(properties, vec!["created_by", "total"]);
+    assert_eq!(validator.rules().len(), 2);
+
+    let validator = ValidatorBuilder::<Invoice>::new()
+        .include_validator(audited().build())
+        .rule_for("total", |i| &i.total, RuleBuilder::for_property("total").greater_than(0.0, None::<String>))
+        .build();
+    let result = validator.validate(&Invoice { created_by: String::new(), total: 1.0 });
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(result.first_error_for("created_by"), Some("must not be empty"));
+    assert_eq!(validator.rules().len(), 2);
+    assert!(validator.validate(&Invoice { created_by: "ada".to_string(), total: 1.0 }).is_valid());
+
+    // The included validator renders in the locale of the call, with or without a context
+    let validator = ValidatorBuilder::<Invoice>::new()
+        .include_validator(audited().with_message_provider(messages::MessageCatalog::new().with("nl", "not_empty", "mag niet leeg zijn")).build())
+        .build();
+    let invoice = Invoice { created_by: String::new(), total: 1.0 };
+    let result = validator.validate_with_locale_and_context(&invoice, "nl", &ValidationContext::new());
+    assert_eq!(result.first_error_for("created_by"), Some("mag niet leeg zijn"));
+}
+
+#[test]
+fn test_override_rules_for() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NAME_CHECKS: AtomicUsize = AtomicUsize::new(0);
+
+    struct User {
+        name: String,
+        email: String,
+        tags: Vec<String>,
+    }
+
+    fn user_rules() -> ValidatorBuilder<User> {
+        ValidatorBuilder::<User>::new()
+            .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").min_length(5, None::<String>).max_length(20, None::<String>))
+            .must("name", |u| &u.name, |_, _| NAME_CHECKS.fetch_add(1, Ordering::SeqCst) < usize::MAX, "counted")
+            .rule_for("email", |u| &u.email, RuleBuilder::for_property("email").email(None::<String>))
+            .rule_for_each("tags", |u| &u.tags, RuleBuilder::for_property("tags").not_empty(None::<String>))
+    }
+
+    let validator = ValidatorBuilder::<User>::new()
+        .include(user_rules())
+        .override_rules_for("name", |u| &u.name, RuleBuilder::for_property("name").max_length(200, None::<String>))
+        .override_rules_for("tags", |u| &u.tags, RuleBuilder::for_property("tags").rule(|tags: &Vec<String>| (tags.len() > 3).then(|| "too many tags".to_string())))
+        .build();
+
+    let user = User { name: "Al".to_string(), email: "nope".to_string(), tags: vec![String::new()] };
+    let result = validator.validate(&user);
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(&*result.errors()[0].property, "email");
+    // Overridden rules no longer run at all
+    assert_eq!(NAME_CHECKS.load(Ordering::SeqCst), 0);
+    let properties: Vec<String> = validator.rules().into_iter().map(|d| d.property).collect();
+    assert_eq!(properties, vec!["email", "name", "tags"]);
+
+    let user = User { name: "x".repeat(201), email: "al@example.com".to_string(), tags: Vec::new() };
+    assert_eq!(&*validator.validate(&user).errors()[0].property, "name");
+    assert!(!user_rules().build().validate(&User { name: "Al".to_string(), email: "al@example.com".to_string(), tags: Vec::new() }).is_valid());
+}
+
+#[test]
+fn test_validate_property() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct Signup {
+        email: String,
+        name: String,
+        tags: Vec<String>,
+    }
+
+    let name_checks = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&name_checks);
+    let validator = ValidatorBuilder::<Signup>::new()
+        .rule_for("email", |s| &s.email, RuleBuilder::for_property("email").email(None::<String>))
+        .rule_for("name", |s| &s.name, RuleBuilder::for_property("name").rule(move |_: &String| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Some("taken".to_string())
+        }))
+        .rule_for_each("tags", |s| &s.tags, RuleBuilder::for_property("tags").not_empty(None::<String>))
+        .build();
+
+    let signup = Signup { email: "nope".to_string(), name: "ada".to_string(), tags: vec![String::new(), "ok".to_string(), String::new()] };
+    let result = validator.validate_property(&signup, "email");
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(&*result.errors()[0].property, "email");
+    assert_eq!(name_checks.load(Ordering::SeqCst), 0);
+
+    assert_eq!(validator.validate_property(&signup, "tags").errors().len(), 2);
+    assert_eq!(validator.validate_property(&signup, "tags[2]").properties(), vec!["tags[2]"]);
+    assert!(validator.validate_property(&signup, "unknown").is_valid());
+    assert_eq!(validator.validate_property(&signup, "name").first_error_for("name"), Some("taken"));
+    assert_eq!(name_checks.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_matches() {
+    use std::sync::Arc;
+
+    let sku = Arc::new(regex::Regex::new(r"^[A-Z]{3}-\d{4}$").unwrap());
+    let validator = RuleBuilder::<String>::for_property("sku").matches(Arc::clone(&sku), None::<String>).build();
+    assert!(validator(&"ABC-1234".to_string()).is_empty());
+    let errors = validator(&"abc-12".to_string());
+    assert_eq!(errors[0].message, r"must match the pattern ^[A-Z]{3}-\d{4}$");
+    assert_eq!(errors[0].code.as_deref(), Some("MATCHES"));
+
+    let validator = RuleBuilder::<&str>::for_property("sku").matches(regex::Regex::new("^x+$").unwrap(), Some("only x, please")).build();
+    assert_eq!(validator(&"xy")[0].message, "only x, please");
+}
+
+#[test]
+fn test_into_map() {
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("address.city", "required"));
+    result.add_error(ValidationError::new("lines[2]", "too many"));
+    result.add_error(ValidationError::new("address.city", "too short"));
+    result.add_error(ValidationError::new("", "inconsistent order"));
+
+    let map: std::collections::HashMap<String, Vec<String>> = result.clone().into_map();
+    assert_eq!(map, result.errors_by_property().into_iter().map(|(property, messages)| (property.to_string(), messages)).collect());
+    assert_eq!(map["address.city"], vec!["required", "too short"]);
+    assert_eq!(map["lines[2]"], vec!["too many"]);
+    assert_eq!(map[""], vec!["inconsistent order"]);
+    assert_eq!(map.len(), 3);
+}
+
+#[test]
+fn test_one_error_per_property() {
+    struct Signup {
+        password: String,
+        email: String,
+    }
+
+    let builder = || ValidatorBuilder::<Signup>::new()
+        .rule_for("password", |s| &s.password, RuleBuilder::for_property("password").min_length(8, None::<String>).matches(regex::Regex::new("[0-9]").unwrap(), None::<String>).sensitive())
+        .rule_for("email", |s| &s.email, RuleBuilder::for_property("email").not_empty(None::<String>).email(None::<String>));
+    let signup = Signup { password: "abc".to_string(), email: String::new() };
+
+    assert_eq!(builder().build().validate(&signup).errors().len(), 4);
+    let result = builder().one_error_per_property().build().validate(&signup);
+    let errors: Vec<String> = result.errors().iter().map(|e| e.to_string()).collect();
+    assert_eq!(errors, vec!["password: must be at least 8 characters long", "email: must not be empty (got )"]);
+
+    let messages = builder().build().validate(&signup).single_message_map();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages["password"], "must be at least 8 characters long");
+    assert_eq!(messages["email"], "must not be empty");
+
+    let result = builder().build().validate(&Signup { password: "abcdefgh".to_string(), email: String::new() });
+    #[cfg(feature = "indexmap")]
+    {
+        let messages: Vec<(String, String)> = result.first_errors_map().into_iter().collect();
+        assert_eq!(messages, vec![
+            ("password".to_string(), "must match the pattern [0-9]".to_string()),
+            ("email".to_string(), "must not be empty".to_string()),
+        ]);
+    }
+    assert!(result.has_error_for("email"));
+    assert!(!result.has_error_for("name"));
+}
+
+#[test]
+fn test_property_names_are_shared() {
+    struct User {
+        name: String,
+    }
+
+    let validator = ValidatorBuilder::<User>::new()
+        .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").not_empty(None::<String>).min_length(2, None::<String>))
+        .build();
+    let user = User { name: String::new() };
+
+    let first = validator.validate(&user);
+    let second = validator.validate(&user);
+    assert!(std::sync::Arc::ptr_eq(&first.errors()[0].property, &first.errors()[1].property));
+    assert!(std::sync::Arc::ptr_eq(&first.errors()[0].property, &second.errors()[0].property));
+    assert!(std::sync::Arc::ptr_eq(&first.errors()[0].property, &first.errors_by_property()[0].0));
+}
+
+#[test]
+fn test_empty_values_skip() {
+    struct Profile {
+        email: String,
+        website: String,
+        nickname: String,
+        tags: Vec<String>,
+        manager: Option<String>,
+    }
+
+    let validator = ValidatorBuilder::<Profile>::new()
+        .empty_values(EmptyValues::Skip)
+        .rule_for("email", |p| &p.email, RuleBuilder::for_property("email").not_empty(None::<String>).email(None::<String>))
+        .rule_for("website", |p| &p.website, RuleBuilder::for_property("website").min_length(8, None::<String>))
+        .rule_for("nickname", |p| &p.nickname,
+            RuleBuilder::for_property("nickname")
+                .length(3, 20, None::<String>, None::<String>)
+                .must(|name: &String| name.is_empty() || !name.trim().is_empty(), "must not be only whitespace")
+                .even_if_empty())
+        .rule_for("tags", |p| &p.tags, RuleBuilder::for_property("tags").length_one_of(&[2, 3], None::<String>))
+        .rule_for("manager", |p| &p.manager, RuleBuilder::for_property("manager").not_null(None::<String>))
+        .build();
+
+    let empty = Profile { email: String::new(), website: String::new(), nickname: " ".to_string(), tags: Vec::new(), manager: None };
+    let result = validator.validate(&empty);
+    let errors: Vec<(&str, &str)> = result.errors().iter().map(|e| (&*e.property, e.message.as_str())).collect();
+    assert_eq!(errors, vec![
+        ("email", "must not be empty"),
+        ("nickname", "must not be only whitespace"),
+        ("manager", "must not be null"),
+    ]);
+
+    let filled = Profile { email: "nope".to_string(), website: "x.io".to_string(), nickname: "al".to_string(), tags: vec![String::new()], manager: Some("Ada".to_string()) };
+    let result = validator.validate(&filled);
+    assert_eq!(result.properties(), vec!["email", "website", "nickname", "tags"]);
+
+    // The default validates empty values like any other
+    let validator = ValidatorBuilder::<Profile>::new()
+        .rule_for("email", |p| &p.email, RuleBuilder::for_property("email").not_empty(None::<String>).email(None::<String>))
+        .build();
+    assert_eq!(validator.validate(&empty).errors().len(), 2);
+}
+
+#[test]
+fn test_isolate_panics() {
+    struct Order {
+        sku: String,
+        quantity: i32,
+    }
+
+    let builder = || ValidatorBuilder::<Order>::new()
+        .rule_for("sku", |o| &o.sku,
+            RuleBuilder::for_property("sku")
+                .must(|sku: &String| sku.as_bytes()[3] == b'-', "must have a dash after the prefix")
+                .min_length(5, None::<String>))
+        .rule_for("quantity", |o| &o.quantity, RuleBuilder::for_property("quantity").rule(|q: &i32| (*q <= 0).then(|| "must be positive".to_string())));
+
+    let validator = builder().isolate_panics().build();
+    let result = validator.validate(&Order { sku: "AB".to_string(), quantity: 0 });
+    let errors: Vec<(&str, &str, Option<&str>)> = result.errors().iter().map(|e| (&*e.property, e.message.as_str(), e.code.as_deref())).collect();
+    assert_eq!(errors, vec![
+        ("sku", "could not be validated", Some("RULE_PANICKED")),
+        ("sku", "must be at least 5 characters long", Some("MIN_LENGTH")),
+        ("quantity", "must be positive", None),
+    ]);
+    assert!(validator.validate(&Order { sku: "ABC-1".to_string(), quantity: 1 }).is_valid());
+
+    let validator = builder().build();
+    let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| validator.validate(&Order { sku: "AB".to_string(), quantity: 1 })));
+    assert!(outcome.is_err());
+}
+
+#[test]
+fn test_max_errors() {
+    struct Row {
+        cells: Vec<String>,
+        total: i32,
+    }
+
+    let builder = || ValidatorBuilder::<Row>::new()
+        .rule_for_each("cells", |r| &r.cells, RuleBuilder::for_property("cells").not_empty(None::<String>))
+        .rule_for("total", |r| &r.total, RuleBuilder::for_property("total").greater_than(0, None::<String>));
+
+    let validator = builder().max_errors(3).build();
+    let result = validator.validate(&Row { cells: vec![String::new(); 100], total: 0 });
+    let properties: Vec<&str> = result.errors().iter().map(|e| &*e.property).collect();
+    assert_eq!(properties, vec!["cells[0]", "cells[1]", "cells[2]"]);
+    assert!(result.is_truncated());
+
+    // Reaching the limit with the last rule leaves nothing out
+    let result = validator.validate(&Row { cells: vec![String::new(); 2], total: 0 });
+    assert_eq!(result.errors().len(), 3);
+    assert!(!result.is_truncated());
+
+    let result = validator.validate(&Row { cells: vec![String::new(); 3], total: 0 });
+    assert_eq!(result.errors().len(), 3);
+    assert!(result.is_truncated());
+
+    let result = builder().build().validate(&Row { cells: vec![String::new(); 100], total: 0 });
+    assert_eq!(result.errors().len(), 101);
+    assert!(!result.is_truncated());
+}
+
+#[test]
+fn test_combine_results() {
+    let mut result = ValidationResult::new().with_correlation_id("a1");
+    result.add_error(ValidationError::new("email", "is required"));
+
+    let mut other = ValidationResult::new().with_schema("order.create", Some("3")).with_correlation_id("b2");
+    other.add_error(ValidationError::new("total", "must be positive"));
+    result.merge(other);
+    assert_eq!(result.correlation_id(), Some("a1"));
+    assert_eq!(result.schema_id(), Some("order.create"));
+
+    result.extend([ValidationError::new("lines", "must not be empty")]);
+    let properties: Vec<&str> = (&result).into_iter().map(|e| &*e.property).collect();
+    assert_eq!(properties, vec!["email", "total", "lines"]);
+
+    let manual: ValidationResult = ["a", "b"].into_iter().filter(|p| *p != "a").map(|p| ValidationError::new(p, "is invalid")).collect();
+    assert_eq!(manual.errors().len(), 1);
+    assert!(std::iter::empty::<ValidationError>().collect::<ValidationResult>().is_valid());
+
+    let messages: Vec<String> = result.into_iter().map(|e| e.message).collect();
+    assert_eq!(messages, vec!["is required", "must be positive", "must not be empty"]);
+}
+
+#[test]
+fn test_into_result() {
+    fn register(name: String) -> Result<String, Box<dyn std::error::Error>> {
+        let validator = ValidatorBuilder::<String>::new()
+            .rule_for("name", |n| n, RuleBuilder::for_property("name").not_empty(None::<String>).min_length(2, None::<String>))
+            .build();
+        Ok(validator.validate(&name).ok_or_errors(name)?)
+    }
+
+    assert_eq!(register("Ada".to_string()).unwrap(), "Ada");
+    let error = register(String::new()).unwrap_err();
+    assert_eq!(error.to_string(), "validation failed: name: must not be empty (got ); name: must be at least 2 characters long (got )");
+    let errors = error.downcast::<ValidationErrors>().unwrap();
+    assert_eq!(errors.errors().len(), 2);
+    assert_eq!(ValidationResult::from(*errors).errors().len(), 2);
+
+    assert_eq!(ValidationResult::new().into_result(), Ok(()));
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("", "is inconsistent"));
+    assert_eq!(result.clone().into_result().unwrap_err().into_inner(), result);
+}
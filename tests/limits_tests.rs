@@ -0,0 +1,108 @@
+use std::sync::OnceLock;
+
+use fluentval::*;
+
+struct Upload {
+    email: String,
+    tags: Vec<String>,
+    samples: Vec<i32>,
+}
+
+fn upload_validator() -> impl Validator<Upload> {
+    ValidatorBuilder::<Upload>::new()
+        .max_input_bytes(64)
+        .max_collection_len(3)
+        .rule_for("email", |u| &u.email, RuleBuilder::for_property("email").email(None::<String>))
+        .rule_for_each("tags", |u| &u.tags, RuleBuilder::for_property("tags").not_empty(None::<String>))
+        .rule_for("samples", |u| &u.samples, RuleBuilder::for_property("samples").sorted_ascending(None::<String>))
+        .build()
+}
+
+#[test]
+fn test_oversized_values_fail_with_resource_limit() {
+    let validator = upload_validator();
+    let upload = Upload {
+        email: format!("{}@example.com", "a".repeat(100)),
+        tags: vec![String::new(); 10],
+        samples: vec![3, 2, 1, 0],
+    };
+
+    let result = validator.validate(&upload);
+    let errors: Vec<(&str, &str, Option<&str>)> = result.errors().iter().map(|e| (&*e.property, e.message.as_str(), e.code.as_deref())).collect();
+    assert_eq!(errors, vec![
+        ("email", "is too large to validate (limit 64)", Some("RESOURCE_LIMIT")),
+        ("tags[0]", "must not be empty", Some("NOT_EMPTY")),
+        ("tags[1]", "must not be empty", Some("NOT_EMPTY")),
+        ("tags[2]", "must not be empty", Some("NOT_EMPTY")),
+        ("tags", "is too large to validate (limit 3)", Some("RESOURCE_LIMIT")),
+        ("samples", "is too large to validate (limit 3)", Some("RESOURCE_LIMIT")),
+    ]);
+    assert_eq!(result.errors()[0].state.get("resource").map(String::as_str), Some("input_bytes"));
+    assert_eq!(result.errors()[4].state.get("resource").map(String::as_str), Some("collection_len"));
+}
+
+#[test]
+fn test_values_within_limits_validate_normally() {
+    let validator = upload_validator();
+    let upload = Upload { email: "ada@example.com".to_string(), tags: vec!["x".to_string(); 3], samples: vec![1, 2, 2] };
+    assert!(validator.validate(&upload).is_valid());
+
+    let upload = Upload { email: "ada".to_string(), tags: Vec::new(), samples: vec![2, 1] };
+    let result = validator.validate(&upload);
+    assert_eq!(result.first_error_for("email"), Some("must be a valid email address"));
+    assert_eq!(result.first_error_for("samples"), Some("must be sorted in ascending order (element 1 is out of order)"));
+}
+
+struct Schedule {
+    labels: Vec<String>,
+    values: Vec<i32>,
+    slots: Vec<(u32, u32)>,
+}
+
+#[test]
+fn test_paired_collection_rules_respect_max_collection_len() {
+    let validator = ValidatorBuilder::<Schedule>::new()
+        .max_collection_len(3)
+        .same_length("values", |s| &s.labels, |s| &s.values, None::<String>)
+        .each_pair("values", |s| &s.labels, |s| &s.values, |label, _| !label.is_empty(), "value {CollectionIndex} needs a label")
+        .non_overlapping("slots", |s| &s.slots, |slot| *slot, None::<String>)
+        .build();
+
+    let schedule = Schedule { labels: vec!["a".to_string(); 3], values: vec![0; 10], slots: vec![(0, 1); 10] };
+    let result = validator.validate(&schedule);
+    let errors: Vec<(&str, &str, Option<&str>)> = result.errors().iter().map(|e| (&*e.property, e.message.as_str(), e.code.as_deref())).collect();
+    assert_eq!(errors, vec![
+        ("values", "is too large to validate (limit 3)", Some("RESOURCE_LIMIT")),
+        ("values", "is too large to validate (limit 3)", Some("RESOURCE_LIMIT")),
+        ("slots", "is too large to validate (limit 3)", Some("RESOURCE_LIMIT")),
+    ]);
+
+    let schedule = Schedule { labels: vec![String::new(); 2], values: vec![0; 2], slots: vec![(0, 2), (1, 3)] };
+    let result = validator.validate(&schedule);
+    let codes: Vec<Option<&str>> = result.errors().iter().map(|e| e.code.as_deref()).collect();
+    assert_eq!(codes, vec![None, None, Some("NON_OVERLAPPING")]);
+}
+
+struct Node {
+    children: Vec<Node>,
+}
+
+fn node_validator() -> &'static dyn Validator<Node> {
+    static VALIDATOR: OnceLock<Box<dyn Validator<Node>>> = OnceLock::new();
+    VALIDATOR
+        .get_or_init(|| Box::new(ValidatorBuilder::<Node>::new()
+            .max_depth(4)
+            .must("children", |n| &n.children, |_, children: &Vec<Node>| {
+                children.iter().all(|child| node_validator().validate(child).is_valid())
+            }, "must be valid")
+            .build()))
+        .as_ref()
+}
+
+#[test]
+fn test_max_depth() {
+    let tree = |depth: usize| (0..depth).fold(Node { children: Vec::new() }, |node, _| Node { children: vec![node] });
+
+    assert!(node_validator().validate(&tree(3)).is_valid());
+    assert!(!node_validator().validate(&tree(4)).is_valid());
+}
@@ -0,0 +1,79 @@
+use fluentval::*;
+
+struct User {
+    name: String,
+    email: String,
+    age: i32,
+}
+
+#[test]
+fn test_validator_macro() {
+    let validator = validator!(User {
+        name: [not_empty, min_length(2)],
+        email: [email],
+        age: [inclusive_between(18, 130)],
+    });
+
+    let valid = User { name: "Ada".into(), email: "ada@example.com".into(), age: 36 };
+    assert!(validator.validate(&valid).is_valid());
+
+    let invalid = User { name: "".into(), email: "ada".into(), age: 12 };
+    let result = validator.validate(&invalid);
+    assert_eq!(&*result.errors_by_property()[0].0, "name");
+    assert_eq!(result.errors_by_property()[0].1.len(), 2);
+    assert_eq!(result.first_error_for("email"), Some("must be a valid email address"));
+    assert_eq!(result.errors()[3].code.as_deref(), Some("INCLUSIVE_BETWEEN"));
+    assert_eq!(validator.rules().iter().map(|r| r.property.as_str()).collect::<Vec<_>>(), ["name", "name", "email", "age"]);
+}
+
+struct Address {
+    city: String,
+}
+
+struct Customer {
+    address: Address,
+}
+
+#[test]
+fn test_prop_macro() {
+    assert_eq!(prop!(User::email), "email");
+    assert_eq!(prop!(Customer::address.city), "address.city");
+
+    let validator = ValidatorBuilder::<Customer>::new()
+        .rule_for(prop!(Customer::address.city), |c| &c.address.city,
+            RuleBuilder::for_property(prop!(Customer::address.city)).not_empty(None::<String>))
+        .build();
+    let result = validator.validate(&Customer { address: Address { city: String::new() } });
+    assert_eq!(&*result.errors()[0].property, "address.city");
+}
+
+#[test]
+fn test_validate_all() {
+    struct Customer {
+        email: String,
+    }
+    struct Address {
+        zip: String,
+    }
+    struct Request {
+        shipping: Address,
+    }
+
+    let customer_validator = ValidatorBuilder::<Customer>::new()
+        .rule_for("email", |c| &c.email, RuleBuilder::for_property("email").email(None::<String>))
+        .build();
+    let address_validator = ValidatorBuilder::<Address>::new()
+        .rule_for("zip", |a| &a.zip, RuleBuilder::for_property("zip").not_empty(None::<String>))
+        .build();
+
+    let customer = Customer { email: "nope".to_string() };
+    let request = Request { shipping: Address { zip: String::new() } };
+    let result = validate_all!(
+        (customer, &customer_validator),
+        ("shipping" => request.shipping, &address_validator),
+    );
+    assert_eq!(result.properties(), vec!["customer.email", "shipping.zip"]);
+
+    let customer = Customer { email: "ada@example.com".to_string() };
+    assert!(validate_all!((customer, &customer_validator)).is_valid());
+}
@@ -0,0 +1,25 @@
+use fluentval::messages;
+use fluentval::*;
+
+// Kept in a single test because the overrides are process-wide
+#[test]
+fn test_set_default_overrides_builtin_wording() {
+    let rule_fn = RuleBuilder::<String>::for_property("name")
+        .not_empty(None::<String>)
+        .min_length(3, None::<String>)
+        .max_length(5, Some("custom max"))
+        .build();
+
+    messages::set_default("not_empty", "This field is required");
+    messages::set_default("min_length", "Needs {min}+ characters");
+    messages::set_default("max_length", "ignored, custom messages win");
+
+    let errors = rule_fn(&" ".to_string());
+    assert_eq!(errors[0].message, "This field is required");
+    assert_eq!(errors[1].message, "Needs 3+ characters");
+    assert_eq!(rule_fn(&"toolong".to_string())[0].message, "custom max");
+    assert_eq!(messages::default_message("not_empty").as_deref(), Some("This field is required"));
+
+    messages::clear_defaults();
+    assert_eq!(rule_fn(&"".to_string())[0].message, "must not be empty");
+}
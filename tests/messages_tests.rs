@@ -0,0 +1,91 @@
+use fluentval::messages::{MessageCatalog, MessageProvider};
+use fluentval::*;
+
+struct User {
+    name: String,
+    age: i32,
+}
+
+fn dutch() -> MessageCatalog {
+    MessageCatalog::new()
+        .with("nl", "not_empty", "mag niet leeg zijn")
+        .with("nl", "greater_than_or_equal", "moet minstens {min} zijn")
+        .with("nl-BE", "not_empty", "mag ni leeg zijn")
+}
+
+fn user_validator() -> impl Validator<User> {
+    ValidatorBuilder::<User>::new()
+        .with_message_provider(dutch())
+        .rule_for("name", |u| &u.name,
+            RuleBuilder::for_property("name")
+                .not_empty(None::<String>)
+                .min_length(2, None::<String>))
+        .rule_for("age", |u| &u.age,
+            RuleBuilder::for_property("age")
+                .greater_than_or_equal(18, Some("custom wins")))
+        .build()
+}
+
+#[test]
+fn test_validate_with_locale_uses_provider() {
+    let validator = user_validator();
+    let user = User { name: "".to_string(), age: 15 };
+
+    let result = validator.validate_with_locale(&user, "nl-NL");
+    let messages: Vec<&str> = result.errors().iter().map(|e| e.message.as_str()).collect();
+    // "nl-NL" falls back to "nl"; min_length has no Dutch template so English is used
+    assert_eq!(messages, vec!["mag niet leeg zijn", "must be at least 2 characters long", "custom wins"]);
+
+    let result = validator.validate_with_locale(&user, "nl-BE");
+    assert_eq!(result.first_error_for("name"), Some("mag ni leeg zijn"));
+
+    // Plain validate uses the default English messages
+    let result = validator.validate(&user);
+    assert_eq!(result.first_error_for("name"), Some("must not be empty"));
+}
+
+#[test]
+fn test_rule_builder_message_provider_placeholders() {
+    struct Shouting;
+    impl MessageProvider for Shouting {
+        fn message(&self, kind: &str, _locale: &str) -> Option<String> {
+            (kind == "max_length").then(|| "NO MORE THAN {max}!".to_string())
+        }
+    }
+
+    let rule_fn = RuleBuilder::<String>::for_property("name")
+        .with_message_provider(Shouting)
+        .max_length(3, None::<String>)
+        .build();
+    assert_eq!(rule_fn(&"abcdef".to_string())[0].message, "NO MORE THAN 3!");
+}
+
+#[test]
+fn test_accept_language_ordering() {
+    use fluentval::messages::accept_language;
+
+    assert_eq!(accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.95, *;q=0.5"), vec!["fr-CH", "de", "fr", "en"]);
+    assert_eq!(accept_language("en;q=0, es"), vec!["es"]);
+    assert!(accept_language("").is_empty());
+}
+
+#[test]
+fn test_negotiate_locale() {
+    use fluentval::messages::negotiate_locale;
+
+    assert_eq!(negotiate_locale("fr-CH, de;q=0.9, en;q=0.8", &["en", "de"]).as_deref(), Some("de"));
+    assert_eq!(negotiate_locale("de-AT", &["en", "de-DE"]).as_deref(), Some("de-DE"));
+    assert_eq!(negotiate_locale("PT-br", &["pt-BR"]).as_deref(), Some("pt-BR"));
+    assert_eq!(negotiate_locale("ja", &["en", "de"]), None);
+}
+
+#[test]
+fn test_available_locales() {
+    use fluentval::messages::{available_locales, MessageCatalog};
+
+    let catalog = MessageCatalog::new().with("nl", "not_empty", "mag niet leeg zijn").with("nl", "email", "ongeldig");
+    let locales = available_locales(Some(&catalog));
+    assert_eq!(locales.first().map(String::as_str), Some("nl"));
+    assert_eq!(locales.iter().filter(|l| *l == "nl").count(), 1);
+    assert!(locales.contains(&"en".to_string()));
+}
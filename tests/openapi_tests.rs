@@ -0,0 +1,48 @@
+#![cfg(feature = "utoipa")]
+
+use fluentval::openapi::{apply_constraints, component_schema};
+use fluentval::*;
+use serde_json::json;
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+#[allow(dead_code)]
+#[derive(ToSchema)]
+struct CreateUser {
+    name: String,
+    email: String,
+    age: i32,
+    tags: Vec<String>,
+}
+
+fn validator() -> impl Validator<CreateUser> {
+    ValidatorBuilder::<CreateUser>::new()
+        .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").not_empty(None::<String>).max_length(50, None::<String>))
+        .rule_for("email", |u| &u.email, RuleBuilder::for_property("email").email(None::<String>))
+        .rule_for("age", |u| &u.age, RuleBuilder::for_property("age").inclusive_between(18, 130, None::<String>))
+        .rule_for_each("tags", |u| &u.tags, RuleBuilder::for_property("tags").min_length(2, None::<String>))
+        .build()
+}
+
+#[test]
+fn test_apply_constraints_to_derived_schema() {
+    let RefOr::T(mut schema) = CreateUser::schema() else { panic!("expected an inline schema") };
+    apply_constraints(&validator(), &mut schema);
+
+    let json = serde_json::to_value(&schema).unwrap();
+    assert_eq!(json["properties"]["name"], json!({"type": "string", "minLength": 1, "maxLength": 50}));
+    assert_eq!(json["properties"]["email"], json!({"type": "string", "format": "email"}));
+    assert_eq!(json["properties"]["age"]["minimum"], 18);
+    assert_eq!(json["properties"]["age"]["maximum"], 130);
+    assert_eq!(json["properties"]["tags"]["items"]["minLength"], 2);
+    assert_eq!(json["required"], json!(["name", "email", "age", "tags"]));
+}
+
+#[test]
+fn test_component_schema_from_validator() {
+    let json = serde_json::to_value(component_schema(&validator())).unwrap();
+    assert_eq!(json["type"], "object");
+    assert_eq!(json["properties"]["name"], json!({"type": "string", "minLength": 1, "maxLength": 50}));
+    assert_eq!(json["properties"]["tags"], json!({"type": "array", "items": {"type": "string", "minLength": 2}}));
+    assert_eq!(json["required"], json!(["name"]));
+}
@@ -0,0 +1,75 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use fluentval::*;
+
+struct Order {
+    sku: String,
+    quantity: i32,
+}
+
+fn pipeline(remote_calls: Arc<AtomicUsize>) -> ValidationPipeline<Order> {
+    ValidationPipeline::<Order>::new()
+        .stage("syntactic", ValidatorBuilder::<Order>::new()
+            .rule_for("sku", |o| &o.sku, RuleBuilder::for_property("sku").not_empty(None::<String>))
+            .build())
+        .stage("semantic", ValidatorBuilder::<Order>::new()
+            .rule_for("quantity", |o| &o.quantity, RuleBuilder::for_property("quantity").less_than_or_equal(10, None::<String>))
+            .build())
+        .stage("remote", ValidatorBuilder::<Order>::new()
+            .rule_for("sku", |o| &o.sku, RuleBuilder::for_property("sku").rule(move |sku: &String| {
+                remote_calls.fetch_add(1, Ordering::SeqCst);
+                (sku == "discontinued").then(|| "is no longer sold".to_string())
+            }))
+            .build())
+}
+
+#[test]
+fn test_stops_at_first_failing_stage() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let pipeline = pipeline(Arc::clone(&calls));
+
+    let result = pipeline.validate(&Order { sku: String::new(), quantity: 50 });
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(&*result.errors()[0].property, "sku");
+    assert_eq!(result.errors()[0].state["stage"], "syntactic");
+
+    let result = pipeline.validate(&Order { sku: "A1".to_string(), quantity: 50 });
+    assert_eq!(result.errors()[0].state["stage"], "semantic");
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    let result = pipeline.validate(&Order { sku: "discontinued".to_string(), quantity: 1 });
+    assert_eq!(result.first_error_for("sku"), Some("is no longer sold"));
+    assert_eq!(result.errors()[0].state["stage"], "remote");
+    assert!(pipeline.validate(&Order { sku: "A1".to_string(), quantity: 1 }).is_valid());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_keeps_skipped_rules_of_earlier_stages() {
+    let pipeline = ValidationPipeline::<Order>::new()
+        .stage("syntactic", ValidatorBuilder::<Order>::new()
+            .record_skipped_rules()
+            .rule_for("sku", |o| &o.sku, RuleBuilder::for_property("sku")
+                .not_empty(None::<String>)
+                .skip_when_context(|ctx| ctx.flag("import")))
+            .build())
+        .stage("semantic", ValidatorBuilder::<Order>::new()
+            .record_skipped_rules()
+            .max_errors(1)
+            .rule_for("quantity", |o| &o.quantity, RuleBuilder::for_property("quantity").less_than_or_equal(10, None::<String>))
+            .rule_for("sku", |o| &o.sku, RuleBuilder::for_property("sku").max_length(8, None::<String>))
+            .build());
+
+    let context = ValidationContext::new().with_flag("import");
+    let result = pipeline.validate_with_context(&Order { sku: String::new(), quantity: 50 }, &context);
+    let skipped: Vec<(&str, SkipReason)> = result.skipped_rules().iter().map(|s| (&*s.property, s.reason)).collect();
+    assert_eq!(skipped, vec![("sku", SkipReason::Condition), ("sku", SkipReason::MaxErrors)]);
+}
+
+#[test]
+fn test_describes_all_stages() {
+    let pipeline = pipeline(Arc::new(AtomicUsize::new(0)));
+    assert_eq!(pipeline.stage_names(), vec!["syntactic", "semantic", "remote"]);
+    assert_eq!(pipeline.rules().len(), 3);
+}
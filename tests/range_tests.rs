@@ -0,0 +1,57 @@
+use fluentval::*;
+
+struct Booking {
+    check_in: i64,
+    check_out: i64,
+}
+
+fn booking(check_in: i64, check_out: i64) -> Booking {
+    Booking { check_in, check_out }
+}
+
+#[test]
+fn test_date_range_requires_end_after_start() {
+    let validator = ValidatorBuilder::<Booking>::new()
+        .date_range("check_out", |b| &b.check_in, |b| &b.check_out, DateRange::new())
+        .build();
+
+    assert!(validate(&booking(1, 2), &validator).is_valid());
+
+    let result = validate(&booking(5, 5), &validator);
+    let error = &result.errors()[0];
+    assert_eq!(&*error.property, "check_out");
+    assert_eq!(error.message, "must be after 5");
+    assert_eq!(error.code.as_deref(), Some("DATE_RANGE"));
+    assert_eq!(error.state.get("start").map(String::as_str), Some("5"));
+}
+
+#[test]
+fn test_date_range_options() {
+    let validator = ValidatorBuilder::<Booking>::new()
+        .date_range("check_out", |b| &b.check_in, |b| &b.check_out, DateRange::new().allow_equal(true).max_span(7))
+        .build();
+
+    assert!(validate(&booking(5, 5), &validator).is_valid());
+    assert!(validate(&booking(5, 12), &validator).is_valid());
+
+    let result = validate(&booking(5, 4), &validator);
+    assert_eq!(result.errors()[0].message, "must not be before 5");
+
+    let result = validate(&booking(5, 13), &validator);
+    assert_eq!(result.errors()[0].message, "must be within 7 of 5");
+    assert_eq!(result.errors()[0].code.as_deref(), Some("DATE_RANGE_SPAN"));
+}
+
+#[test]
+fn test_date_range_custom_message_and_descriptor() {
+    let validator = ValidatorBuilder::<Booking>::new()
+        .date_range("stay", |b| &b.check_in, |b| &b.check_out, DateRange::new().max_span(3).message("stay {start}..{end} is invalid"))
+        .build();
+
+    let result = validate(&booking(1, 9), &validator);
+    assert_eq!(result.errors()[0].to_string(), "stay: stay 1..9 is invalid (got 9)");
+
+    let rule = &validator.rules()[0];
+    assert_eq!(rule.kind, "date_range");
+    assert_eq!(rule.params, vec![("max_span".to_string(), "3".to_string())]);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "rocket")]
+
+use fluentval::web::rocket::{catcher, Validated};
+use fluentval::*;
+use rocket::form::Form;
+use rocket::http::{ContentType, Header, Status};
+use rocket::local::blocking::Client;
+use rocket::serde::json::Json;
+use rocket::{post, routes, FromForm};
+use serde::Deserialize;
+
+#[derive(Deserialize, FromForm)]
+struct CreateUser {
+    name: String,
+    age: i32,
+}
+
+fn user_validator() -> impl Validator<CreateUser> {
+    ValidatorBuilder::<CreateUser>::new()
+        .with_message_provider(messages::MessageCatalog::new().with("nl", "not_empty", "mag niet leeg zijn"))
+        .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").not_empty(None::<String>))
+        .rule_for("age", |u| &u.age, RuleBuilder::for_property("age").greater_than_or_equal(18, None::<String>))
+        .build()
+}
+
+impl Validate for CreateUser {
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+        user_validator().validate_with_locale(self, locale)
+    }
+
+    fn locales(&self) -> Vec<String> {
+        user_validator().locales()
+    }
+}
+
+#[post("/json", data = "<user>")]
+fn create_json(user: Validated<Json<CreateUser>>) -> String {
+    user.into_inner().into_inner().name
+}
+
+#[post("/form", data = "<user>")]
+fn create_form(user: Validated<Form<CreateUser>>) -> String {
+    user.name.clone()
+}
+
+fn client() -> Client {
+    let rocket = rocket::build().mount("/", routes![create_json, create_form]).register("/", vec![catcher()]);
+    Client::tracked(rocket).unwrap()
+}
+
+#[test]
+fn test_json_is_validated() {
+    let client = client();
+
+    let response = client.post("/json").header(ContentType::JSON).body(r#"{"name":"Ada","age":36}"#).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "Ada");
+
+    let response = client.post("/json").header(ContentType::JSON).body(r#"{"name":"","age":15}"#).dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    let body: serde_json::Value = serde_json::from_str(&response.into_string().unwrap()).unwrap();
+    assert_eq!(body["errors"]["name"][0], "must not be empty");
+    assert_eq!(body["errors"]["age"][0], "must be greater than or equal to 18");
+}
+
+#[test]
+fn test_form_messages_follow_accept_language() {
+    let client = client();
+
+    let response = client
+        .post("/form")
+        .header(ContentType::Form)
+        .header(Header::new("Accept-Language", "nl-BE"))
+        .body("name=&age=20")
+        .dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    assert!(response.into_string().unwrap().contains("mag niet leeg zijn"));
+}
@@ -0,0 +1,57 @@
+use fluentval::*;
+
+struct Employee {
+    work_email: String,
+    private_email: String,
+}
+
+struct Customer {
+    email: String,
+}
+
+fn email_rules() -> RuleSet<String> {
+    RuleSet::new(|rules| rules.not_empty(None::<String>).email(None::<String>))
+}
+
+#[test]
+fn test_bind_rule_set_to_several_properties() {
+    let rules = email_rules();
+    let employees = ValidatorBuilder::<Employee>::new()
+        .bind("workEmail", |e| &e.work_email, &rules)
+        .bind("privateEmail", |e| &e.private_email, &rules)
+        .build();
+    let customers = ValidatorBuilder::<Customer>::new()
+        .bind("email", |c| &c.email, &rules)
+        .build();
+
+    let result = employees.validate(&Employee { work_email: "ada@example.com".to_string(), private_email: "ada".to_string() });
+    let errors: Vec<(&str, &str)> = result.errors().iter().map(|e| (&*e.property, e.message.as_str())).collect();
+    assert_eq!(errors, vec![("privateEmail", "must be a valid email address")]);
+
+    let result = customers.validate(&Customer { email: String::new() });
+    assert_eq!(result.first_error_for("email"), Some("must not be empty"));
+
+    let kinds: Vec<(String, String)> = employees.rules().into_iter().map(|d| (d.property, d.kind)).collect();
+    assert_eq!(kinds, vec![
+        ("workEmail".to_string(), "not_empty".to_string()),
+        ("workEmail".to_string(), "email".to_string()),
+        ("privateEmail".to_string(), "not_empty".to_string()),
+        ("privateEmail".to_string(), "email".to_string()),
+    ]);
+}
+
+#[test]
+fn test_extend_rule_set_for_one_property() {
+    let validator = ValidatorBuilder::<Customer>::new()
+        .rule_for("email", |c| &c.email,
+            email_rules().for_property("email").must(|email: &String| email.ends_with("@example.com"), "must be a company address"))
+        .build();
+    assert_eq!(validator.validate(&Customer { email: "ada@example.org".to_string() }).first_error_for("email"), Some("must be a company address"));
+
+    let validator = ValidatorBuilder::<Customer>::new()
+        .rule_for("email", |c| &c.email, RuleBuilder::for_property("email").max_length(5, None::<String>).include(&email_rules()))
+        .build();
+    let result = validator.validate(&Customer { email: "ada@example.com".to_string() });
+    assert_eq!(result.errors().len(), 1);
+    assert_eq!(result.first_error_for("email"), Some("must be at most 5 characters long"));
+}
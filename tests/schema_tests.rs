@@ -0,0 +1,71 @@
+use fluentval::*;
+use serde_json::json;
+
+struct User {
+    name: String,
+    email: String,
+    age: i32,
+    nickname: Option<String>,
+    tags: Vec<String>,
+}
+
+fn user_validator() -> impl Validator<User> {
+    ValidatorBuilder::<User>::new()
+        .rule_for("name", |u| &u.name,
+            RuleBuilder::for_property("name").length(2, 50, None::<String>, None::<String>).not_empty(None::<String>))
+        .rule_for("email", |u| &u.email, RuleBuilder::for_property("email").email(None::<String>))
+        .rule_for("age", |u| &u.age,
+            RuleBuilder::for_property("age").greater_than_or_equal(18, None::<String>).less_than(130, None::<String>))
+        .rule_for("nickname", |u| &u.nickname, RuleBuilder::for_property("nickname").not_null(None::<String>))
+        .rule_for_each("tags", |u| &u.tags, RuleBuilder::for_property("tags").max_length(20, None::<String>))
+        .must("email", |u| &u.email, |u, email| !email.contains(&u.name), "must not contain the name")
+        .build()
+}
+
+#[test]
+fn test_to_json_schema() {
+    let schema: serde_json::Value = serde_json::from_str(&user_validator().to_json_schema()).unwrap();
+    assert_eq!(schema, json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "name": {"type": "string", "minLength": 2, "maxLength": 50},
+            "email": {"type": "string", "format": "email"},
+            "age": {"type": "number", "minimum": 18, "exclusiveMaximum": 130},
+            "nickname": {},
+            "tags": {"type": "array", "items": {"type": "string", "maxLength": 20}}
+        },
+        "required": ["name", "nickname"]
+    }));
+}
+
+#[test]
+fn test_to_zod() {
+    let expected = r#"import { z } from "zod";
+
+export const UserSchema = z.object({
+  "name": z.string().min(2).max(50),
+  "email": z.string().email().optional(),
+  "age": z.number().min(18).lt(130).optional(),
+  "nickname": z.unknown(),
+  "tags": z.array(z.string().max(20)).optional(),
+});
+
+export type User = z.infer<typeof UserSchema>;
+"#;
+    assert_eq!(user_validator().to_zod("User"), expected);
+}
+
+#[test]
+fn test_pattern_export() {
+    struct Product {
+        sku: String,
+    }
+
+    let validator = ValidatorBuilder::<Product>::new()
+        .rule_for("sku", |p| &p.sku, RuleBuilder::for_property("sku").matches(regex::Regex::new(r"^[A-Z]{3}-\d{4}$").unwrap(), None::<String>))
+        .build();
+    let schema: serde_json::Value = serde_json::from_str(&validator.to_json_schema()).unwrap();
+    assert_eq!(schema["properties"]["sku"], json!({"type": "string", "pattern": r"^[A-Z]{3}-\d{4}$"}));
+    assert!(validator.to_zod("Product").contains(r#""sku": z.string().regex(new RegExp("^[A-Z]{3}-\\d{4}$")).optional(),"#));
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "serde")]
+
+use fluentval::*;
+
+#[test]
+fn test_validation_error_json_shape() {
+    let error = ValidationError::new("age", "must be greater than or equal to 18")
+        .with_code("GREATER_THAN_OR_EQUAL")
+        .with_attempted_value(15)
+        .with_state("limit", 18);
+    assert_eq!(
+        serde_json::to_string(&error).unwrap(),
+        r#"{"property":"age","message":"must be greater than or equal to 18","code":"GREATER_THAN_OR_EQUAL","severity":"error","attempted_value":"15","state":{"limit":"18"}}"#
+    );
+
+    let bare = ValidationError::new("name", "must not be empty").with_severity(Severity::Warning);
+    assert_eq!(
+        serde_json::to_string(&bare).unwrap(),
+        r#"{"property":"name","message":"must not be empty","code":null,"severity":"warning"}"#
+    );
+}
+
+#[test]
+fn test_validation_result_round_trip() {
+    let rule_fn = RuleBuilder::<String>::for_property("email")
+        .not_empty(None::<String>)
+        .email(None::<String>)
+        .build();
+    let mut result = ValidationResult::new();
+    result.add_errors(rule_fn(&"".to_string()));
+
+    let json = serde_json::to_string(&result).unwrap();
+    assert!(json.starts_with(r#"{"errors":[{"property":"email""#));
+    let restored: ValidationResult = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, result);
+
+    // Only property and message are required when deserializing
+    let minimal: ValidationResult = serde_json::from_str(r#"{"errors":[{"property":"name","message":"bad"}]}"#).unwrap();
+    assert_eq!(minimal.errors()[0], ValidationError::new("name", "bad"));
+}
+
+#[test]
+fn test_schema_tags_serialize() {
+    let result = ValidationResult::new().with_schema("order.create", Some("3"));
+    assert_eq!(serde_json::to_string(&result).unwrap(), r#"{"errors":[],"schema_id":"order.create","schema_version":"3"}"#);
+    assert_eq!(serde_json::from_str::<ValidationResult>(r#"{"errors":[],"schema_id":"order.create","schema_version":"3"}"#).unwrap(), result);
+}
+
+#[test]
+fn test_correlation_id_serializes() {
+    let result = ValidationResult::new().with_correlation_id("9f86d081884c7d65");
+    assert_eq!(serde_json::to_string(&result).unwrap(), r#"{"errors":[],"correlation_id":"9f86d081884c7d65"}"#);
+    assert_eq!(serde_json::from_str::<ValidationResult>(r#"{"errors":[],"correlation_id":"9f86d081884c7d65"}"#).unwrap(), result);
+}
+
+#[test]
+fn test_truncated_serializes() {
+    struct Row {
+        a: String,
+        b: String,
+    }
+
+    let validator = ValidatorBuilder::<Row>::new()
+        .max_errors(1)
+        .rule_for("a", |r| &r.a, RuleBuilder::for_property("a").not_empty(None::<String>))
+        .rule_for("b", |r| &r.b, RuleBuilder::for_property("b").not_empty(None::<String>))
+        .build();
+    let result = validator.validate(&Row { a: String::new(), b: String::new() });
+
+    let json = serde_json::to_string(&result).unwrap();
+    assert!(json.ends_with(r#"],"truncated":true}"#));
+    assert_eq!(serde_json::from_str::<ValidationResult>(&json).unwrap(), result);
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "stream")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use fluentval::*;
+use futures_util::stream::{self, StreamExt};
+
+static IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+static MAX_IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+/// Passes even numbers, taking longer for smaller ones so later items finish first
+async fn is_even(n: u32) -> bool {
+    let in_flight = IN_FLIGHT.fetch_add(1, Ordering::SeqCst) + 1;
+    MAX_IN_FLIGHT.fetch_max(in_flight, Ordering::SeqCst);
+    for _ in 0..(10 - n) {
+        tokio::task::yield_now().await;
+    }
+    IN_FLIGHT.fetch_sub(1, Ordering::SeqCst);
+    n.is_multiple_of(2)
+}
+
+#[tokio::test]
+async fn test_validate_stream_in_order_with_bounded_concurrency() {
+    let validator = ValidatorBuilder::<u32>::new()
+        .rule_for_async("n", |n| n,
+            AsyncRuleBuilder::for_property("n").must(|n: &u32| Box::pin(is_even(*n)), "must be even"))
+        .build();
+
+    let results: Vec<(u32, bool)> = validate_stream(stream::iter(0..10), &validator, 3)
+        .map(|(n, result)| (n, result.is_valid()))
+        .collect()
+        .await;
+
+    assert_eq!(results, (0..10u32).map(|n| (n, n.is_multiple_of(2))).collect::<Vec<_>>());
+    assert_eq!(MAX_IN_FLIGHT.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_validate_stream_with_sync_rules_only() {
+    let validator = ValidatorBuilder::<String>::new()
+        .rule_for("name", |s| s, RuleBuilder::for_property("name").min_length(2, None::<String>))
+        .build();
+
+    let names = stream::iter(["Ada", "A"].map(String::from));
+    let results: Vec<(String, Option<String>)> = validate_stream(names, &validator, 0)
+        .map(|(name, result)| (name, result.first_error_for("name").map(String::from)))
+        .collect()
+        .await;
+    assert_eq!(results, vec![
+        ("Ada".to_string(), None),
+        ("A".to_string(), Some("must be at least 2 characters long".to_string())),
+    ]);
+}
@@ -0,0 +1,154 @@
+use fluentval::testkit::{check, mutations};
+use fluentval::*;
+
+#[derive(Clone)]
+struct User {
+    name: String,
+    email: String,
+}
+
+fn user_validator() -> impl Validator<User> {
+    ValidatorBuilder::<User>::new()
+        .rule_for("name", |u| &u.name,
+            RuleBuilder::for_property("name")
+                .not_empty(None::<String>)
+                .min_length(2, None::<String>))
+        .rule_for("email", |u| &u.email,
+            RuleBuilder::for_property("email")
+                .email(None::<String>))
+        .build()
+}
+
+#[test]
+fn test_check_expect_error_and_no_error() {
+    let validator = user_validator();
+    check(&validator)
+        .given(User { name: "John".to_string(), email: "invalid".to_string() })
+        .expect_invalid()
+        .expect_error("email", "EMAIL")
+        .expect_error_for("email")
+        .expect_message("email", "must be a valid email address")
+        .expect_no_error("name")
+        .expect_error_count(1);
+}
+
+#[test]
+fn test_check_expect_valid() {
+    let validator = user_validator();
+    let user = User { name: "John".to_string(), email: "john@example.com".to_string() };
+    let outcome = check(&validator).given(&user).expect_valid();
+    assert!(outcome.result().is_valid());
+}
+
+#[test]
+fn test_custom_rule_code() {
+    let rule_fn = RuleBuilder::<String>::for_property("name")
+        .must(|v| v != "admin", "is reserved")
+        .with_code("RESERVED")
+        .build();
+    assert_eq!(rule_fn(&"admin".to_string())[0].code.as_deref(), Some("RESERVED"));
+}
+
+#[test]
+#[should_panic(expected = "expected an error on `name` with code `MIN_LENGTH`, but validation produced 1 error(s):\n  - email: must be a valid email address (got invalid) [EMAIL]")]
+fn test_check_failure_reports_full_result() {
+    let validator = user_validator();
+    check(&validator)
+        .given(User { name: "John".to_string(), email: "invalid".to_string() })
+        .expect_error("name", "MIN_LENGTH");
+}
+
+#[derive(Clone)]
+struct Profile {
+    name: String,
+    age: i32,
+    nickname: Option<String>,
+}
+
+fn profile_validator() -> impl Validator<Profile> {
+    ValidatorBuilder::<Profile>::new()
+        .rule_for("name", |p| &p.name,
+            RuleBuilder::for_property("name").not_empty(None::<String>))
+        .rule_for("age", |p| &p.age,
+            RuleBuilder::for_property("age").inclusive_between(0, 150, None::<String>))
+        .rule_for("nickname", |p| &p.nickname,
+            RuleBuilder::for_property("nickname").not_null(None::<String>))
+        .build()
+}
+
+fn valid_profile() -> Profile {
+    Profile { name: "John".to_string(), age: 30, nickname: Some("JJ".to_string()) }
+}
+
+#[test]
+fn test_mutations_all_rejected() {
+    let validator = profile_validator();
+    mutations(&validator, valid_profile())
+        .boundaries("age", |p| &mut p.age)
+        .empty_string("name", |p| &mut p.name)
+        .extremes("age", |p| &mut p.age)
+        .drop_option("nickname", |p| &mut p.nickname)
+        .mutation("negative age", "age", |p| p.age = -1)
+        .assert_all_rejected();
+}
+
+#[test]
+fn test_mutations_from_rule_boundaries() {
+    let validator = user_validator();
+    mutations(&validator, User { name: "John".to_string(), email: "john@example.com".to_string() })
+        .boundaries("name", |u| &mut u.name)
+        .boundaries("email", |u| &mut u.email)
+        .assert_all_rejected();
+}
+
+#[test]
+#[should_panic(expected = "2 of 2 mutation(s) escaped validation:\n  - `name` = \"aaa\" was not rejected on `name`\n  - `name` = \"aaaaa\" was not rejected on `name`")]
+fn test_mutations_from_exact_length_boundaries() {
+    let validator = ValidatorBuilder::<User>::new()
+        .rule_for("name", |u| &u.name,
+            RuleBuilder::for_property("name")
+                .exact_length(4, None::<String>)
+                .skip_when_context(|_| true))
+        .build();
+    mutations(&validator, User { name: "John".to_string(), email: String::new() })
+        .boundaries("name", |u| &mut u.name)
+        .assert_all_rejected();
+}
+
+#[test]
+#[should_panic(expected = "2 of 2 mutation(s) escaped validation:\n  - `age` = -1 was not rejected on `age`\n  - `age` = 151 was not rejected on `age`")]
+fn test_mutations_from_rule_boundaries_report_rules_that_never_run() {
+    let validator = ValidatorBuilder::<Profile>::new()
+        .rule_for("age", |p| &p.age,
+            RuleBuilder::for_property("age")
+                .inclusive_between(0, 150, None::<String>)
+                .skip_when_context(|_| true))
+        .build();
+    mutations(&validator, valid_profile())
+        .boundaries("age", |p| &mut p.age)
+        .assert_all_rejected();
+}
+
+#[test]
+#[should_panic(expected = "2 of 2 mutation(s) escaped validation:\n  - `age` = 0 was not rejected on `age`\n  - `age` = 10 was not rejected on `age`")]
+fn test_mutations_round_fractional_bounds_toward_rejection() {
+    let validator = ValidatorBuilder::<Profile>::new()
+        .rule_for("age", |p| &p.age,
+            RuleBuilder::for_property("age")
+                .greater_than_or_equal(0.5, None::<String>)
+                .less_than(9.5, None::<String>)
+                .skip_when_context(|_| true))
+        .build();
+    mutations(&validator, valid_profile())
+        .boundaries("age", |p| &mut p.age)
+        .assert_all_rejected();
+}
+
+#[test]
+#[should_panic(expected = "1 of 1 mutation(s) escaped validation:\n  - `age` = 2147483647 was not rejected on `name`")]
+fn test_mutations_report_escaped() {
+    let validator = profile_validator();
+    mutations(&validator, valid_profile())
+        .mutation("`age` = 2147483647", "name", |p| p.age = i32::MAX)
+        .assert_all_rejected();
+}
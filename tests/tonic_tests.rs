@@ -0,0 +1,56 @@
+#![cfg(feature = "tonic")]
+
+use fluentval::web::tonic::{invalid_argument, validate_request};
+use fluentval::*;
+use tonic::{Code, Request, Status};
+use tonic_types::StatusExt;
+
+#[derive(Debug)]
+struct CreateUser {
+    name: String,
+    age: i32,
+}
+
+fn user_validator() -> impl Validator<CreateUser> {
+    ValidatorBuilder::<CreateUser>::new()
+        .with_message_provider(messages::MessageCatalog::new().with("nl", "not_empty", "mag niet leeg zijn"))
+        .rule_for("name", |u| &u.name, RuleBuilder::for_property("name").not_empty(None::<String>))
+        .rule_for("age", |u| &u.age, RuleBuilder::for_property("age").greater_than_or_equal(18, None::<String>))
+        .build()
+}
+
+impl Validate for CreateUser {
+    fn validate_with_locale(&self, locale: &str) -> ValidationResult {
+        user_validator().validate_with_locale(self, locale)
+    }
+
+    fn locales(&self) -> Vec<String> {
+        user_validator().locales()
+    }
+}
+
+#[test]
+fn test_invalid_argument_carries_field_violations() {
+    let mut result = ValidationResult::new();
+    result.add_error(ValidationError::new("items[2].name", "must not be empty"));
+
+    let status = invalid_argument(&result);
+    assert_eq!(status.code(), Code::InvalidArgument);
+    let bad_request = status.get_details_bad_request().unwrap();
+    assert_eq!(bad_request.field_violations.len(), 1);
+    assert_eq!(bad_request.field_violations[0].field, "items[2].name");
+    assert_eq!(bad_request.field_violations[0].description, "must not be empty");
+}
+
+#[test]
+fn test_validate_request() {
+    let request = Request::new(CreateUser { name: "Ada".into(), age: 36 });
+    assert!(validate_request(request).is_ok());
+
+    let mut request = Request::new(CreateUser { name: "".into(), age: 15 });
+    request.metadata_mut().insert("accept-language", "nl".parse().unwrap());
+    let status: Status = validate_request(request).unwrap_err();
+    let violations = status.get_details_bad_request().unwrap().field_violations;
+    let fields: Vec<(&str, &str)> = violations.iter().map(|v| (v.field.as_str(), v.description.as_str())).collect();
+    assert_eq!(fields, vec![("name", "mag niet leeg zijn"), ("age", "must be greater than or equal to 18")]);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "tracing-error")]
+
+use fluentval::*;
+use tracing_error::ErrorLayer;
+use tracing_subscriber::layer::SubscriberExt;
+
+struct Order {
+    quantity: i32,
+}
+
+#[test]
+fn test_span_trace_is_captured_on_failure() {
+    let validator = ValidatorBuilder::<Order>::new()
+        .rule_for("quantity", |o| &o.quantity, RuleBuilder::for_property("quantity").greater_than(0, None::<String>))
+        .build();
+    let subscriber = tracing_subscriber::registry().with(ErrorLayer::default());
+
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!("import_order", order_id = 42);
+        let _entered = span.enter();
+
+        let result = validator.validate(&Order { quantity: 0 });
+        let trace = result.span_trace().expect("failed results carry a span trace").to_string();
+        assert!(trace.contains("import_order"), "{}", trace);
+        assert!(trace.contains("order_id"), "{}", trace);
+        // The trace is context, not content
+        assert_eq!(result, validator.validate(&Order { quantity: 0 }));
+
+        assert!(validator.validate(&Order { quantity: 1 }).span_trace().is_none());
+    });
+}